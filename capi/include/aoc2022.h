@@ -0,0 +1,43 @@
+#ifndef AOC2022_H
+#define AOC2022_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define AOC_OK 0
+#define AOC_UNKNOWN_DAY 1
+#define AOC_UNKNOWN_PART 2
+#define AOC_INVALID_INPUT 3
+#define AOC_SOLVE_FAILED 4
+#define AOC_BUFFER_TOO_SMALL 5
+#define AOC_NULL_POINTER 6
+#define AOC_PANICKED 7
+
+/*
+ * Solve `part` of `day` for the UTF-8 input in `input[0..input_len]`.
+ *
+ * The answer (or the error message, for AOC_SOLVE_FAILED, or the panic
+ * message, for AOC_PANICKED) is written to `out_buf` without a NUL
+ * terminator, and `*out_len` is set to its length. If `*out_len` would
+ * exceed `out_buf_len`, nothing is written and AOC_BUFFER_TOO_SMALL is
+ * returned. `out_buf` may be NULL when `out_buf_len` is 0, to ask for the
+ * length before allocating a buffer.
+ */
+int32_t aoc_solve(
+    uint8_t day,
+    uint8_t part,
+    const uint8_t *input,
+    size_t input_len,
+    uint8_t *out_buf,
+    size_t out_buf_len,
+    size_t *out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,231 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use solvers::SolveError;
+
+pub const AOC_OK: i32 = 0;
+pub const AOC_UNKNOWN_DAY: i32 = 1;
+pub const AOC_UNKNOWN_PART: i32 = 2;
+pub const AOC_INVALID_INPUT: i32 = 3;
+pub const AOC_SOLVE_FAILED: i32 = 4;
+pub const AOC_BUFFER_TOO_SMALL: i32 = 5;
+pub const AOC_NULL_POINTER: i32 = 6;
+pub const AOC_PANICKED: i32 = 7;
+
+/// Solve `part` of `day` for the UTF-8 input in `input_ptr[..input_len]`.
+///
+/// On success the answer is written to `out_buf` and `AOC_OK` is returned.
+/// If the solver fails, its error message is written instead and
+/// `AOC_SOLVE_FAILED` is returned, and if the solver panics, the panic
+/// message is written and `AOC_PANICKED` is returned. Either way `*out_len`
+/// is set to the length of the message; if that exceeds `out_buf_len`
+/// nothing is written and `AOC_BUFFER_TOO_SMALL` is returned so the caller
+/// can retry with a bigger buffer. The output is not NUL-terminated.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes, `out_buf` must
+/// point to `out_buf_len` writable bytes (or be null if `out_buf_len` is 0,
+/// to only ask for the length), and `out_len` must be a valid pointer to a
+/// `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut u8,
+    out_buf_len: usize,
+    out_len: *mut usize,
+) -> i32 {
+    if input_ptr.is_null() || (out_buf.is_null() && out_buf_len > 0) || out_len.is_null() {
+        return AOC_NULL_POINTER;
+    }
+
+    let input = std::slice::from_raw_parts(input_ptr, input_len);
+    let Ok(input) = std::str::from_utf8(input) else {
+        return AOC_INVALID_INPUT;
+    };
+
+    // Unwinding across the FFI boundary is undefined behavior, so panics
+    // are reported like any other failure
+    let result = catch_unwind(AssertUnwindSafe(|| solvers::solve(day, part, input)));
+    let (output, status) = match result {
+        Ok(Ok(answer)) => (answer.to_string(), AOC_OK),
+        Ok(Err(SolveError::UnknownDay(_))) => return AOC_UNKNOWN_DAY,
+        Ok(Err(SolveError::UnknownPart(_))) => return AOC_UNKNOWN_PART,
+        Ok(Err(SolveError::Failed(error))) => (error, AOC_SOLVE_FAILED),
+        Err(payload) => (panic_message(payload), AOC_PANICKED),
+    };
+
+    *out_len = output.len();
+    if output.len() > out_buf_len {
+        return AOC_BUFFER_TOO_SMALL;
+    }
+
+    if !output.is_empty() {
+        std::ptr::copy_nonoverlapping(output.as_ptr(), out_buf, output.len());
+    }
+
+    status
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "solver panicked".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &[u8] = day1::EXAMPLE.as_bytes();
+
+    // Call `aoc_solve` with `out_buf`, or a null buffer if `None`, returning
+    // the status and `*out_len`
+    fn solve(day: u8, part: u8, input: &[u8], out_buf: Option<&mut [u8]>) -> (i32, usize) {
+        let (out_ptr, out_buf_len) = match out_buf {
+            Some(out_buf) => (out_buf.as_mut_ptr(), out_buf.len()),
+            None => (std::ptr::null_mut(), 0),
+        };
+        let mut out_len = usize::MAX;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out_ptr,
+                out_buf_len,
+                &mut out_len,
+            )
+        };
+        (status, out_len)
+    }
+
+    #[test]
+    fn exact_buffer() {
+        let mut out_buf = [0; 5];
+        assert_eq!(solve(1, 1, EXAMPLE, Some(&mut out_buf)), (AOC_OK, 5));
+        assert_eq!(&out_buf, b"24000");
+    }
+
+    #[test]
+    fn bigger_buffer() {
+        let mut out_buf = [b'?'; 8];
+        assert_eq!(solve(1, 2, EXAMPLE, Some(&mut out_buf)), (AOC_OK, 5));
+        assert_eq!(&out_buf, b"45000???");
+    }
+
+    #[test]
+    fn null_buffer_asks_for_length() {
+        assert_eq!(solve(1, 1, EXAMPLE, None), (AOC_BUFFER_TOO_SMALL, 5));
+
+        let mut out_buf = [];
+        assert_eq!(
+            solve(1, 1, EXAMPLE, Some(&mut out_buf)),
+            (AOC_BUFFER_TOO_SMALL, 5)
+        );
+    }
+
+    #[test]
+    fn short_buffer() {
+        let mut out_buf = [b'?'; 4];
+        assert_eq!(
+            solve(1, 1, EXAMPLE, Some(&mut out_buf)),
+            (AOC_BUFFER_TOO_SMALL, 5)
+        );
+        assert_eq!(&out_buf, b"????");
+    }
+
+    #[test]
+    fn null_pointers() {
+        let mut out_buf = [0; 8];
+        let mut out_len = 0;
+        unsafe {
+            assert_eq!(
+                aoc_solve(
+                    1,
+                    1,
+                    std::ptr::null(),
+                    0,
+                    out_buf.as_mut_ptr(),
+                    out_buf.len(),
+                    &mut out_len,
+                ),
+                AOC_NULL_POINTER
+            );
+            assert_eq!(
+                aoc_solve(
+                    1,
+                    1,
+                    EXAMPLE.as_ptr(),
+                    EXAMPLE.len(),
+                    std::ptr::null_mut(),
+                    out_buf.len(),
+                    &mut out_len,
+                ),
+                AOC_NULL_POINTER
+            );
+            assert_eq!(
+                aoc_solve(
+                    1,
+                    1,
+                    EXAMPLE.as_ptr(),
+                    EXAMPLE.len(),
+                    out_buf.as_mut_ptr(),
+                    out_buf.len(),
+                    std::ptr::null_mut(),
+                ),
+                AOC_NULL_POINTER
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut out_buf = [0; 8];
+        let (status, _) = solve(1, 1, b"1000\n\xff\xfe\n", Some(&mut out_buf));
+        assert_eq!(status, AOC_INVALID_INPUT);
+    }
+
+    #[test]
+    fn unknown_day_or_part() {
+        let mut out_buf = [0; 8];
+        assert_eq!(solve(26, 1, EXAMPLE, Some(&mut out_buf)).0, AOC_UNKNOWN_DAY);
+        assert_eq!(solve(0, 1, EXAMPLE, Some(&mut out_buf)).0, AOC_UNKNOWN_DAY);
+        assert_eq!(solve(1, 3, EXAMPLE, Some(&mut out_buf)).0, AOC_UNKNOWN_PART);
+    }
+
+    #[test]
+    fn solve_failed() {
+        let mut out_buf = [0; 256];
+        let (status, out_len) = solve(1, 1, b"not a number\n", Some(&mut out_buf));
+        assert_eq!(status, AOC_SOLVE_FAILED);
+        assert!(out_len > 0);
+        assert!(std::str::from_utf8(&out_buf[..out_len]).is_ok());
+    }
+
+    #[test]
+    fn panicked() {
+        // Day 16 unwraps the starting valve `AA`, so a scan without it panics
+        let input = b"Valve BB has flow rate=0; tunnel leads to valve BB\n";
+        let mut out_buf = [0; 256];
+        let (status, out_len) = solve(16, 1, input, Some(&mut out_buf));
+        assert_eq!(status, AOC_PANICKED);
+        assert!(out_len > 0);
+        assert!(std::str::from_utf8(&out_buf[..out_len]).is_ok());
+    }
+
+    #[test]
+    fn panic_messages() {
+        let message = |f: fn()| panic_message(catch_unwind(f).unwrap_err());
+        assert_eq!(message(|| panic!("static")), "static");
+        assert_eq!(message(|| panic!("formatted {}", 1)), "formatted 1");
+        assert_eq!(message(|| std::panic::panic_any(1_u8)), "solver panicked");
+    }
+}
@@ -0,0 +1,313 @@
+use std::{
+    convert::Infallible,
+    fmt::Debug,
+    io::Read,
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use common::{
+    config::{Config, ConfigError},
+    render::Animation,
+};
+
+// Where to read the puzzle input from
+#[derive(Debug, Clone, clap::Args)]
+pub struct InputArgs {
+    /// Use the example input from the puzzle description instead of stdin
+    #[clap(long)]
+    pub example: bool,
+
+    /// Read input from a file, an https:// URL, `clipboard`, or `-` for stdin
+    #[clap(short, long = "input", default_value = "-")]
+    pub source: InputSource,
+}
+
+impl InputArgs {
+    pub fn read(&self, example: &str) -> Result<String, InputError> {
+        if self.example {
+            Ok(example.to_string())
+        } else {
+            self.source.read()
+        }
+    }
+
+    /// Like `read`, but for reading the input a bit at a time
+    pub fn open(&self, example: &'static str) -> Result<Box<dyn Read>, InputError> {
+        if self.example {
+            Ok(Box::new(example.as_bytes()))
+        } else {
+            self.source.open()
+        }
+    }
+}
+
+// Like `InputArgs`, for tools that combine several inputs
+#[derive(Debug, Clone, clap::Args)]
+pub struct MultiInputArgs {
+    /// Use the example input from the puzzle description instead of stdin
+    #[clap(long)]
+    pub example: bool,
+
+    /// Read input from a file, an https:// URL, `clipboard`, or `-` for
+    /// stdin. Can be passed more than once.
+    #[clap(short, long = "input", default_value = "-")]
+    pub sources: Vec<InputSource>,
+}
+
+impl MultiInputArgs {
+    /// Read every input, in the order they were passed
+    pub fn read_all(&self, example: &str) -> Result<Vec<String>, InputError> {
+        if self.example {
+            Ok(vec![example.to_string()])
+        } else {
+            self.sources.iter().map(InputSource::read).collect()
+        }
+    }
+}
+
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// The only host the session cookie is ever sent to
+pub const SESSION_HOST: &str = "adventofcode.com";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    Path(PathBuf),
+    // Sent with the session cookie from `AOC_SESSION` or aoc.toml, if set
+    // and the URL is on `SESSION_HOST`
+    Url(String),
+    Clipboard,
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Stdin => {
+                std::io::read_to_string(std::io::stdin()).map_err(|error| InputError::Io {
+                    source: self.clone(),
+                    error,
+                })
+            }
+            InputSource::Path(path) => {
+                std::fs::read_to_string(path).map_err(|error| InputError::Io {
+                    source: self.clone(),
+                    error,
+                })
+            }
+            InputSource::Url(url) => {
+                let response = fetch(url)?;
+                response.into_string().map_err(|error| InputError::Io {
+                    source: self.clone(),
+                    error,
+                })
+            }
+            InputSource::Clipboard => {
+                let mut clipboard = arboard::Clipboard::new().map_err(InputError::Clipboard)?;
+                clipboard.get_text().map_err(InputError::Clipboard)
+            }
+        }
+    }
+
+    /// Start reading the input without waiting for all of it. Only the
+    /// clipboard gets read up front.
+    pub fn open(&self) -> Result<Box<dyn Read>, InputError> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(std::io::stdin())),
+            InputSource::Path(path) => {
+                let file = std::fs::File::open(path).map_err(|error| InputError::Io {
+                    source: self.clone(),
+                    error,
+                })?;
+                Ok(Box::new(file))
+            }
+            InputSource::Url(url) => Ok(fetch(url)?.into_reader()),
+            InputSource::Clipboard => {
+                let text = self.read()?;
+                Ok(Box::new(std::io::Cursor::new(text)))
+            }
+        }
+    }
+}
+
+/// Whether `url` points at [`SESSION_HOST`], ignoring any user info, port,
+/// path, query, or fragment
+pub fn is_session_host(url: &str) -> bool {
+    let Some(rest) = url.strip_prefix("https://") else {
+        return false;
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+    host.eq_ignore_ascii_case(SESSION_HOST)
+}
+
+/// The session cookie from `AOC_SESSION`, or else from `session` in the
+/// `[aoc]` table of `config`
+pub fn session(config: &Config) -> Result<Option<String>, ConfigError> {
+    match std::env::var(SESSION_ENV_VAR) {
+        Ok(session) => Ok(Some(session)),
+        Err(_) => config.get("aoc", "session"),
+    }
+}
+
+// GET `url`, with the session cookie only if it's going to Advent of Code
+fn fetch(url: &str) -> Result<ureq::Response, InputError> {
+    let mut request = ureq::get(url);
+    if is_session_host(url) {
+        let config = Config::load().map_err(InputError::Config)?;
+        if let Some(session) = session(&config).map_err(InputError::Config)? {
+            request = request.set("Cookie", &format!("session={session}"));
+        }
+    }
+
+    request
+        .call()
+        .map_err(|error| InputError::Http(Box::new(error)))
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = match s {
+            "-" => InputSource::Stdin,
+            "clipboard" => InputSource::Clipboard,
+            url if url.starts_with("https://") => InputSource::Url(url.to_string()),
+            path => InputSource::Path(PathBuf::from(path)),
+        };
+        Ok(source)
+    }
+}
+
+impl std::fmt::Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Url(url) => write!(f, "{url}"),
+            InputSource::Clipboard => write!(f, "clipboard"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io {
+        source: InputSource,
+        error: std::io::Error,
+    },
+    Http(Box<ureq::Error>),
+    Clipboard(arboard::Error),
+    Config(ConfigError),
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io { source, error } => write!(f, "failed to read {source}: {error}"),
+            InputError::Http(error) => write!(f, "failed to fetch input: {error}"),
+            InputError::Clipboard(error) => write!(f, "failed to read clipboard: {error}"),
+            InputError::Config(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+// Flags for days that can animate their simulation in the terminal
+#[derive(Debug, Clone, clap::Args)]
+pub struct DisplayArgs {
+    /// Redraw the simulation in the terminal after each step
+    #[clap(short, long)]
+    pub display: bool,
+
+    /// Milliseconds to wait between frames
+    #[clap(short, long)]
+    pub rate: Option<u64>,
+}
+
+impl DisplayArgs {
+    pub fn animation(&self, default_rate: u64) -> Animation {
+        let rate = self.rate.unwrap_or(default_rate);
+        Animation::new(self.display, Duration::from_millis(rate))
+    }
+}
+
+// Which part of the puzzle to solve
+#[derive(Debug, Clone, clap::Args)]
+pub struct PartArg {
+    #[clap(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+}
+
+// Reference implementations a day can be checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Reference {
+    Naive,
+}
+
+// For days with more than one algorithm for the same answer
+#[derive(Debug, Clone, clap::Args)]
+pub struct CheckArgs {
+    /// Also run a reference implementation and fail if the answers differ
+    #[clap(long, value_enum)]
+    pub check_against: Option<Reference>,
+}
+
+impl CheckArgs {
+    /// Run `solve`, and if `--check-against naive` was passed, run `naive`
+    /// too and compare the answers and timings.
+    pub fn run<T>(
+        &self,
+        solve: impl FnOnce() -> T,
+        naive: impl FnOnce() -> T,
+    ) -> Result<T, MismatchError>
+    where
+        T: PartialEq + Debug,
+    {
+        let start = Instant::now();
+        let answer = solve();
+        let elapsed = start.elapsed();
+
+        let Some(Reference::Naive) = self.check_against else {
+            return Ok(answer);
+        };
+
+        let start = Instant::now();
+        let expected = naive();
+        let naive_elapsed = start.elapsed();
+
+        if answer != expected {
+            return Err(MismatchError {
+                expected: format!("{expected:?}"),
+                actual: format!("{answer:?}"),
+            });
+        }
+
+        let speedup = naive_elapsed.as_secs_f64() / elapsed.as_secs_f64();
+        eprintln!("Answer matches naive: {elapsed:?} vs {naive_elapsed:?} ({speedup:.1}x speedup)");
+
+        Ok(answer)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MismatchError {
+    pub expected: String,
+    pub actual: String,
+}
+
+impl std::fmt::Display for MismatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "answer {} does not match naive answer {}",
+            self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for MismatchError {}
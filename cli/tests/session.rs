@@ -0,0 +1,50 @@
+use cli::{is_session_host, SESSION_ENV_VAR};
+use common::config::Config;
+
+#[test]
+fn advent_of_code_urls() {
+    for url in [
+        "https://adventofcode.com/2022/day/1/input",
+        "https://adventofcode.com",
+        "https://AdventOfCode.com/2022/day/1/input",
+        "https://adventofcode.com:443/2022/day/1/input",
+        "https://adventofcode.com?day=1",
+    ] {
+        assert!(is_session_host(url), "{url}");
+    }
+}
+
+#[test]
+fn other_hosts() {
+    for url in [
+        "https://example.com/2022/day/1/input",
+        "https://adventofcode.com.example.com/input",
+        "https://evil-adventofcode.com/input",
+        "https://example.com/https://adventofcode.com/",
+        "https://adventofcode.com@example.com/input",
+        "http://adventofcode.com/2022/day/1/input",
+        "adventofcode.com",
+    ] {
+        assert!(!is_session_host(url), "{url}");
+    }
+}
+
+#[test]
+fn session_from_env_or_config() {
+    let path = std::env::temp_dir().join(format!("aoc-session-{}.toml", std::process::id()));
+    std::fs::write(&path, "[aoc]\nsession = \"from-config\"\n").unwrap();
+    let config = Config::from_path(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    std::env::remove_var(SESSION_ENV_VAR);
+    assert_eq!(cli::session(&Config::default()).unwrap(), None);
+    assert_eq!(
+        cli::session(&config).unwrap().as_deref(),
+        Some("from-config")
+    );
+
+    // The environment takes precedence
+    std::env::set_var(SESSION_ENV_VAR, "from-env");
+    assert_eq!(cli::session(&config).unwrap().as_deref(), Some("from-env"));
+    std::env::remove_var(SESSION_ENV_VAR);
+}
@@ -0,0 +1,100 @@
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+// A puzzle answer: usually a number, sometimes a short string, and
+// occasionally letters drawn as ASCII art
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Grid(String),
+}
+
+impl Answer {
+    // Grid rows with trailing whitespace and surrounding blank lines removed
+    fn grid_rows(grid: &str) -> impl Iterator<Item = &str> {
+        grid.trim_matches(&['\n', '\r'][..])
+            .lines()
+            .map(|row| row.trim_end())
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Integer(a), Answer::Integer(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Grid(a), Answer::Grid(b)) => Self::grid_rows(a).eq(Self::grid_rows(b)),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(answer) => write!(f, "{answer}"),
+            Answer::Text(answer) => write!(f, "{answer}"),
+            Answer::Grid(answer) => write!(f, "{answer}"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Multi-line answers are grids, anything else that parses as a number
+    /// is an integer, and the rest is text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answer = s.trim_matches(&['\n', '\r'][..]);
+        if answer.contains('\n') {
+            return Ok(Answer::Grid(answer.to_string()));
+        }
+
+        let answer = answer.trim();
+        match answer.parse() {
+            Ok(answer) => Ok(Answer::Integer(answer)),
+            Err(_) => Ok(Answer::Text(answer.to_string())),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(answer: $t) -> Self {
+                    Answer::Integer(answer.into())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_try_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(answer: $t) -> Self {
+                    Answer::Integer(answer.try_into().expect("answer out of range"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, u32);
+impl_try_from_integer!(u64, usize);
+
+impl From<i64> for Answer {
+    fn from(answer: i64) -> Self {
+        Answer::Integer(answer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::Text(answer)
+    }
+}
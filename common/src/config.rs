@@ -0,0 +1,123 @@
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+
+// Defaults for command-line flags, grouped into one table per day:
+//
+//     [day15]
+//     search_row = 2000000
+//
+// Each binary should prefer an explicit flag over the config value.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    path: Option<PathBuf>,
+    values: toml::value::Table,
+}
+
+impl Config {
+    /// Load `aoc.toml` from the current directory or the nearest parent
+    /// directory that has one. Returns an empty config if none exists.
+    pub fn load() -> Result<Self, ConfigError> {
+        let current_dir = std::env::current_dir().map_err(|error| ConfigError::Io {
+            path: PathBuf::from("."),
+            error,
+        })?;
+
+        let path = current_dir
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .find(|path| path.is_file());
+        match path {
+            Some(path) => Self::from_path(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path).map_err(|error| ConfigError::Io {
+            path: path.to_owned(),
+            error,
+        })?;
+        let value = contents
+            .parse::<toml::Value>()
+            .map_err(|error| ConfigError::Parse {
+                path: path.to_owned(),
+                error,
+            })?;
+        let values = match value {
+            toml::Value::Table(values) => values,
+            _ => toml::value::Table::new(),
+        };
+
+        Ok(Self {
+            path: Some(path.to_owned()),
+            values,
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn get<T>(&self, section: &str, key: &str) -> Result<Option<T>, ConfigError>
+    where
+        T: DeserializeOwned,
+    {
+        let value = self
+            .values
+            .get(section)
+            .and_then(|section| section.get(key));
+        let Some(value) = value else {
+            return Ok(None);
+        };
+
+        let value = value
+            .clone()
+            .try_into()
+            .map_err(|error| ConfigError::InvalidValue {
+                section: section.to_string(),
+                key: key.to_string(),
+                error,
+            })?;
+        Ok(Some(value))
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+    InvalidValue {
+        section: String,
+        key: String,
+        error: toml::de::Error,
+    },
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io { path, error } => {
+                write!(f, "failed to read {}: {error}", path.display())
+            }
+            ConfigError::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+            ConfigError::InvalidValue {
+                section,
+                key,
+                error,
+            } => write!(f, "invalid value for {section}.{key}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
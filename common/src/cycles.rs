@@ -0,0 +1,108 @@
+use std::{collections::HashMap, hash::Hash};
+
+// A sequence that eventually repeats: the states at `start` and
+// `start + length` are the same, and so on forever after
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// Extrapolate a value that grows by the same amount every cycle (like a
+    /// running total) out to step `n`. `values[i]` is the value at step `i`,
+    /// where step 0 is the initial state, and must cover one full cycle.
+    /// Returns `None` if the value at step `n` doesn't fit in an `i64`.
+    pub fn extrapolate(&self, values: &[i64], n: usize) -> Option<i64> {
+        assert!(
+            values.len() > self.start + self.length,
+            "values must cover a full cycle"
+        );
+
+        if n < self.start {
+            return Some(values[n]);
+        }
+
+        let growth = values[self.start + self.length] - values[self.start];
+        let cycles = i64::try_from((n - self.start) / self.length).ok()?;
+        growth
+            .checked_mul(cycles)?
+            .checked_add(values[self.equivalent_step(n)])
+    }
+}
+
+/// Find the cycle in the sequence `initial`, `step(initial)`, ... using
+/// Brent's algorithm. Only a few states are kept at a time, but `step` is
+/// called several times per state. Never returns if there is no cycle.
+pub fn brent<S>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: PartialEq + Clone,
+{
+    // Find the cycle length by racing the hare ahead in powers of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then find the start by keeping the two exactly one cycle apart
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Find the cycle by stepping `state` in place and remembering the `key` of
+/// every state seen so far. `key` should capture everything that affects
+/// future steps, but can leave out things like running totals. Returns
+/// `None` if no cycle is found within `max_steps` steps.
+pub fn find_cycle<S, K>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    max_steps: usize,
+) -> Option<Cycle>
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    for n in 0..=max_steps {
+        if let Some(start) = seen.insert(key(state), n) {
+            return Some(Cycle {
+                start,
+                length: n - start,
+            });
+        }
+
+        step(state);
+    }
+
+    None
+}
@@ -0,0 +1,94 @@
+use std::{fmt::Display, ops::Range, str::FromStr};
+
+use miette::{Diagnostic, LabeledSpan, SourceCode, SourceSpan};
+
+// A parse error pointing at the part of the puzzle input that caused it.
+// Shows the line and column when displayed, and the source snippet when
+// reported through miette.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    message: String,
+    source: String,
+    span: Range<usize>,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    /// Create an error for the byte range `span` of `input`.
+    pub fn new(input: &str, span: Range<usize>, message: impl Display) -> Self {
+        let before = &input[..span.start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Self {
+            message: message.to_string(),
+            source: input.to_string(),
+            span,
+            line,
+            column,
+        }
+    }
+
+    /// Create an error for `part`, which must be a slice of `input` (like a
+    /// line from `input.lines()`).
+    pub fn at(input: &str, part: &str, message: impl Display) -> Self {
+        let start = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&start| start + part.len() <= input.len())
+            .expect("part is not a slice of input");
+
+        Self::new(input, start..start + part.len(), message)
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Diagnostic for ParseError {
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let span = SourceSpan::from(self.span.clone());
+        let label = LabeledSpan::new_with_span(Some(self.message.clone()), span);
+        Some(Box::new(std::iter::once(label)))
+    }
+}
+
+/// Parse each line of `input`, pointing any error at the line it came from.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    crate::input::lines(input)
+        .map(|line| {
+            line.parse()
+                .map_err(|error| ParseError::at(input, line, format_args!("{error:#}")))
+        })
+        .collect()
+}
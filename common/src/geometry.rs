@@ -0,0 +1,369 @@
+use std::{
+    num::ParseIntError,
+    ops::{Add, AddAssign, Mul, Neg, RangeInclusive, Sub},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self { x: 0, y: 0 };
+
+    pub fn manhattan_distance(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn new(point: Point) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    // The smallest bounds containing every point, or `None` if there are none
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for point in points {
+            bounds.add(point);
+        }
+
+        Some(bounds)
+    }
+
+    pub fn add(&mut self, point: Point) {
+        self.min.x = std::cmp::min(self.min.x, point.x);
+        self.min.y = std::cmp::min(self.min.y, point.y);
+        self.max.x = std::cmp::max(self.max.x, point.x);
+        self.max.y = std::cmp::max(self.max.y, point.y);
+    }
+
+    pub fn union(&mut self, bounds: &Bounds) {
+        self.add(bounds.min);
+        self.add(bounds.max);
+    }
+
+    pub fn x_bounds(&self) -> RangeInclusive<i32> {
+        self.min.x..=self.max.x
+    }
+
+    pub fn y_bounds(&self) -> RangeInclusive<i32> {
+        self.min.y..=self.max.y
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.x_bounds().contains(&point.x) && self.y_bounds().contains(&point.y)
+    }
+
+    pub fn width(&self) -> i32 {
+        (self.max.x - self.min.x) + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        (self.max.y - self.min.y) + 1
+    }
+
+    // Column by column, then top to bottom within each column
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Self { min, max } = *self;
+        (min.x..=max.x).flat_map(move |x| (min.y..=max.y).map(move |y| Point { x, y }))
+    }
+
+    // Every point in row `row`, even if it's outside the bounds vertically
+    pub fn points_row(&self, row: i32) -> impl Iterator<Item = Point> {
+        self.x_bounds().map(move |x| Point { x, y: row })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub const ORIGIN: Self = Self { x: 0, y: 0, z: 0 };
+
+    pub fn manhattan_distance(&self, other: &Point3) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    // The 6 points sharing a face with this one
+    pub fn face_neighbors(self) -> impl Iterator<Item = Point3> {
+        Vector3::FACE_OFFSETS
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+
+    // The 26 points sharing a face, edge, or corner with this one
+    pub fn all_neighbors(self) -> impl Iterator<Item = Point3> {
+        (-1..=1).flat_map(move |x| {
+            (-1..=1).flat_map(move |y| {
+                (-1..=1).filter_map(move |z| {
+                    let offset = Vector3 { x, y, z };
+                    (offset != Vector3::ZERO).then_some(self + offset)
+                })
+            })
+        })
+    }
+}
+
+impl std::fmt::Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = ParsePointError;
+
+    /// Parse a point written as `x,y,z`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coordinates = s.trim().split(',');
+        let mut next = || -> Result<i32, ParsePointError> {
+            let coordinate = coordinates
+                .next()
+                .ok_or_else(|| ParsePointError::WrongDimensions(s.to_string()))?;
+            coordinate
+                .trim()
+                .parse()
+                .map_err(ParsePointError::InvalidCoordinate)
+        };
+
+        let point = Point3 {
+            x: next()?,
+            y: next()?,
+            z: next()?,
+        };
+
+        if coordinates.next().is_some() {
+            return Err(ParsePointError::WrongDimensions(s.to_string()));
+        }
+
+        Ok(point)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vector3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Vector3 {
+    pub const ZERO: Self = Self { x: 0, y: 0, z: 0 };
+
+    pub const FACE_OFFSETS: [Vector3; 6] = [
+        Vector3 { x: 1, y: 0, z: 0 },
+        Vector3 { x: -1, y: 0, z: 0 },
+        Vector3 { x: 0, y: 1, z: 0 },
+        Vector3 { x: 0, y: -1, z: 0 },
+        Vector3 { x: 0, y: 0, z: 1 },
+        Vector3 { x: 0, y: 0, z: -1 },
+    ];
+
+    // Clamp each component to -1, 0, or 1
+    pub fn normalize(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum(),
+        }
+    }
+
+    pub fn dot(self, other: Vector3) -> i32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl Add<Vector3> for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Vector3) -> Self::Output {
+        Point3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl AddAssign<Vector3> for Point3 {
+    fn add_assign(&mut self, rhs: Vector3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Point3> for Point3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Point3) -> Self::Output {
+        Vector3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Add<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, rhs: Vector3) -> Self::Output {
+        Vector3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Vector3) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul<i32> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Vector3 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Self::Output {
+        Vector3 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Bounds3 {
+    pub fn new(point: Point3) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    // The smallest bounds containing every point, or `None` if there are none
+    pub fn from_points(points: impl IntoIterator<Item = Point3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for point in points {
+            bounds.add(point);
+        }
+
+        Some(bounds)
+    }
+
+    pub fn add(&mut self, point: Point3) {
+        self.min.x = std::cmp::min(self.min.x, point.x);
+        self.min.y = std::cmp::min(self.min.y, point.y);
+        self.min.z = std::cmp::min(self.min.z, point.z);
+        self.max.x = std::cmp::max(self.max.x, point.x);
+        self.max.y = std::cmp::max(self.max.y, point.y);
+        self.max.z = std::cmp::max(self.max.z, point.z);
+    }
+
+    // Grow the bounds by `amount` in every direction
+    pub fn expand(&self, amount: i32) -> Self {
+        let offset = Vector3 {
+            x: amount,
+            y: amount,
+            z: amount,
+        };
+        Self {
+            min: self.min + -offset,
+            max: self.max + offset,
+        }
+    }
+
+    pub fn x_bounds(&self) -> RangeInclusive<i32> {
+        self.min.x..=self.max.x
+    }
+
+    pub fn y_bounds(&self) -> RangeInclusive<i32> {
+        self.min.y..=self.max.y
+    }
+
+    pub fn z_bounds(&self) -> RangeInclusive<i32> {
+        self.min.z..=self.max.z
+    }
+
+    pub fn contains(&self, point: Point3) -> bool {
+        self.x_bounds().contains(&point.x)
+            && self.y_bounds().contains(&point.y)
+            && self.z_bounds().contains(&point.z)
+    }
+
+    pub fn width(&self) -> i32 {
+        (self.max.x - self.min.x) + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        (self.max.y - self.min.y) + 1
+    }
+
+    pub fn depth(&self) -> i32 {
+        (self.max.z - self.min.z) + 1
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let Self { min, max } = *self;
+        (min.x..=max.x).flat_map(move |x| {
+            (min.y..=max.y).flat_map(move |y| (min.z..=max.z).map(move |z| Point3 { x, y, z }))
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePointError {
+    WrongDimensions(String),
+    InvalidCoordinate(ParseIntError),
+}
+
+impl std::fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePointError::WrongDimensions(point) => {
+                write!(f, "expected 3 comma-separated coordinates, got {point:?}")
+            }
+            ParsePointError::InvalidCoordinate(error) => write!(f, "invalid coordinate: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParsePointError {}
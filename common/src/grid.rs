@@ -0,0 +1,224 @@
+use std::ops::{Index, IndexMut};
+
+pub const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+// One of the directions in `ORTHOGONAL_OFFSETS`, where up is toward row 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise, starting from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> (isize, isize) {
+        ORTHOGONAL_OFFSETS[self as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<u8> {
+    /// Parse a rectangular grid of bytes, one row per line.
+    pub fn parse(input: &str) -> Result<Self, ParseGridError> {
+        let mut width = None;
+        let mut cells = vec![];
+        for (row, line) in crate::input::lines(input).enumerate() {
+            match width {
+                Some(width) if width != line.len() => {
+                    return Err(ParseGridError::MismatchedWidth {
+                        row,
+                        expected: width,
+                        actual: line.len(),
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    width = Some(line.len());
+                }
+            }
+
+            cells.extend_from_slice(line.as_bytes());
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self { width, cells }),
+            _ => Err(ParseGridError::Empty),
+        }
+    }
+
+    /// Parse a grid of bytes like [`Grid::parse`], but allow rows of
+    /// different lengths, padding the short ones out with `fill`.
+    pub fn parse_padded(input: &str, fill: u8) -> Result<Self, ParseGridError> {
+        let rows: Vec<&str> = crate::input::lines(input).collect();
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        if width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in rows {
+            cells.extend_from_slice(row.as_bytes());
+            cells.resize(cells.len() + width - row.len(), fill);
+        }
+
+        Ok(Self { width, cells })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0, "grid width must be non-zero");
+        assert_eq!(cells.len() % width, 0, "grid cells must fill every row");
+
+        Self { width, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        let index = self.index_of(position)?;
+        self.cells.get(index)
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        let index = self.index_of(position)?;
+        self.cells.get_mut(index)
+    }
+
+    pub fn index_of(&self, position: Position) -> Option<usize> {
+        if position.row < self.height() && position.col < self.width() {
+            Some(position.row * self.width + position.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn position_of(&self, index: usize) -> Position {
+        Position {
+            row: index / self.width,
+            col: index % self.width,
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Position {
+            row: index / width,
+            col: index % width,
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Move `position` by `(row, col)`, returning `None` if the result
+    /// would fall outside the grid.
+    pub fn offset(&self, position: Position, offset: (isize, isize)) -> Option<Position> {
+        let (offset_row, offset_col) = offset;
+
+        let row = position.row.checked_add_signed(offset_row)?;
+        let col = position.col.checked_add_signed(offset_col)?;
+        let position = Position { row, col };
+
+        self.index_of(position).map(|_| position)
+    }
+
+    // Up, right, down, and left of `position`, skipping any outside the grid
+    pub fn orthogonal_neighbors(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL_OFFSETS
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            cells: self.cells.into_iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).expect("position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position).expect("position out of bounds")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    MismatchedWidth {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl std::fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "grid is empty"),
+            ParseGridError::MismatchedWidth {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "expected row {row} to match width {expected}, but was {actual}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
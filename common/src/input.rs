@@ -0,0 +1,128 @@
+use std::{
+    io::{BufRead, Lines},
+    str::FromStr,
+};
+
+/// Iterate over the lines of `input`, ignoring any trailing blank lines.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end_matches(['\n', '\r']).lines()
+}
+
+/// Iterate over the blocks of `input` separated by one or more blank lines.
+/// Each block keeps its inner newlines but has no trailing newline.
+pub fn blocks(input: &str) -> Blocks<'_> {
+    Blocks { rest: input }
+}
+
+pub struct Blocks<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip any blank lines before the start of the block
+        loop {
+            let (line, rest) = split_line(self.rest)?;
+            if !line.trim().is_empty() {
+                break;
+            }
+            self.rest = rest;
+        }
+
+        let block = self.rest;
+        let mut block_len = 0;
+        while let Some((line, rest)) = split_line(self.rest) {
+            if line.trim().is_empty() {
+                break;
+            }
+
+            block_len = block.len() - rest.len();
+            self.rest = rest;
+        }
+
+        let block = block[..block_len].trim_end_matches(['\n', '\r']);
+        Some(block)
+    }
+}
+
+/// Like [`blocks`], but reading lines from `reader`. Each block is the
+/// lines in it, without line endings.
+pub fn read_blocks<R: BufRead>(reader: R) -> ReadBlocks<R> {
+    ReadBlocks {
+        lines: reader.lines(),
+    }
+}
+
+pub struct ReadBlocks<R> {
+    lines: Lines<R>,
+}
+
+impl<R: BufRead> Iterator for ReadBlocks<R> {
+    type Item = std::io::Result<Vec<String>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut block = vec![];
+        loop {
+            match self.lines.next() {
+                Some(Ok(line)) if line.trim().is_empty() => {
+                    if !block.is_empty() {
+                        return Some(Ok(block));
+                    }
+                }
+                Some(Ok(line)) => {
+                    block.push(line);
+                }
+                Some(Err(error)) => {
+                    return Some(Err(error));
+                }
+                None => {
+                    return (!block.is_empty()).then_some(Ok(block));
+                }
+            }
+        }
+    }
+}
+
+fn split_line(s: &str) -> Option<(&str, &str)> {
+    if s.is_empty() {
+        return None;
+    }
+
+    match s.split_once('\n') {
+        Some((line, rest)) => Some((line, rest)),
+        None => Some((s, "")),
+    }
+}
+
+/// Parse a list of numbers separated by whitespace and/or commas.
+pub fn numbers<T>(s: &str) -> Result<Vec<T>, ParseNumbersError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token.parse().map_err(|error: T::Err| ParseNumbersError {
+                token: token.to_string(),
+                reason: error.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNumbersError {
+    pub token: String,
+    pub reason: String,
+}
+
+impl std::fmt::Display for ParseNumbersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid number {:?}: {}", self.token, self.reason)
+    }
+}
+
+impl std::error::Error for ParseNumbersError {}
@@ -0,0 +1,15 @@
+pub mod answer;
+pub mod config;
+pub mod cycles;
+pub mod diagnostics;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod modular;
+pub mod ocr;
+pub mod parallel;
+pub mod pathfinding;
+pub mod ranges;
+pub mod render;
+pub mod rng;
+pub mod simulation;
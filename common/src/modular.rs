@@ -0,0 +1,161 @@
+use std::ops::{Add, Mul};
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// Compute `base.pow(exponent) % modulus` without overflowing.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be non-zero");
+
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// Move `index` by `offset` around a ring of `len` slots, wrapping in
+/// either direction. `offset` can be many times larger than `len`.
+pub fn wrap_index(index: usize, offset: i64, len: usize) -> usize {
+    assert!(len > 0, "len must be non-zero");
+
+    let offset = offset.rem_euclid(len as i64) as usize;
+    (index % len + offset) % len
+}
+
+/// `a * b % modulus`, without overflowing
+pub fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    let product = u128::from(a) * u128::from(b) % u128::from(modulus);
+    product.try_into().unwrap()
+}
+
+/// `(a + b) % modulus`, without overflowing
+pub fn add_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    let sum = (u128::from(a) + u128::from(b)) % u128::from(modulus);
+    sum.try_into().unwrap()
+}
+
+/// A value tracked only by its remainder modulo each of a set of moduli,
+/// so it can be added to and multiplied indefinitely without growing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Residues {
+    moduli: Vec<u64>,
+    residues: Vec<u64>,
+}
+
+impl Residues {
+    pub fn new(value: u64, moduli: &[u64]) -> Self {
+        assert!(
+            moduli.iter().all(|&modulus| modulus > 0),
+            "moduli must be non-zero"
+        );
+
+        Self {
+            moduli: moduli.to_vec(),
+            residues: moduli.iter().map(|&modulus| value % modulus).collect(),
+        }
+    }
+
+    pub fn moduli(&self) -> &[u64] {
+        &self.moduli
+    }
+
+    pub fn residue(&self, modulus: u64) -> Option<u64> {
+        let index = self.moduli.iter().position(|&m| m == modulus)?;
+        Some(self.residues[index])
+    }
+
+    pub fn is_divisible_by(&self, divisor: u64) -> bool {
+        let residue = self
+            .residue(divisor)
+            .unwrap_or_else(|| panic!("modulus {divisor} is not tracked"));
+        residue == 0
+    }
+
+    /// Replace each residue with `f(residue, modulus)`, which should be
+    /// less than `modulus`. Unlike the operators, this doesn't allocate.
+    pub fn update(&mut self, f: impl Fn(u64, u64) -> u64) {
+        for (residue, &modulus) in self.residues.iter_mut().zip(&self.moduli) {
+            *residue = f(*residue, modulus);
+        }
+    }
+
+    fn zip_with(&self, other: &Residues, f: impl Fn(u64, u64, u64) -> u64) -> Residues {
+        assert_eq!(self.moduli, other.moduli, "residues must share moduli");
+
+        let residues = self
+            .moduli
+            .iter()
+            .zip(&self.residues)
+            .zip(&other.residues)
+            .map(|((&modulus, &a), &b)| f(a, b, modulus))
+            .collect();
+        Residues {
+            moduli: self.moduli.clone(),
+            residues,
+        }
+    }
+}
+
+impl Add for &Residues {
+    type Output = Residues;
+
+    fn add(self, other: &Residues) -> Residues {
+        self.zip_with(other, add_mod)
+    }
+}
+
+impl Mul for &Residues {
+    type Output = Residues;
+
+    fn mul(self, other: &Residues) -> Residues {
+        self.zip_with(other, mul_mod)
+    }
+}
+
+impl Add<u64> for &Residues {
+    type Output = Residues;
+
+    fn add(self, value: u64) -> Residues {
+        self + &Residues::new(value, &self.moduli)
+    }
+}
+
+impl Mul<u64> for &Residues {
+    type Output = Residues;
+
+    fn mul(self, value: u64) -> Residues {
+        self * &Residues::new(value, &self.moduli)
+    }
+}
+
+impl std::fmt::Display for Residues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (residue, modulus)) in self.residues.iter().zip(&self.moduli).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{residue} (mod {modulus})")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::grid::{Grid, ParseGridError, Position};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+// Glyphs are separated by one blank column
+const GLYPH_STRIDE: usize = GLYPH_WIDTH + 1;
+
+const GLYPHS: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Read the letters drawn in a grid of lit (`true`) and unlit pixels.
+pub fn decode(pixels: &Grid<bool>) -> Result<String, OcrError> {
+    if pixels.height() != GLYPH_HEIGHT {
+        return Err(OcrError::UnexpectedHeight(pixels.height()));
+    }
+
+    let num_glyphs = (pixels.width() + 1) / GLYPH_STRIDE;
+    (0..num_glyphs)
+        .map(|index| {
+            let glyph = (0..GLYPH_HEIGHT)
+                .map(|row| {
+                    (0..GLYPH_WIDTH)
+                        .map(|col| {
+                            let position = Position {
+                                row,
+                                col: index * GLYPH_STRIDE + col,
+                            };
+                            match pixels.get(position) {
+                                Some(true) => '#',
+                                Some(false) | None => '.',
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            GLYPHS
+                .iter()
+                .find(|&&(_, pattern)| pattern == glyph)
+                .map(|&(letter, _)| letter)
+                .ok_or(OcrError::UnknownGlyph { index, glyph })
+        })
+        .collect()
+}
+
+/// Like [`decode`], for text drawn with `#` for lit pixels.
+pub fn decode_str(screen: &str) -> Result<String, OcrError> {
+    let pixels = Grid::parse(screen).map_err(OcrError::InvalidGrid)?;
+    decode(&pixels.map(|pixel| pixel == b'#'))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    InvalidGrid(ParseGridError),
+    UnexpectedHeight(usize),
+    UnknownGlyph { index: usize, glyph: String },
+}
+
+impl std::fmt::Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::InvalidGrid(error) => write!(f, "invalid screen: {error}"),
+            OcrError::UnexpectedHeight(height) => {
+                write!(f, "expected {GLYPH_HEIGHT} rows of pixels, found {height}")
+            }
+            OcrError::UnknownGlyph { index, glyph } => {
+                write!(f, "unrecognized glyph at position {index}:\n{glyph}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
@@ -0,0 +1,55 @@
+// Iterators that run on rayon's thread pool when the `parallel` feature is
+// enabled, and as plain sequential iterators otherwise. Callers should only
+// use methods that exist on both `Iterator` and rayon's `ParallelIterator`
+// (plus `find_any`, which is provided for sequential iterators below).
+
+pub mod prelude {
+    pub use super::IntoMaybeParallelIterator;
+
+    #[cfg(feature = "parallel")]
+    pub use rayon::iter::ParallelIterator;
+
+    #[cfg(not(feature = "parallel"))]
+    pub use super::FindAny;
+}
+
+pub trait IntoMaybeParallelIterator {
+    type Iter;
+
+    fn into_maybe_par_iter(self) -> Self::Iter;
+}
+
+#[cfg(feature = "parallel")]
+impl<T> IntoMaybeParallelIterator for T
+where
+    T: rayon::iter::IntoParallelIterator,
+{
+    type Iter = T::Iter;
+
+    fn into_maybe_par_iter(self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> IntoMaybeParallelIterator for T
+where
+    T: IntoIterator,
+{
+    type Iter = T::IntoIter;
+
+    fn into_maybe_par_iter(self) -> Self::Iter {
+        self.into_iter()
+    }
+}
+
+// Matches rayon's `find_any`, which returns whichever match is found first
+#[cfg(not(feature = "parallel"))]
+pub trait FindAny: Iterator + Sized {
+    fn find_any(mut self, predicate: impl Fn(&Self::Item) -> bool) -> Option<Self::Item> {
+        self.find(|item| predicate(item))
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: Iterator> FindAny for I {}
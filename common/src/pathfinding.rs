@@ -0,0 +1,161 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Find the shortest path (by number of steps) from `start` to a node
+/// matching `success`. The returned path includes both endpoints.
+pub fn bfs<N, FN, IN>(
+    start: N,
+    mut successors: FN,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    parents.insert(start.clone(), None);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if success(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+
+        for successor in successors(&node) {
+            if !parents.contains_key(&successor) {
+                parents.insert(successor.clone(), Some(node.clone()));
+                queue.push_back(successor);
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the cheapest path from `start` to a node matching `success`,
+/// returning the path (including both endpoints) and its total cost.
+pub fn dijkstra<N, C, FN, IN>(
+    start: N,
+    successors: FN,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Like [`dijkstra`], guided by `heuristic`, which must never overestimate
+/// the remaining cost to reach a goal. The heuristic doesn't need to be
+/// consistent: a node is expanded again if a cheaper path to it turns up
+/// after it was expanded.
+pub fn astar<N, C, FN, IN>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    // Nodes are tracked by index so `N` doesn't need to implement `Ord`
+    let mut nodes = vec![start.clone()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut best: Vec<(C, Option<usize>)> = vec![(C::default(), None)];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // Skip entries for nodes that have since been reached more cheaply
+        let (best_cost, _) = best[index];
+        if cost > best_cost {
+            continue;
+        }
+
+        let node = nodes[index].clone();
+        if success(&node) {
+            let mut path = vec![node];
+            let mut current = index;
+            while let (_, Some(parent)) = best[current] {
+                path.push(nodes[parent].clone());
+                current = parent;
+            }
+            path.reverse();
+
+            return Some((path, cost));
+        }
+
+        for (successor, step_cost) in successors(&node) {
+            let new_cost = cost + step_cost;
+            let successor_index = match indices.get(&successor) {
+                Some(&successor_index) => {
+                    let (old_cost, _) = best[successor_index];
+                    if old_cost <= new_cost {
+                        continue;
+                    }
+
+                    best[successor_index] = (new_cost, Some(index));
+                    successor_index
+                }
+                None => {
+                    let successor_index = nodes.len();
+                    nodes.push(successor.clone());
+                    indices.insert(successor.clone(), successor_index);
+                    best.push((new_cost, Some(index)));
+                    successor_index
+                }
+            };
+
+            let estimate = new_cost + heuristic(&successor);
+            queue.push(Reverse((estimate, new_cost, successor_index)));
+        }
+    }
+
+    None
+}
+
+/// Collect every node reachable from `start`, including `start` itself.
+pub fn flood_fill<N, FN, IN>(start: N, mut successors: FN) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut reached = HashSet::from([start.clone()]);
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        for successor in successors(&node) {
+            if reached.insert(successor.clone()) {
+                stack.push(successor);
+            }
+        }
+    }
+
+    reached
+}
+
+fn reconstruct_path<N>(parents: &HashMap<N, Option<N>>, end: N) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+{
+    let mut path = vec![end];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+
+    path
+}
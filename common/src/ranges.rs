@@ -0,0 +1,218 @@
+use std::ops::RangeInclusive;
+
+pub trait Integer: Copy + Ord + std::fmt::Debug {
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+
+    // Number of integers in `start..=end`, saturating at `u64::MAX`
+    fn span(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Integer for $ty {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn span(start: Self, end: Self) -> u64 {
+                    let span = (end as i128) - (start as i128) + 1;
+                    span.try_into().unwrap_or(u64::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i32, i64, u32, u64, usize);
+
+// A set of integers, stored as sorted, disjoint, non-adjacent ranges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = &RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let (mut start, mut end) = range.into_inner();
+        let mut inserted = false;
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        for existing in self.ranges.drain(..) {
+            if ends_before(&existing, &(start..=end)) {
+                ranges.push(existing);
+            } else if ends_before(&(start..=end), &existing) {
+                if !inserted {
+                    ranges.push(start..=end);
+                    inserted = true;
+                }
+                ranges.push(existing);
+            } else {
+                start = std::cmp::min(start, *existing.start());
+                end = std::cmp::max(end, *existing.end());
+            }
+        }
+
+        if !inserted {
+            ranges.push(start..=end);
+        }
+
+        self.ranges = ranges;
+    }
+
+    pub fn merge(&mut self, other: &RangeSet<T>) {
+        for range in other.ranges() {
+            self.insert(range.clone());
+        }
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        union.merge(other);
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut intersection = RangeSet::new();
+        let mut ours = self.ranges.iter().peekable();
+        let mut theirs = other.ranges.iter().peekable();
+        while let (Some(&a), Some(&b)) = (ours.peek(), theirs.peek()) {
+            if let Some(overlap) = self::intersection(a, b) {
+                intersection.ranges.push(overlap);
+            }
+
+            if a.end() < b.end() {
+                ours.next();
+            } else {
+                theirs.next();
+            }
+        }
+
+        intersection
+    }
+
+    // Every integer within `bounds` that is not in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> RangeSet<T> {
+        let mut complement = RangeSet::new();
+        if bounds.is_empty() {
+            return complement;
+        }
+
+        let (bounds_start, bounds_end) = bounds.into_inner();
+        let mut next_start = Some(bounds_start);
+        for range in &self.ranges {
+            let Some(start) = next_start else {
+                break;
+            };
+
+            if *range.end() < start {
+                continue;
+            }
+            if *range.start() > bounds_end {
+                break;
+            }
+
+            if let Some(gap_end) = range.start().predecessor() {
+                let gap_end = std::cmp::min(gap_end, bounds_end);
+                if start <= gap_end {
+                    complement.ranges.push(start..=gap_end);
+                }
+            }
+
+            next_start = range.end().successor();
+        }
+
+        if let Some(start) = next_start {
+            if start <= bounds_end {
+                complement.ranges.push(start..=bounds_end);
+            }
+        }
+
+        complement
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    // Total number of integers in the set
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| T::span(*range.start(), *range.end()))
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<T: Integer> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+// True if `a` and `b` have at least one integer in common
+pub fn overlaps<T: Integer>(a: &RangeInclusive<T>, b: &RangeInclusive<T>) -> bool {
+    intersection(a, b).is_some()
+}
+
+// True if every integer in `inner` is also in `outer`. An empty range is
+// contained by every range.
+pub fn contains<T: Integer>(outer: &RangeInclusive<T>, inner: &RangeInclusive<T>) -> bool {
+    inner.is_empty() || (outer.start() <= inner.start() && inner.end() <= outer.end())
+}
+
+// The integers in both `a` and `b`, or `None` if there aren't any
+pub fn intersection<T: Integer>(
+    a: &RangeInclusive<T>,
+    b: &RangeInclusive<T>,
+) -> Option<RangeInclusive<T>> {
+    let start = std::cmp::max(*a.start(), *b.start());
+    let end = std::cmp::min(*a.end(), *b.end());
+    (start <= end).then_some(start..=end)
+}
+
+// True if `a` ends before `b` starts with at least one integer between them
+fn ends_before<T: Integer>(a: &RangeInclusive<T>, b: &RangeInclusive<T>) -> bool {
+    match a.end().successor() {
+        Some(next) => next < *b.start(),
+        None => false,
+    }
+}
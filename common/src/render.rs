@@ -0,0 +1,104 @@
+use std::{
+    fmt::Display,
+    ops::{ControlFlow, RangeInclusive},
+    time::Duration,
+};
+
+use crate::simulation::Simulation;
+
+// Something that can be drawn as a grid of characters
+pub trait Renderable {
+    // Draw larger y values first, for scenes where y points up
+    const Y_UP: bool = false;
+
+    fn x_range(&self) -> RangeInclusive<i64>;
+    fn y_range(&self) -> RangeInclusive<i64>;
+    fn render_cell(&self, x: i64, y: i64) -> char;
+
+    fn render(&self) -> String {
+        let ys: Box<dyn Iterator<Item = i64>> = if Self::Y_UP {
+            Box::new(self.y_range().rev())
+        } else {
+            Box::new(self.y_range())
+        };
+
+        ys.map(|y| {
+            self.x_range()
+                .map(|x| self.render_cell(x, y))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+    }
+}
+
+// Redraws frames in place in the terminal, or does nothing when headless
+#[derive(Debug, Clone)]
+pub struct Animation {
+    rate: Option<Duration>,
+}
+
+impl Animation {
+    pub fn new(display: bool, rate: Duration) -> Self {
+        Self {
+            rate: display.then_some(rate),
+        }
+    }
+
+    pub fn headless() -> Self {
+        Self { rate: None }
+    }
+
+    pub fn is_headless(&self) -> bool {
+        self.rate.is_none()
+    }
+
+    pub fn start(&self) {
+        if !self.is_headless() {
+            println!("{}", termion::clear::All);
+        }
+    }
+
+    pub fn frame(&self, title: impl Display, scene: &impl Renderable) {
+        let Some(rate) = self.rate else {
+            return;
+        };
+
+        println!(
+            "{}{}{title}\n{}",
+            termion::cursor::Goto(1, 1),
+            termion::clear::CurrentLine,
+            scene.render(),
+        );
+        std::thread::sleep(rate);
+    }
+
+    /// Run a simulation to completion like [`crate::simulation::run_with`],
+    /// drawing a frame before each step unless headless.
+    pub fn run<S>(
+        &self,
+        simulation: &mut S,
+        mut on_event: impl FnMut(usize, S::Event) -> ControlFlow<()>,
+    ) -> usize
+    where
+        S: Simulation + Renderable,
+    {
+        self.start();
+
+        let mut steps = 0;
+        loop {
+            self.frame(format_args!("Steps: {steps}"), simulation);
+
+            let ControlFlow::Continue(event) = simulation.step() else {
+                break;
+            };
+
+            steps += 1;
+            if on_event(steps, event).is_break() {
+                break;
+            }
+        }
+
+        steps
+    }
+}
@@ -0,0 +1,31 @@
+use std::ops::ControlFlow;
+
+// Something that advances one step at a time until it finishes
+pub trait Simulation {
+    // Reported by each step that doesn't finish the simulation
+    type Event;
+
+    fn step(&mut self) -> ControlFlow<(), Self::Event>;
+}
+
+/// Run a simulation to completion, returning the number of steps taken.
+pub fn run<S: Simulation>(simulation: &mut S) -> usize {
+    run_with(simulation, |_, _| ControlFlow::Continue(()))
+}
+
+/// Like [`run`], calling `on_event` with the step count and event after
+/// every step. Stops early if `on_event` breaks.
+pub fn run_with<S: Simulation>(
+    simulation: &mut S,
+    mut on_event: impl FnMut(usize, S::Event) -> ControlFlow<()>,
+) -> usize {
+    let mut steps = 0;
+    while let ControlFlow::Continue(event) = simulation.step() {
+        steps += 1;
+        if on_event(steps, event).is_break() {
+            break;
+        }
+    }
+
+    steps
+}
@@ -0,0 +1,109 @@
+use common::cycles::{self, Cycle};
+
+// 0, 1, 2, 3, 4, then 5, 6, 7 forever
+fn with_tail(state: &u64) -> u64 {
+    if *state < 5 {
+        state + 1
+    } else {
+        5 + (state - 5 + 1) % 3
+    }
+}
+
+// 0, 1, 2, 3, then 4 forever
+fn settles(state: &u64) -> u64 {
+    (state + 1).min(4)
+}
+
+fn sequence(step: fn(&u64) -> u64, len: usize) -> Vec<u64> {
+    std::iter::successors(Some(0), |state| Some(step(state)))
+        .take(len)
+        .collect()
+}
+
+fn find_cycle(step: fn(&u64) -> u64, max_steps: usize) -> Option<Cycle> {
+    let mut state = 0;
+    cycles::find_cycle(
+        &mut state,
+        |state| *state = step(state),
+        |&state| state,
+        max_steps,
+    )
+}
+
+#[test]
+fn cycle_after_tail() {
+    let expected = Cycle {
+        start: 5,
+        length: 3,
+    };
+    assert_eq!(cycles::brent(0, with_tail), expected);
+    assert_eq!(find_cycle(with_tail, 100), Some(expected));
+}
+
+#[test]
+fn period_one() {
+    let expected = Cycle {
+        start: 4,
+        length: 1,
+    };
+    assert_eq!(cycles::brent(0, settles), expected);
+    assert_eq!(find_cycle(settles, 100), Some(expected));
+
+    // Already repeating from the initial state
+    let expected = Cycle {
+        start: 0,
+        length: 1,
+    };
+    assert_eq!(cycles::brent(4, settles), expected);
+}
+
+#[test]
+fn no_cycle_within_max_steps() {
+    // The cycle closes at step 8
+    assert_eq!(find_cycle(with_tail, 7), None);
+    assert!(find_cycle(with_tail, 8).is_some());
+}
+
+#[test]
+fn equivalent_steps() {
+    let cycle = cycles::brent(0, with_tail);
+    let states = sequence(with_tail, 50);
+    for (n, state) in states.iter().enumerate() {
+        let equivalent = cycle.equivalent_step(n);
+        assert!(equivalent < cycle.start + cycle.length, "{n}");
+        assert_eq!(states[equivalent], *state, "{n}");
+    }
+}
+
+#[test]
+fn extrapolate_running_total() {
+    let cycle = cycles::brent(0, with_tail);
+
+    // `totals[i]` is the sum of the first `i` states
+    let states = sequence(with_tail, 100);
+    let totals: Vec<i64> = std::iter::once(0)
+        .chain(states.iter().scan(0, |total, &state| {
+            *total += state as i64;
+            Some(*total)
+        }))
+        .collect();
+
+    // Only one full cycle past the tail is needed to extrapolate, both to
+    // steps within the tail and far beyond it
+    let known = &totals[..=cycle.start + cycle.length];
+    for (n, &total) in totals.iter().enumerate() {
+        assert_eq!(cycle.extrapolate(known, n), Some(total), "{n}");
+    }
+}
+
+#[test]
+fn extrapolate_out_of_range() {
+    let cycle = Cycle {
+        start: 0,
+        length: 1,
+    };
+    let values = [0, i64::MAX / 2];
+    assert_eq!(cycle.extrapolate(&values, 2), Some(i64::MAX / 2 * 2));
+    assert_eq!(cycle.extrapolate(&values, 3), None);
+    assert_eq!(cycle.extrapolate(&values, usize::MAX), None);
+}
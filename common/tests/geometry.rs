@@ -0,0 +1,142 @@
+use common::geometry::{Bounds, Bounds3, Point, Point3, Vector3};
+
+#[test]
+fn manhattan_distance() {
+    let a = Point { x: 2, y: 18 };
+    let b = Point { x: -2, y: 15 };
+    assert_eq!(a.manhattan_distance(&b), 7);
+    assert_eq!(b.manhattan_distance(&a), 7);
+    assert_eq!(a.manhattan_distance(&a), 0);
+
+    let a = Point3 { x: 1, y: -2, z: 3 };
+    assert_eq!(a.manhattan_distance(&Point3::ORIGIN), 6);
+}
+
+#[test]
+fn bounds_from_points() {
+    assert_eq!(Bounds::from_points([]), None);
+
+    let bounds = Bounds::from_points([
+        Point { x: 3, y: -1 },
+        Point { x: -2, y: 4 },
+        Point { x: 0, y: 0 },
+    ])
+    .unwrap();
+    assert_eq!(bounds.min, Point { x: -2, y: -1 });
+    assert_eq!(bounds.max, Point { x: 3, y: 4 });
+    assert_eq!((bounds.width(), bounds.height()), (6, 6));
+    assert_eq!(bounds.x_bounds(), -2..=3);
+    assert_eq!(bounds.y_bounds(), -1..=4);
+}
+
+#[test]
+fn single_point_bounds() {
+    let point = Point { x: 5, y: 7 };
+    let bounds = Bounds::new(point);
+    assert_eq!((bounds.width(), bounds.height()), (1, 1));
+    assert!(bounds.contains(point));
+    assert!(!bounds.contains(Point { x: 5, y: 8 }));
+    assert_eq!(bounds.points().collect::<Vec<_>>(), [point]);
+}
+
+#[test]
+fn bounds_union() {
+    let mut bounds = Bounds::new(Point::ORIGIN);
+    bounds.union(&Bounds {
+        min: Point { x: 2, y: -3 },
+        max: Point { x: 4, y: -1 },
+    });
+    assert_eq!(
+        bounds,
+        Bounds {
+            min: Point { x: 0, y: -3 },
+            max: Point { x: 4, y: 0 },
+        }
+    );
+}
+
+#[test]
+fn bounds_contains_edges() {
+    let bounds = Bounds {
+        min: Point { x: 0, y: 0 },
+        max: Point { x: 20, y: 20 },
+    };
+    for point in [
+        Point { x: 0, y: 0 },
+        Point { x: 20, y: 20 },
+        Point { x: 0, y: 20 },
+    ] {
+        assert!(bounds.contains(point), "{point:?}");
+    }
+    for point in [
+        Point { x: -1, y: 0 },
+        Point { x: 21, y: 5 },
+        Point { x: 5, y: 21 },
+    ] {
+        assert!(!bounds.contains(point), "{point:?}");
+    }
+}
+
+#[test]
+fn bounds_points() {
+    let bounds = Bounds {
+        min: Point { x: 1, y: 1 },
+        max: Point { x: 2, y: 3 },
+    };
+    let points: Vec<_> = bounds.points().map(|point| (point.x, point.y)).collect();
+    assert_eq!(points, [(1, 1), (1, 2), (1, 3), (2, 1), (2, 2), (2, 3)]);
+    assert!(bounds.points().all(|point| bounds.contains(point)));
+
+    let row: Vec<_> = bounds.points_row(10).collect();
+    assert_eq!(row, [Point { x: 1, y: 10 }, Point { x: 2, y: 10 }]);
+}
+
+#[test]
+fn point3_neighbors() {
+    let center = Point3 { x: 1, y: 1, z: 1 };
+    let faces: Vec<_> = center.face_neighbors().collect();
+    assert_eq!(faces.len(), 6);
+    assert!(faces
+        .iter()
+        .all(|face| face.manhattan_distance(&center) == 1));
+
+    let all: Vec<_> = center.all_neighbors().collect();
+    assert_eq!(all.len(), 26);
+    assert!(!all.contains(&center));
+    assert!(faces.iter().all(|face| all.contains(face)));
+}
+
+#[test]
+fn parse_point3() {
+    assert_eq!("1, -2,3".parse(), Ok(Point3 { x: 1, y: -2, z: 3 }));
+    assert_eq!(Point3 { x: 1, y: -2, z: 3 }.to_string(), "1,-2,3");
+    assert!("1,2".parse::<Point3>().is_err());
+    assert!("1,2,3,4".parse::<Point3>().is_err());
+    assert!("1,x,3".parse::<Point3>().is_err());
+}
+
+#[test]
+fn vector3_arithmetic() {
+    let a = Point3 { x: 1, y: 2, z: 3 };
+    let b = Point3 { x: 4, y: 0, z: -1 };
+    let offset = b - a;
+    assert_eq!(offset, Vector3 { x: 3, y: -2, z: -4 });
+    assert_eq!(a + offset, b);
+    assert_eq!(offset.normalize(), Vector3 { x: 1, y: -1, z: -1 });
+    assert_eq!(offset * 2 - offset, offset);
+    assert_eq!(offset.dot(Vector3 { x: 1, y: 1, z: 1 }), -3);
+}
+
+#[test]
+fn bounds3() {
+    let bounds =
+        Bounds3::from_points([Point3 { x: 0, y: 1, z: 2 }, Point3 { x: 2, y: 1, z: 0 }]).unwrap();
+    assert_eq!((bounds.width(), bounds.height(), bounds.depth()), (3, 1, 3));
+    assert_eq!(bounds.points().count(), 9);
+
+    let expanded = bounds.expand(1);
+    assert_eq!(expanded.min, Point3 { x: -1, y: 0, z: -1 });
+    assert_eq!(expanded.max, Point3 { x: 3, y: 2, z: 3 });
+    assert!(expanded.contains(Point3 { x: 3, y: 2, z: 3 }));
+    assert!(!bounds.contains(Point3 { x: 3, y: 1, z: 1 }));
+}
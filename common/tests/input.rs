@@ -0,0 +1,68 @@
+use common::input::{blocks, read_blocks};
+
+fn read_all(input: &str) -> Vec<Vec<String>> {
+    read_blocks(input.as_bytes())
+        .collect::<std::io::Result<_>>()
+        .unwrap()
+}
+
+fn block_lines(input: &str) -> Vec<Vec<String>> {
+    blocks(input)
+        .map(|block| block.lines().map(str::to_string).collect())
+        .collect()
+}
+
+fn expected(blocks: &[&[&str]]) -> Vec<Vec<String>> {
+    blocks
+        .iter()
+        .map(|block| block.iter().map(|line| line.to_string()).collect())
+        .collect()
+}
+
+#[test]
+fn single_blank_lines() {
+    let input = "1000\n2000\n\n3000\n\n4000\n5000\n";
+    let want = expected(&[&["1000", "2000"], &["3000"], &["4000", "5000"]]);
+    assert_eq!(read_all(input), want);
+    assert_eq!(block_lines(input), want);
+}
+
+#[test]
+fn crlf_line_endings() {
+    let input = "1000\r\n2000\r\n\r\n3000\r\n";
+    let want = expected(&[&["1000", "2000"], &["3000"]]);
+    assert_eq!(read_all(input), want);
+    assert_eq!(block_lines(input), want);
+}
+
+#[test]
+fn trailing_blank_line() {
+    let input = "1000\n\n2000\n\n";
+    let want = expected(&[&["1000"], &["2000"]]);
+    assert_eq!(read_all(input), want);
+    assert_eq!(block_lines(input), want);
+}
+
+#[test]
+fn several_blank_lines_between_blocks() {
+    let input = "\n1000\n\n\n\n2000\n3000\n \n\t\n4000\n";
+    let want = expected(&[&["1000"], &["2000", "3000"], &["4000"]]);
+    assert_eq!(read_all(input), want);
+    assert_eq!(block_lines(input), want);
+}
+
+#[test]
+fn no_final_newline() {
+    let input = "1000\n\n2000\n3000";
+    let want = expected(&[&["1000"], &["2000", "3000"]]);
+    assert_eq!(read_all(input), want);
+    assert_eq!(block_lines(input), want);
+}
+
+#[test]
+fn empty_input() {
+    assert!(read_all("").is_empty());
+    assert!(read_all("\n\r\n\n").is_empty());
+    assert_eq!(blocks("").count(), 0);
+    assert_eq!(blocks("\n\r\n\n").count(), 0);
+}
@@ -0,0 +1,123 @@
+use common::modular::{self, Residues};
+use num_bigint::BigInt;
+
+// The largest prime below 2^64
+const LARGE_PRIME: u64 = 18_446_744_073_709_551_557;
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(modular::gcd(12, 18), 6);
+    assert_eq!(modular::gcd(17, 5), 1);
+    assert_eq!(modular::gcd(0, 7), 7);
+    assert_eq!(modular::lcm(4, 6), 12);
+    assert_eq!(modular::lcm(0, 6), 0);
+    assert_eq!(modular::lcm(u64::MAX, u64::MAX), u64::MAX);
+}
+
+// Step around the ring one slot at a time
+fn wrap_index_by_steps(index: usize, offset: i64, len: usize) -> usize {
+    let mut index = index % len;
+    for _ in 0..offset.unsigned_abs() {
+        index = if offset > 0 {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+    }
+    index
+}
+
+#[test]
+fn wrap_index_negative_and_many_laps() {
+    for len in 1..=7 {
+        for index in 0..len {
+            for offset in -30..=30 {
+                assert_eq!(
+                    modular::wrap_index(index, offset, len),
+                    wrap_index_by_steps(index, offset, len),
+                    "{index} + {offset} around {len}"
+                );
+            }
+        }
+    }
+
+    assert_eq!(modular::wrap_index(0, -1, 5), 4);
+    assert_eq!(modular::wrap_index(2, -5_000_000_003, 5), 4);
+    assert_eq!(modular::wrap_index(2, 5_000_000_003, 5), 0);
+    // -2^63 is 6 modulo 7
+    assert_eq!(modular::wrap_index(3, i64::MIN, 7), 2);
+}
+
+#[test]
+fn mod_pow_near_u64_max() {
+    // Fermat's little theorem
+    for base in [2, 3, u64::MAX - 1, LARGE_PRIME - 1] {
+        assert_eq!(modular::mod_pow(base, LARGE_PRIME - 1, LARGE_PRIME), 1);
+    }
+
+    // u64::MAX - 1 is -1 modulo u64::MAX
+    assert_eq!(modular::mod_pow(u64::MAX - 1, 2, u64::MAX), 1);
+    assert_eq!(modular::mod_pow(u64::MAX - 1, 3, u64::MAX), u64::MAX - 1);
+    assert_eq!(modular::mod_pow(u64::MAX, 5, u64::MAX), 0);
+    assert_eq!(modular::mod_pow(u64::MAX, 0, u64::MAX), 1);
+    assert_eq!(modular::mod_pow(5, 0, 1), 0);
+
+    // Small enough to check by repeated multiplication
+    let mut expected = 1u128;
+    for exponent in 0..50 {
+        assert_eq!(
+            u128::from(modular::mod_pow(u64::MAX - 2, exponent, LARGE_PRIME)),
+            expected
+        );
+        expected = expected * u128::from(u64::MAX - 2) % u128::from(LARGE_PRIME);
+    }
+}
+
+#[test]
+fn residues_match_big_ints() {
+    let moduli = [2, 3, 5, 7, 11, 13, 17, 19, 23, 9_999_991];
+    let check = |residues: &Residues, exact: &BigInt| {
+        for &modulus in &moduli {
+            assert_eq!(
+                BigInt::from(residues.residue(modulus).unwrap()),
+                exact % modulus,
+                "{exact} mod {modulus}"
+            );
+            assert_eq!(
+                residues.is_divisible_by(modulus),
+                exact % modulus == BigInt::from(0u64),
+                "{exact} divisible by {modulus}"
+            );
+        }
+    };
+
+    // Grow well past u64::MAX with a mix of operations
+    let mut seed = 0x11u64;
+    let mut residues = Residues::new(79, &moduli);
+    let mut exact = BigInt::from(79u64);
+    check(&residues, &exact);
+    for round in 0..12 {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        let value = (seed >> 48) + 1;
+        (residues, exact) = match round % 3 {
+            0 => (&residues * value, &exact * BigInt::from(value)),
+            1 => (&residues + value, &exact + BigInt::from(value)),
+            _ => (&residues * &residues, &exact * &exact),
+        };
+        check(&residues, &exact);
+
+        let other = Residues::new(value, &moduli);
+        check(&(&residues + &other), &(&exact + BigInt::from(value)));
+    }
+    assert!(exact > BigInt::from(u64::MAX));
+}
+
+#[test]
+fn update_in_place() {
+    let moduli = [3, 5, 7];
+    let mut residues = Residues::new(20, &moduli);
+    residues.update(|residue, modulus| modular::mul_mod(residue, residue, modulus));
+    assert_eq!(residues, Residues::new(400, &moduli));
+    residues.update(|residue, modulus| modular::add_mod(residue, 6, modulus));
+    assert_eq!(residues, Residues::new(406, &moduli));
+}
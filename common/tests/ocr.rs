@@ -0,0 +1,35 @@
+use common::ocr::{self, OcrError};
+
+const SCREEN: &str = "\
+###..#....####.####.#..#.#....###..###..
+#..#.#....#....#....#..#.#....#..#.#..#.
+#..#.#....###..###..#..#.#....#..#.###..
+###..#....#....#....#..#.#....###..#..#.
+#....#....#....#....#..#.#....#....#..#.
+#....####.####.#.....##..####.#....###..";
+
+#[test]
+fn decode_crt_screen() {
+    assert_eq!(ocr::decode_str(SCREEN), Ok("PLEFULPB".to_string()));
+}
+
+#[test]
+fn unknown_glyph() {
+    // Knock a pixel out of the top of the `E`, the third glyph
+    let mut rows: Vec<String> = SCREEN.lines().map(str::to_string).collect();
+    rows[0].replace_range(11..12, ".");
+    let screen = rows.join("\n");
+    assert_eq!(
+        ocr::decode_str(&screen),
+        Err(OcrError::UnknownGlyph {
+            index: 2,
+            glyph: "#.##\n#...\n###.\n#...\n#...\n####".to_string(),
+        })
+    );
+}
+
+#[test]
+fn unexpected_height() {
+    let screen: String = SCREEN.lines().take(5).collect::<Vec<_>>().join("\n");
+    assert_eq!(ocr::decode_str(&screen), Err(OcrError::UnexpectedHeight(5)));
+}
@@ -0,0 +1,107 @@
+use common::pathfinding;
+
+// Two separate lines: 0 through 9, and 20 through 29
+fn neighbors(&n: &i32) -> Vec<i32> {
+    [n - 1, n + 1]
+        .into_iter()
+        .filter(|next| (0..10).contains(next) || (20..30).contains(next))
+        .collect()
+}
+
+fn weighted_neighbors(n: &i32) -> Vec<(i32, u32)> {
+    neighbors(n).into_iter().map(|next| (next, 1)).collect()
+}
+
+#[test]
+fn path_includes_both_endpoints() {
+    let path = pathfinding::bfs(2, neighbors, |&n| n == 7).unwrap();
+    assert_eq!(path, [2, 3, 4, 5, 6, 7]);
+
+    let (path, cost) = pathfinding::dijkstra(2, weighted_neighbors, |&n| n == 7).unwrap();
+    assert_eq!(path, [2, 3, 4, 5, 6, 7]);
+    assert_eq!(cost, 5);
+
+    let (path, cost) = pathfinding::astar(
+        7,
+        weighted_neighbors,
+        |&n| (n - 2).unsigned_abs(),
+        |&n| n == 2,
+    )
+    .unwrap();
+    assert_eq!(path, [7, 6, 5, 4, 3, 2]);
+    assert_eq!(cost, 5);
+}
+
+#[test]
+fn unreachable_goal() {
+    assert_eq!(pathfinding::bfs(2, neighbors, |&n| n == 25), None);
+    assert_eq!(
+        pathfinding::dijkstra(2, weighted_neighbors, |&n| n == 25),
+        None
+    );
+    assert_eq!(
+        pathfinding::astar(2, weighted_neighbors, |_| 0, |&n| n == 25),
+        None
+    );
+}
+
+#[test]
+fn start_is_goal() {
+    assert_eq!(pathfinding::bfs(4, neighbors, |&n| n == 4), Some(vec![4]));
+    assert_eq!(
+        pathfinding::dijkstra(4, weighted_neighbors, |&n| n == 4),
+        Some((vec![4], 0))
+    );
+}
+
+#[test]
+fn cheapest_path_over_shortest() {
+    // Going straight from 0 to 3 costs 10, but the long way round costs 3
+    let successors = |&n: &u8| -> Vec<(u8, u32)> {
+        match n {
+            0 => vec![(3, 10), (1, 1)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    };
+    assert_eq!(
+        pathfinding::dijkstra(0, successors, |&n| n == 3),
+        Some((vec![0, 1, 2, 3], 3))
+    );
+
+    // With unweighted steps, the direct path is shortest
+    let unweighted = |n: &u8| successors(n).into_iter().map(|(next, _)| next);
+    assert_eq!(
+        pathfinding::bfs(0, unweighted, |&n| n == 3),
+        Some(vec![0, 3])
+    );
+}
+
+#[test]
+fn astar_reopens_cheaper_nodes() {
+    // The heuristic is admissible but not consistent: it makes 1 look far
+    // from the goal, so 3 is first expanded via the expensive route
+    // through 2, then found again more cheaply through 1
+    let successors = |&n: &u8| -> Vec<(u8, u32)> {
+        match n {
+            0 => vec![(1, 1), (2, 1)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 3)],
+            3 => vec![(4, 5)],
+            _ => vec![],
+        }
+    };
+    let heuristic = |&n: &u8| if n == 1 { 6 } else { 0 };
+    assert_eq!(
+        pathfinding::astar(0, successors, heuristic, |&n| n == 4),
+        Some((vec![0, 1, 3, 4], 7))
+    );
+}
+
+#[test]
+fn flood_fill_stays_in_component() {
+    let reached = pathfinding::flood_fill(25, neighbors);
+    assert_eq!(reached.len(), 10);
+    assert!((20..30).all(|n| reached.contains(&n)));
+}
@@ -0,0 +1,191 @@
+use std::{collections::BTreeSet, ops::RangeInclusive};
+
+use common::ranges::{self, RangeSet};
+
+// Every range within 0..=6, including empty ones like `3..=2`
+fn small_ranges() -> impl Iterator<Item = RangeInclusive<u64>> {
+    (0..=6).flat_map(|start| (0..=6).map(move |end| start..=end))
+}
+
+fn members(range: &RangeInclusive<u64>) -> BTreeSet<u64> {
+    range.clone().collect()
+}
+
+#[test]
+fn overlaps_matches_members() {
+    for a in small_ranges() {
+        for b in small_ranges() {
+            let expected = !members(&a).is_disjoint(&members(&b));
+            assert_eq!(ranges::overlaps(&a, &b), expected, "{a:?}, {b:?}");
+            assert_eq!(ranges::overlaps(&b, &a), expected, "{b:?}, {a:?}");
+        }
+    }
+}
+
+#[test]
+fn contains_matches_members() {
+    for outer in small_ranges() {
+        for inner in small_ranges() {
+            let expected = members(&inner).is_subset(&members(&outer));
+            assert_eq!(
+                ranges::contains(&outer, &inner),
+                expected,
+                "{outer:?}, {inner:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn intersection_matches_members() {
+    for a in small_ranges() {
+        for b in small_ranges() {
+            let expected: BTreeSet<u64> = members(&a).intersection(&members(&b)).copied().collect();
+            let actual =
+                ranges::intersection(&a, &b).map_or_else(BTreeSet::new, |range| members(&range));
+            assert_eq!(actual, expected, "{a:?}, {b:?}");
+
+            let set = RangeSet::from(a.clone()).intersection(&RangeSet::from(b.clone()));
+            assert_eq!(set.len(), expected.len() as u64, "{a:?}, {b:?}");
+        }
+    }
+}
+
+#[test]
+fn extremes() {
+    let everything = 0..=u64::MAX;
+    let tail = u64::MAX - 1..=u64::MAX;
+    assert!(ranges::contains(&everything, &tail));
+    assert!(!ranges::contains(&tail, &everything));
+    assert_eq!(ranges::intersection(&everything, &tail), Some(tail.clone()));
+    assert!(!ranges::overlaps(&(0..=0), &(1..=u64::MAX)));
+    assert!(ranges::overlaps(&(i64::MIN..=0), &(0..=i64::MAX)));
+}
+
+// Every set of up to two ranges within 0..=3, so there is room for bounds
+// beyond them in `small_ranges`
+fn small_sets() -> impl Iterator<Item = RangeSet<u64>> {
+    (0..=3)
+        .flat_map(|start| (0..=3).map(move |end| start..=end))
+        .flat_map(|a| {
+            (0..=3)
+                .flat_map(|start| (0..=3).map(move |end| start..=end))
+                .map(move |b| RangeSet::from_iter([a.clone(), b]))
+        })
+}
+
+fn set_members(set: &RangeSet<u64>) -> BTreeSet<u64> {
+    set.ranges().flat_map(|range| range.clone()).collect()
+}
+
+// The ranges are non-empty, sorted, and have at least one integer between
+// each of them
+fn assert_normalized(set: &RangeSet<u64>) {
+    let ranges: Vec<_> = set.ranges().collect();
+    for range in &ranges {
+        assert!(!range.is_empty(), "{set:?}");
+    }
+    for pair in ranges.windows(2) {
+        assert!(pair[0].end() + 1 < *pair[1].start(), "{set:?}");
+    }
+}
+
+#[test]
+fn insert_matches_members() {
+    for a in small_ranges() {
+        for b in small_ranges() {
+            let expected: BTreeSet<u64> = members(&a).union(&members(&b)).copied().collect();
+            for set in [
+                RangeSet::from_iter([a.clone(), b.clone()]),
+                RangeSet::from_iter([b.clone(), a.clone()]),
+            ] {
+                assert_normalized(&set);
+                assert_eq!(set_members(&set), expected, "{a:?}, {b:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn insert_coalesces() {
+    let overlapping = RangeSet::from_iter([1..=4, 3..=6]);
+    assert_eq!(overlapping.ranges().collect::<Vec<_>>(), [&(1..=6)]);
+
+    let adjacent = RangeSet::from_iter([3..=5, 0..=2]);
+    assert_eq!(adjacent.ranges().collect::<Vec<_>>(), [&(0..=5)]);
+
+    let bridged = RangeSet::from_iter([0..=1, 5..=6, 2..=4]);
+    assert_eq!(bridged.ranges().collect::<Vec<_>>(), [&(0..=6)]);
+
+    let gap = RangeSet::from_iter([0..=1, 3..=4]);
+    assert_eq!(gap.ranges().collect::<Vec<_>>(), [&(0..=1), &(3..=4)]);
+}
+
+#[test]
+fn merge_and_union_match_members() {
+    let sets: Vec<_> = small_sets().collect();
+    for a in &sets {
+        for b in &sets {
+            let expected: BTreeSet<u64> = set_members(a).union(&set_members(b)).copied().collect();
+
+            let union = a.union(b);
+            assert_normalized(&union);
+            assert_eq!(set_members(&union), expected, "{a:?}, {b:?}");
+
+            let mut merged = a.clone();
+            merged.merge(b);
+            assert_eq!(merged, union, "{a:?}, {b:?}");
+        }
+    }
+}
+
+#[test]
+fn complement_matches_members() {
+    for set in small_sets() {
+        for bounds in small_ranges() {
+            let expected: BTreeSet<u64> = members(&bounds)
+                .difference(&set_members(&set))
+                .copied()
+                .collect();
+
+            let complement = set.complement(bounds.clone());
+            assert_normalized(&complement);
+            assert_eq!(set_members(&complement), expected, "{set:?}, {bounds:?}");
+        }
+    }
+}
+
+#[test]
+fn complement_at_extremes() {
+    let everything = 0..=u64::MAX;
+    assert_eq!(
+        RangeSet::from(5..=u64::MAX).complement(everything.clone()),
+        RangeSet::from(0..=4)
+    );
+    assert_eq!(
+        RangeSet::from(0..=3).complement(everything.clone()),
+        RangeSet::from(4..=u64::MAX)
+    );
+    assert!(RangeSet::from(everything.clone())
+        .complement(everything.clone())
+        .is_empty());
+    assert_eq!(
+        RangeSet::new().complement(everything.clone()),
+        RangeSet::from(everything)
+    );
+}
+
+#[test]
+fn len_matches_members() {
+    for set in small_sets() {
+        assert_eq!(set.len(), set_members(&set).len() as u64, "{set:?}");
+    }
+
+    let set = RangeSet::from_iter([10..=19, 0..=2, 5..=5]);
+    assert_eq!(set.len(), 14);
+
+    let set = RangeSet::from_iter([i64::MIN..=-1, 1..=i64::MAX]);
+    assert_eq!(set.len(), u64::MAX);
+
+    assert_eq!(RangeSet::from(0..=u64::MAX).len(), u64::MAX);
+}
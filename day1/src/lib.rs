@@ -0,0 +1,415 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    io::{BufRead, Lines},
+};
+
+use common::{
+    answer::Answer,
+    input::{read_blocks, ReadBlocks},
+};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+/// How the calorie list is laid out
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// One calorie count per line, with a blank line between elves (the
+    /// puzzle's format)
+    #[default]
+    Blocks,
+    /// One elf per row, with calorie counts separated by commas or tabs
+    Csv,
+}
+
+/// One elf's inventory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// The elf's 1-based position in the input
+    pub index: usize,
+    /// Set from a `# name` header line at the start of the elf's block
+    pub name: Option<String>,
+    pub calories: u64,
+}
+
+impl Elf {
+    pub fn unnamed(index: usize, calories: u64) -> Self {
+        Elf {
+            index,
+            name: None,
+            calories,
+        }
+    }
+}
+
+/// Formats as the elf's name, or its position if it doesn't have one
+impl std::fmt::Display for Elf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "elf {}", self.index),
+        }
+    }
+}
+
+/// Iterate over each elf read from `reader`. In [`Format::Blocks`], a block
+/// may start with a `# name` line to name the elf.
+pub fn elves<R: BufRead>(reader: R, format: Format) -> Elves<R> {
+    let rows = match format {
+        Format::Blocks => Rows::Blocks(read_blocks(reader)),
+        Format::Csv => Rows::Csv(reader.lines()),
+    };
+    Elves { rows, count: 0 }
+}
+
+pub struct Elves<R> {
+    rows: Rows<R>,
+    count: usize,
+}
+
+enum Rows<R> {
+    Blocks(ReadBlocks<R>),
+    Csv(Lines<R>),
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = anyhow::Result<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, calories) = match &mut self.rows {
+            Rows::Blocks(blocks) => {
+                let block = match blocks.next()? {
+                    Ok(block) => block,
+                    Err(error) => return Some(Err(error.into())),
+                };
+
+                let mut lines = block.iter().map(|line| line.trim()).peekable();
+                let name = lines
+                    .next_if(|line| line.starts_with('#'))
+                    .map(|header| header[1..].trim())
+                    .filter(|name| !name.is_empty())
+                    .map(String::from);
+                (name, sum_calories(lines))
+            }
+            Rows::Csv(lines) => loop {
+                let row = match lines.next()? {
+                    Ok(row) => row,
+                    Err(error) => return Some(Err(error.into())),
+                };
+                if row.trim().is_empty() {
+                    continue;
+                }
+
+                // Spreadsheets pad short rows with empty cells, so skip them
+                let cells = row.split([',', '\t']).map(str::trim);
+                break (None, sum_calories(cells.filter(|cell| !cell.is_empty())));
+            },
+        };
+
+        self.count += 1;
+        let index = self.count;
+        Some(calories.map(|calories| Elf {
+            index,
+            name,
+            calories,
+        }))
+    }
+}
+
+/// Iterate over the calorie total of each elf read from `reader`
+pub fn elf_totals<R: BufRead>(reader: R, format: Format) -> ElfTotals<R> {
+    ElfTotals {
+        elves: elves(reader, format),
+    }
+}
+
+pub struct ElfTotals<R> {
+    elves: Elves<R>,
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = anyhow::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elf = self.elves.next()?;
+        Some(elf.map(|elf| elf.calories))
+    }
+}
+
+fn sum_calories<'a>(items: impl Iterator<Item = &'a str>) -> anyhow::Result<u64> {
+    let mut total = 0;
+    for item in items {
+        let calories: u64 = item.parse()?;
+        total += calories;
+    }
+
+    Ok(total)
+}
+
+/// How [`top_k_with`] keeps track of the largest totals seen so far
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TopKStrategy {
+    /// Keep a min-heap of the top `k`, so each elf costs O(log k)
+    #[default]
+    Heap,
+    /// Re-sort the top `k` after every elf, so each elf costs O(k log k)
+    Sort,
+}
+
+/// Collect the `k` largest totals, largest first, stopping at the first
+/// error
+pub fn top_k(
+    totals: impl IntoIterator<Item = anyhow::Result<u64>>,
+    k: usize,
+) -> anyhow::Result<Vec<u64>> {
+    top_k_with(totals, k, TopKStrategy::default())
+}
+
+/// Like [`top_k`], but with an explicit strategy
+pub fn top_k_with(
+    totals: impl IntoIterator<Item = anyhow::Result<u64>>,
+    k: usize,
+    strategy: TopKStrategy,
+) -> anyhow::Result<Vec<u64>> {
+    match strategy {
+        TopKStrategy::Heap => {
+            let mut top = BinaryHeap::with_capacity(k + 1);
+            for total in totals {
+                top.push(Reverse(total?));
+                if top.len() > k {
+                    top.pop();
+                }
+            }
+
+            // Sorting ascending by `Reverse` puts the largest totals first
+            let top = top.into_sorted_vec();
+            Ok(top.into_iter().map(|Reverse(total)| total).collect())
+        }
+        TopKStrategy::Sort => {
+            let mut top = Vec::with_capacity(k + 1);
+            for total in totals {
+                top.push(total?);
+                top.sort_by_key(|&total| Reverse(total));
+                top.truncate(k);
+            }
+
+            Ok(top)
+        }
+    }
+}
+
+/// Summary statistics over every elf's calorie total
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    // Sorted ascending by calories, never empty
+    elves: Vec<Elf>,
+}
+
+impl Stats {
+    /// Summarize unnamed elves with the given totals. Returns `None` if there
+    /// are no elves to summarize.
+    pub fn new(totals: Vec<u64>) -> Option<Self> {
+        let elves = totals
+            .into_iter()
+            .enumerate()
+            .map(|(i, calories)| Elf::unnamed(i + 1, calories));
+        Stats::from_elves(elves.collect())
+    }
+
+    /// Returns `None` if there are no elves to summarize
+    pub fn from_elves(mut elves: Vec<Elf>) -> Option<Self> {
+        if elves.is_empty() {
+            return None;
+        }
+
+        elves.sort_by_key(|elf| elf.calories);
+        Some(Stats { elves })
+    }
+
+    pub fn count(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn min(&self) -> u64 {
+        self.min_elf().calories
+    }
+
+    pub fn min_elf(&self) -> &Elf {
+        &self.elves[0]
+    }
+
+    pub fn max(&self) -> u64 {
+        self.max_elf().calories
+    }
+
+    pub fn max_elf(&self) -> &Elf {
+        &self.elves[self.elves.len() - 1]
+    }
+
+    pub fn mean(&self) -> f64 {
+        let sum: u64 = self.elves.iter().map(|elf| elf.calories).sum();
+        sum as f64 / self.elves.len() as f64
+    }
+
+    pub fn median(&self) -> f64 {
+        let mid = self.elves.len() / 2;
+        if mid * 2 == self.elves.len() {
+            (self.elves[mid - 1].calories as f64 + self.elves[mid].calories as f64) / 2.0
+        } else {
+            self.elves[mid].calories as f64
+        }
+    }
+
+    /// The nearest-rank percentile, where `p` is between 0 and 100
+    pub fn percentile(&self, p: f64) -> u64 {
+        self.percentile_elf(p).calories
+    }
+
+    /// The elf at the nearest-rank percentile
+    pub fn percentile_elf(&self, p: f64) -> &Elf {
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * self.elves.len() as f64).ceil() as usize;
+        &self.elves[rank.max(1) - 1]
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let min = self.min_elf();
+        let max = self.max_elf();
+
+        writeln!(f, "count: {}", self.count())?;
+        writeln!(f, "min: {} ({min})", min.calories)?;
+        writeln!(f, "max: {} ({max})", max.calories)?;
+        writeln!(f, "mean: {:.1}", self.mean())?;
+        writeln!(f, "median: {:.1}", self.median())?;
+        for p in [10, 25, 75, 90, 99] {
+            let elf = self.percentile_elf(p as f64);
+            writeln!(f, "p{p}: {} ({elf})", elf.calories)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn solve_part1(input: &str) -> anyhow::Result<Answer> {
+    let top_sum = top_elves_sum(input, 1, Format::Blocks)?;
+    Ok(top_sum.into())
+}
+
+pub fn solve_part2(input: &str) -> anyhow::Result<Answer> {
+    let top_sum = top_elves_sum(input, 3, Format::Blocks)?;
+    Ok(top_sum.into())
+}
+
+pub fn top_elves_sum(input: &str, top_slots: usize, format: Format) -> anyhow::Result<u64> {
+    top_elves_sum_with(input, top_slots, format, TopKStrategy::default())
+}
+
+pub fn top_elves_sum_with(
+    input: &str,
+    top_slots: usize,
+    format: Format,
+    strategy: TopKStrategy,
+) -> anyhow::Result<u64> {
+    let top_elves = top_k_with(elf_totals(input.as_bytes(), format), top_slots, strategy)?;
+    Ok(top_elves.iter().sum())
+}
+
+/// The `top_slots` elves carrying the most calories, largest first. Ties
+/// keep their input order.
+pub fn top_elves(input: &str, top_slots: usize, format: Format) -> anyhow::Result<Vec<Elf>> {
+    largest_elves_from(elves(input.as_bytes(), format), top_slots)
+}
+
+/// Like [`top_elves`], for elves that have already been read
+pub fn largest_elves(mut elves: Vec<Elf>, top_slots: usize) -> Vec<Elf> {
+    elves.sort_by_key(|elf| Reverse(elf.calories));
+    elves.truncate(top_slots);
+    elves
+}
+
+/// Like [`largest_elves`], but keeping only the top elves while reading,
+/// stopping at the first error
+pub fn largest_elves_from(
+    elves: impl IntoIterator<Item = anyhow::Result<Elf>>,
+    top_slots: usize,
+) -> anyhow::Result<Vec<Elf>> {
+    let mut top: Vec<Elf> = Vec::with_capacity(top_slots + 1);
+    for elf in elves {
+        let elf = elf?;
+
+        // Insert after any ties, so they keep their input order
+        let position = top.partition_point(|top_elf| top_elf.calories >= elf.calories);
+        if position < top_slots {
+            top.insert(position, elf);
+            top.truncate(top_slots);
+        }
+    }
+
+    Ok(top)
+}
+
+/// How elves read from several inputs are combined
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Merge {
+    /// Each input adds more elves after the previous input's
+    #[default]
+    Append,
+    /// The `i`th elf of every input is the same elf, so their calories are
+    /// summed. An elf keeps the first name any input gives it.
+    ByIndex,
+}
+
+/// Read and combine the elves from each of `inputs`
+pub fn read_inputs<S: AsRef<str>>(
+    inputs: &[S],
+    format: Format,
+    merge: Merge,
+) -> anyhow::Result<Vec<Elf>> {
+    if merge == Merge::Append {
+        return append_inputs(inputs, format).collect();
+    }
+
+    let mut merged: Vec<Elf> = vec![];
+    for input in inputs {
+        for (i, elf) in elves(input.as_ref().as_bytes(), format).enumerate() {
+            let elf = elf?;
+            match merged.get_mut(i) {
+                Some(existing) => {
+                    existing.calories += elf.calories;
+                    if existing.name.is_none() {
+                        existing.name = elf.name;
+                    }
+                }
+                None => merged.push(elf),
+            }
+        }
+    }
+
+    Ok(merged)
+}
+
+/// Iterate over the elves from each of `inputs` in turn, as combined by
+/// [`Merge::Append`]. Unlike [`read_inputs`], nothing is kept in memory
+/// after each elf is read.
+pub fn append_inputs<'a, S: AsRef<str>>(
+    inputs: &'a [S],
+    format: Format,
+) -> impl Iterator<Item = anyhow::Result<Elf>> + 'a {
+    inputs
+        .iter()
+        .flat_map(move |input| elves(input.as_ref().as_bytes(), format))
+        .enumerate()
+        .map(|(i, elf)| {
+            elf.map(|elf| Elf {
+                index: i + 1,
+                ..elf
+            })
+        })
+}
+
+/// Summarize every elf's calorie total, or `None` if there are no elves
+pub fn elf_stats(input: &str, format: Format) -> anyhow::Result<Option<Stats>> {
+    let all_elves = elves(input.as_bytes(), format).collect::<anyhow::Result<_>>()?;
+    Ok(Stats::from_elves(all_elves))
+}
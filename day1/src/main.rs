@@ -1,61 +1,84 @@
 use clap::Parser;
-use std::io::BufRead;
-
-#[derive(Debug, Default)]
-struct Elves {
-    top_slots: usize,
-    top_elves: Vec<u64>,
-    current_elf: u64,
-}
-
-impl Elves {
-    fn new(top_slots: usize) -> Self {
-        Elves {
-            top_slots,
-            top_elves: Vec::with_capacity(top_slots + 1),
-            current_elf: 0,
-        }
-    }
-
-    fn add_current(&mut self, calories: u64) {
-        self.current_elf += calories;
-    }
-
-    fn end_current(&mut self) -> &[u64] {
-        let current = std::mem::replace(&mut self.current_elf, 0);
-        self.top_elves.push(current);
-        self.top_elves.sort_by_key(|&elf| std::cmp::Reverse(elf));
-        self.top_elves.truncate(self.top_slots);
-
-        &self.top_elves
-    }
-}
+use cli::MultiInputArgs;
+use day1::{Elf, Merge};
 
 #[derive(Debug, Parser)]
 struct Args {
+    #[arg(long, required_unless_present = "stats")]
+    top_slots: Option<usize>,
+    /// How to track the top elves while reading
+    #[arg(long, value_enum, default_value = "heap")]
+    strategy: day1::TopKStrategy,
+    /// Print statistics over every elf's total instead of the top sum
     #[arg(long)]
-   top_slots: usize,
+    stats: bool,
+    /// List each of the top elves before the sum
+    #[arg(long)]
+    breakdown: bool,
+    /// How the input is laid out
+    #[arg(long, value_enum, default_value = "blocks")]
+    format: day1::Format,
+    /// With several inputs, sum each input's nth elf together instead of
+    /// treating every input's elves as different elves
+    #[arg(long)]
+    merge_by_index: bool,
+    #[command(flatten)]
+    input: MultiInputArgs,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
-    let stdin = std::io::stdin().lock();
+    let inputs = args.input.read_all(day1::EXAMPLE)?;
+    let merge = if args.merge_by_index {
+        Merge::ByIndex
+    } else {
+        Merge::Append
+    };
+
+    if args.stats {
+        let elves = day1::read_inputs(&inputs, args.format, merge)?;
+        match day1::Stats::from_elves(elves) {
+            Some(stats) => print!("{stats}"),
+            None => println!("no elves"),
+        }
+
+        return Ok(());
+    }
 
-    let mut elves = Elves::new(args.top_slots);
-    for line in stdin.lines() {
-        let line = line?;
+    let top_slots = args
+        .top_slots
+        .ok_or("--top-slots is required unless --stats is passed")?;
 
-        if line.is_empty() {
-            elves.end_current();
-        } else {
-            let calories: u64 = line.parse()?;
-            elves.add_current(calories);
+    // Appended elves are streamed from the inputs, and only need to be read
+    // again for `--breakdown`. Merging by index has to see every input
+    // before any elf's total is known.
+    match merge {
+        Merge::Append => print_top_elves(&args, top_slots, || {
+            day1::append_inputs(&inputs, args.format)
+        })?,
+        Merge::ByIndex => {
+            let elves = day1::read_inputs(&inputs, args.format, merge)?;
+            print_top_elves(&args, top_slots, || elves.iter().cloned().map(Ok))?;
         }
     }
 
-    let top_elves = elves.end_current();
+    Ok(())
+}
+
+/// `elves` starts a fresh pass over the elves each time it's called
+fn print_top_elves<I>(args: &Args, top_slots: usize, elves: impl Fn() -> I) -> anyhow::Result<()>
+where
+    I: Iterator<Item = anyhow::Result<Elf>>,
+{
+    if args.breakdown {
+        for elf in day1::largest_elves_from(elves(), top_slots)? {
+            println!("{elf}: {}", elf.calories);
+        }
+    }
 
+    let totals = elves().map(|elf| elf.map(|elf| elf.calories));
+    let top_elves = day1::top_k_with(totals, top_slots, args.strategy)?;
     let top_sum: u64 = top_elves.iter().sum();
     println!("{}", top_sum);
 
@@ -0,0 +1,18 @@
+use day1::{top_elves_sum, Format};
+
+#[test]
+fn csv_matches_blocks() {
+    let csv = "1000,2000,3000\n4000\n5000,6000\n7000,8000,9000\n10000\n";
+    for top_slots in [1, 3] {
+        assert_eq!(
+            top_elves_sum(csv, top_slots, Format::Csv).unwrap(),
+            top_elves_sum(day1::EXAMPLE, top_slots, Format::Blocks).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn csv_accepts_tabs_and_padded_rows() {
+    let tsv = "1\t2\t\t\n3,,\n\n10\n";
+    assert_eq!(top_elves_sum(tsv, 2, Format::Csv).unwrap(), 13);
+}
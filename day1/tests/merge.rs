@@ -0,0 +1,68 @@
+use day1::{read_inputs, Format, Merge};
+
+const SHARD_A: &str = "# Alice\n1\n2\n\n3\n";
+const SHARD_B: &str = "10\n\n# Bob\n20\n\n30\n";
+
+#[test]
+fn append_keeps_elves_separate() {
+    let elves = read_inputs(&[SHARD_A, SHARD_B], Format::Blocks, Merge::Append).unwrap();
+    let calories: Vec<u64> = elves.iter().map(|elf| elf.calories).collect();
+    let labels: Vec<String> = elves.iter().map(|elf| elf.to_string()).collect();
+    assert_eq!(calories, [3, 3, 10, 20, 30]);
+    assert_eq!(labels, ["Alice", "elf 2", "elf 3", "Bob", "elf 5"]);
+}
+
+#[test]
+fn merge_by_index_sums_shards() {
+    let elves = read_inputs(&[SHARD_A, SHARD_B], Format::Blocks, Merge::ByIndex).unwrap();
+    let calories: Vec<u64> = elves.iter().map(|elf| elf.calories).collect();
+    let labels: Vec<String> = elves.iter().map(|elf| elf.to_string()).collect();
+    assert_eq!(calories, [13, 23, 30]);
+    assert_eq!(labels, ["Alice", "Bob", "elf 3"]);
+}
+
+#[test]
+fn single_input_matches_top_elves_sum() {
+    let elves = read_inputs(&[day1::EXAMPLE], Format::Blocks, Merge::Append).unwrap();
+    let top: u64 = day1::largest_elves(elves, 3)
+        .iter()
+        .map(|elf| elf.calories)
+        .sum();
+    assert_eq!(top, 45000);
+}
+
+#[test]
+fn append_streams_the_same_elves() {
+    let streamed = day1::append_inputs(&[SHARD_A, SHARD_B], Format::Blocks)
+        .collect::<anyhow::Result<Vec<_>>>()
+        .unwrap();
+    let read = read_inputs(&[SHARD_A, SHARD_B], Format::Blocks, Merge::Append).unwrap();
+    assert_eq!(streamed, read);
+}
+
+#[test]
+fn streamed_top_elves() {
+    let inputs = [SHARD_A, SHARD_B];
+    let totals =
+        day1::append_inputs(&inputs, Format::Blocks).map(|elf| elf.map(|elf| elf.calories));
+    assert_eq!(day1::top_k(totals, 2).unwrap(), [30, 20]);
+
+    // Ties keep their input order, like `largest_elves`
+    for top_slots in 0..=6 {
+        let streamed =
+            day1::largest_elves_from(day1::append_inputs(&inputs, Format::Blocks), top_slots)
+                .unwrap();
+        let read = read_inputs(&inputs, Format::Blocks, Merge::Append).unwrap();
+        assert_eq!(
+            streamed,
+            day1::largest_elves(read, top_slots),
+            "{top_slots}"
+        );
+    }
+}
+
+#[test]
+fn streaming_stops_at_first_error() {
+    let elves = day1::append_inputs(&[SHARD_A, "1\n\nx\n"], Format::Blocks);
+    assert!(day1::largest_elves_from(elves, 1).is_err());
+}
@@ -0,0 +1,43 @@
+use day1::{elf_stats, elf_totals, top_elves, Elf, Format};
+
+const NAMED: &str = "\
+# Alice
+1000
+2000
+
+4000
+
+# Bob
+5000
+6000
+";
+
+#[test]
+fn headers_name_elves() {
+    let elves: Vec<Elf> = day1::elves(NAMED.as_bytes(), Format::Blocks)
+        .collect::<anyhow::Result<_>>()
+        .unwrap();
+    let labels: Vec<String> = elves.iter().map(|elf| elf.to_string()).collect();
+    assert_eq!(labels, ["Alice", "elf 2", "Bob"]);
+    assert_eq!(elves[2].calories, 11000);
+}
+
+#[test]
+fn headers_are_not_calories() {
+    let totals: Vec<u64> = elf_totals(NAMED.as_bytes(), Format::Blocks)
+        .collect::<anyhow::Result<_>>()
+        .unwrap();
+    assert_eq!(totals, [3000, 4000, 11000]);
+}
+
+#[test]
+fn breakdown_and_stats_use_names() {
+    let top = top_elves(NAMED, 2, Format::Blocks).unwrap();
+    assert_eq!(top[0].to_string(), "Bob");
+    assert_eq!(top[1].to_string(), "elf 2");
+
+    let stats = elf_stats(NAMED, Format::Blocks).unwrap().unwrap();
+    assert_eq!(stats.min_elf().to_string(), "Alice");
+    assert_eq!(stats.max_elf().to_string(), "Bob");
+    assert!(stats.to_string().contains("max: 11000 (Bob)"));
+}
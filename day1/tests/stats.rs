@@ -0,0 +1,28 @@
+use day1::{elf_stats, Format, Stats};
+
+#[test]
+fn example_stats() {
+    let stats = elf_stats(day1::EXAMPLE, Format::Blocks).unwrap().unwrap();
+    assert_eq!(stats.count(), 5);
+    assert_eq!(stats.min(), 4000);
+    assert_eq!(stats.max(), 24000);
+    assert_eq!(stats.mean(), 11000.0);
+    assert_eq!(stats.median(), 10000.0);
+    assert_eq!(stats.percentile(50.0), 10000);
+    assert_eq!(stats.percentile(90.0), 24000);
+}
+
+#[test]
+fn even_count_median_and_percentiles() {
+    let stats = Stats::new(vec![40, 10, 30, 20]).unwrap();
+    assert_eq!(stats.median(), 25.0);
+    assert_eq!(stats.percentile(0.0), 10);
+    assert_eq!(stats.percentile(25.0), 10);
+    assert_eq!(stats.percentile(26.0), 20);
+    assert_eq!(stats.percentile(100.0), 40);
+}
+
+#[test]
+fn no_elves() {
+    assert_eq!(elf_stats("", Format::Blocks).unwrap(), None);
+}
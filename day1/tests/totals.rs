@@ -0,0 +1,60 @@
+use day1::{elf_totals, top_k, top_k_with, Format, TopKStrategy};
+
+fn totals(input: &str) -> Vec<u64> {
+    elf_totals(input.as_bytes(), Format::Blocks)
+        .collect::<anyhow::Result<_>>()
+        .unwrap()
+}
+
+#[test]
+fn example_totals() {
+    assert_eq!(totals(day1::EXAMPLE), [6000, 4000, 11000, 24000, 10000]);
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(totals(""), []);
+    assert_eq!(totals("\n\n"), []);
+}
+
+#[test]
+fn trailing_blank_line() {
+    assert_eq!(totals("1\n2\n\n3\n\n"), [3, 3]);
+    assert_eq!(totals("1\n2\n\n3"), [3, 3]);
+}
+
+#[test]
+fn consecutive_blank_lines() {
+    assert_eq!(totals("\n\n1\n\n\n\n2\n \n3\n"), [1, 2, 3]);
+}
+
+#[test]
+fn crlf_line_endings() {
+    assert_eq!(totals("1\r\n2\r\n\r\n3\r\n"), [3, 3]);
+}
+
+#[test]
+fn invalid_calories() {
+    let mut totals = elf_totals("1\n\nsnacks\n\n3\n".as_bytes(), Format::Blocks);
+    assert_eq!(totals.next().unwrap().unwrap(), 1);
+    assert!(totals.next().unwrap().is_err());
+}
+
+#[test]
+fn top_k_is_largest_first() {
+    let totals = elf_totals(day1::EXAMPLE.as_bytes(), Format::Blocks);
+    assert_eq!(top_k(totals, 3).unwrap(), [24000, 11000, 10000]);
+
+    let totals = elf_totals(day1::EXAMPLE.as_bytes(), Format::Blocks);
+    assert_eq!(top_k(totals, 10).unwrap().len(), 5);
+}
+
+#[test]
+fn top_k_strategies_agree() {
+    let totals: Vec<u64> = (0..500).map(|i| (i * 7919) % 1013).collect();
+    for k in [0, 1, 3, 100, 500, 1000] {
+        let heap = top_k_with(totals.iter().map(|&t| Ok(t)), k, TopKStrategy::Heap).unwrap();
+        let sort = top_k_with(totals.iter().map(|&t| Ok(t)), k, TopKStrategy::Sort).unwrap();
+        assert_eq!(heap, sort, "k = {k}");
+    }
+}
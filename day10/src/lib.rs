@@ -0,0 +1,120 @@
+#![feature(generators, generator_trait)]
+
+use std::{
+    cell::Cell,
+    ops::{Generator, GeneratorState},
+    pin::Pin,
+    str::FromStr,
+};
+
+use common::answer::Answer;
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(_input: &str) -> eyre::Result<Answer> {
+    eyre::bail!("part 1 is not implemented");
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let program = input
+        .lines()
+        .map(Instruction::from_str)
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    let system = System::new();
+    let mut run_system = system.run(program.into_iter().map(Ok));
+    let mut current_cycle = 0;
+    let mut screen = String::new();
+
+    let result = loop {
+        match Pin::new(&mut run_system).resume(()) {
+            GeneratorState::Yielded(()) => {
+                let sprite_x = system.x.get();
+                let sprite_range = (sprite_x - 1)..=(sprite_x + 1);
+                let screen_x = current_cycle % 40;
+
+                if screen_x == 0 && current_cycle > 0 {
+                    screen.push('\n');
+                }
+
+                if sprite_range.contains(&screen_x) {
+                    screen.push('#');
+                } else {
+                    screen.push('.');
+                }
+
+                current_cycle += 1;
+            }
+            GeneratorState::Complete(result) => {
+                break result;
+            }
+        }
+    };
+    result?;
+
+    Ok(Answer::Grid(screen))
+}
+
+#[derive(Debug)]
+struct System {
+    x: Cell<i64>,
+}
+
+impl System {
+    fn new() -> Self {
+        Self { x: Cell::new(1) }
+    }
+
+    fn run(
+        &self,
+        program: impl Iterator<Item = eyre::Result<Instruction>> + 'static,
+    ) -> impl Generator<(), Yield = (), Return = eyre::Result<()>> + '_ {
+        move || {
+            for instruction in program {
+                let instruction = instruction?;
+                match instruction {
+                    Instruction::NoOp => {
+                        yield;
+                    }
+                    Instruction::AddX(value) => {
+                        yield;
+                        yield;
+                        let x = self.x.get();
+                        self.x.set(x + value);
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+enum Instruction {
+    NoOp,
+    AddX(i64),
+}
+
+impl FromStr for Instruction {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_whitespace();
+        let opcode = fields.next().ok_or_else(|| eyre::eyre!("empty opcode"))?;
+        let instruction = match opcode {
+            "noop" => Self::NoOp,
+            "addx" => {
+                let value = fields
+                    .next()
+                    .ok_or_else(|| eyre::eyre!("no arg for addx"))?;
+                let value = value.parse()?;
+                Self::AddX(value)
+            }
+            unknown => eyre::bail!("unknown opcode: {unknown:?}"),
+        };
+
+        eyre::ensure!(fields.next().is_none(), "unexpected argument");
+
+        Ok(instruction)
+    }
+}
@@ -0,0 +1,5 @@
+#[test]
+fn crt_example() {
+    let screen = day10::solve_part2(day10::EXAMPLE).unwrap();
+    insta::assert_snapshot!(screen);
+}
@@ -0,0 +1,494 @@
+use std::{cmp::Reverse, str::FromStr};
+
+use common::{
+    answer::Answer,
+    diagnostics::ParseError,
+    input::blocks,
+    modular::{self, Residues},
+};
+use joinery::JoinableIterator;
+use num_bigint::BigInt;
+use num_traits::Zero;
+use regex::Regex;
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let monkeys = parse_monkeys(input)?;
+    let monkey_business = play_keep_away(monkeys, 20, Relief::DivideByThree);
+    Ok(monkey_business.into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let monkeys = parse_monkeys(input)?;
+    let monkey_business = play_keep_away(monkeys, 10000, Relief::None);
+    Ok(monkey_business.into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief {
+    DivideByThree,
+    None,
+}
+
+pub fn parse_monkeys(input: &str) -> eyre::Result<Vec<Monkey>> {
+    let mut monkeys = vec![];
+
+    for block in blocks(input) {
+        let mut lines = block.lines();
+        let Some(header_line) = lines.next() else {
+            continue;
+        };
+
+        let header_captures = HEADER_REGEX
+            .captures(header_line)
+            .ok_or_else(|| ParseError::at(input, header_line, "invalid header"))?;
+        let monkey_index = header_captures.get(1).unwrap();
+        let monkey_index: usize = monkey_index.as_str().parse().unwrap();
+        if monkey_index != monkeys.len() {
+            let message = format!("expected index {}, got {monkey_index}", monkeys.len());
+            return Err(ParseError::at(input, header_line, message).into());
+        }
+
+        let items_line = lines
+            .next()
+            .ok_or_else(|| missing_line(input, header_line, "items"))?;
+        let items_capture = ITEMS_REGEX
+            .captures(items_line)
+            .ok_or_else(|| ParseError::at(input, items_line, "invalid items"))?;
+        let items = items_capture.get(1).unwrap();
+        let items = items.as_str().split(", ").map(|item_worry| {
+            let worry = item_worry
+                .parse()
+                .map_err(|error| ParseError::at(input, item_worry, error))?;
+            eyre::Result::Ok(Item {
+                worry: Worry::Exact(worry),
+            })
+        });
+        let items = items.collect::<eyre::Result<Vec<_>>>()?;
+
+        let operation_line = lines
+            .next()
+            .ok_or_else(|| missing_line(input, header_line, "operation"))?;
+        let operation_capture = OPERATION_REGEX
+            .captures(operation_line)
+            .ok_or_else(|| ParseError::at(input, operation_line, "invalid operation"))?;
+        let operation = operation_capture.get(1).unwrap().as_str();
+        let operation: Operation = parse_part(input, operation)?;
+
+        let test_line = lines
+            .next()
+            .ok_or_else(|| missing_line(input, header_line, "test"))?;
+        let test_capture = TEST_REGEX
+            .captures(test_line)
+            .ok_or_else(|| ParseError::at(input, test_line, "invalid test"))?;
+        let test = test_capture.get(1).unwrap().as_str();
+        let test: Test = parse_part(input, test)?;
+
+        let condition_1_line = lines
+            .next()
+            .ok_or_else(|| missing_line(input, header_line, "condition 1"))?;
+        let condition_1_capture = CONDITION_REGEX
+            .captures(condition_1_line)
+            .ok_or_else(|| ParseError::at(input, condition_1_line, "invalid condition"))?;
+        let condition_1_when = condition_1_capture.get(1).unwrap().as_str();
+        let condition_1_action: Action =
+            parse_part(input, condition_1_capture.get(2).unwrap().as_str())?;
+
+        let condition_2_line = lines
+            .next()
+            .ok_or_else(|| missing_line(input, header_line, "condition 2"))?;
+        let condition_2_capture = CONDITION_REGEX
+            .captures(condition_2_line)
+            .ok_or_else(|| ParseError::at(input, condition_2_line, "invalid condition"))?;
+        let condition_2_when = condition_2_capture.get(1).unwrap().as_str();
+        let condition_2_action: Action =
+            parse_part(input, condition_2_capture.get(2).unwrap().as_str())?;
+
+        let (if_true, if_false) = match (condition_1_when, condition_2_when) {
+            ("true", "false") => (condition_1_action, condition_2_action),
+            _ => {
+                let message = "expected an `If true` condition followed by `If false`";
+                return Err(ParseError::at(input, condition_1_line, message).into());
+            }
+        };
+
+        let condition = Condition {
+            test,
+            if_true,
+            if_false,
+        };
+
+        let monkey = Monkey {
+            inspections: 0,
+            items,
+            operation,
+            condition,
+        };
+
+        monkeys.push(monkey);
+    }
+
+    Ok(monkeys)
+}
+
+fn missing_line(input: &str, header_line: &str, name: &str) -> ParseError {
+    ParseError::at(input, header_line, format_args!("no {name} for monkey"))
+}
+
+// Parse part of a line, pointing any error at that part of the input
+fn parse_part<T>(input: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = eyre::Error>,
+{
+    part.parse()
+        .map_err(|error| ParseError::at(input, part, format_args!("{error:#}")))
+}
+
+lazy_static::lazy_static! {
+    static ref HEADER_REGEX: Regex = Regex::new(r#"^Monkey (\d+):$"#).unwrap();
+    static ref ITEMS_REGEX: Regex = Regex::new(r##"^\s+Starting items: ([\d, ]+)$"##).unwrap();
+    static ref OPERATION_REGEX: Regex = Regex::new(r##"^\s+Operation: new = (.+)$"##).unwrap();
+    static ref TEST_REGEX: Regex = Regex::new(r##"^\s+Test: (divisible by \d+)$"##).unwrap();
+    static ref CONDITION_REGEX: Regex = Regex::new(r##"\s+If (true|false): (throw to monkey \d+)$"##).unwrap();
+}
+
+pub fn play_keep_away(mut monkeys: Vec<Monkey>, rounds: u64, relief: Relief) -> usize {
+    if relief == Relief::None {
+        // Without relief, worry levels grow without bound. Each monkey only
+        // checks divisibility, so track them modulo every monkey's divisor
+        let divisors: Vec<_> = monkeys.iter().map(|monkey| monkey.divisor()).collect();
+
+        tracing::info!("Tracking worry levels modulo {divisors:?}");
+
+        for item in monkeys.iter_mut().flat_map(|monkey| &mut monkey.items) {
+            item.worry = item.worry.to_residues(&divisors);
+        }
+    }
+
+    for round in 1..=rounds {
+        tracing::info!("Round {round}");
+
+        for i in 0..monkeys.len() {
+            tracing::trace!("Monkey {i}:");
+            let outcomes = monkeys[i].play_turn(relief);
+            for outcome in outcomes {
+                match outcome {
+                    Outcome::ThrowToMonkey { item, target } => {
+                        monkeys[target].items.push(item);
+                    }
+                }
+            }
+        }
+
+        tracing::debug!(
+            "After round {round}, the monkeys are holding items with these worry levels:"
+        );
+        for (i, monkey) in monkeys.iter().enumerate() {
+            tracing::debug!(
+                "Monkey {i}: {}",
+                monkey
+                    .items
+                    .iter()
+                    .map(|item| lazy_format::lazy_format!("{}", item.worry))
+                    .join_with(", ")
+            );
+        }
+        tracing::debug!("");
+    }
+
+    monkeys.sort_by_key(|monkey| Reverse(monkey.inspections));
+
+    let monkey_business = monkeys
+        .iter()
+        .take(2)
+        .map(|monkey| monkey.inspections)
+        .product();
+    monkey_business
+}
+
+#[derive(Debug)]
+pub struct Monkey {
+    inspections: usize,
+    items: Vec<Item>,
+    operation: Operation,
+    condition: Condition,
+}
+
+impl Monkey {
+    fn play_turn(&mut self, relief: Relief) -> Vec<Outcome> {
+        let mut outcomes = vec![];
+
+        for mut item in self.items.drain(..) {
+            tracing::trace!(
+                "  Monkey inspect an item with a worry level of {}",
+                item.worry
+            );
+
+            // Inspect the item
+            self.operation.apply(&mut item.worry);
+
+            tracing::trace!("    Worry level becomes {}", item.worry);
+
+            match relief {
+                Relief::DivideByThree => {
+                    // Relief from the item not being damaged
+                    item.worry.divide_by_three();
+
+                    tracing::trace!(
+                        "    Monkey gets bored with item. Worry level is divided by 3 to {}",
+                        item.worry
+                    );
+                }
+                Relief::None => {
+                    // Worry is tracked by its residues, so it stays bounded
+                }
+            }
+
+            // Result of the inspection
+            let action = self.condition.action(&item.worry);
+            let outcome = match *action {
+                Action::ThrowToMonkey(target) => {
+                    tracing::trace!(
+                        "    Item with worry level {} is thrown to monkey {target}",
+                        item.worry
+                    );
+                    Outcome::ThrowToMonkey { item, target }
+                }
+            };
+            outcomes.push(outcome);
+
+            // Count the inspection
+            self.inspections += 1;
+        }
+
+        outcomes
+    }
+
+    fn divisor(&self) -> u64 {
+        match self.condition.test {
+            Test::DivisibleBy(divisor) => divisor,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Item {
+    worry: Worry,
+}
+
+#[derive(Debug)]
+enum Worry {
+    Exact(BigInt),
+    Residues(Residues),
+}
+
+impl Worry {
+    fn to_residues(&self, divisors: &[u64]) -> Worry {
+        match self {
+            Worry::Exact(worry) => {
+                let worry = worry.try_into().expect("worry level out of range");
+                Worry::Residues(Residues::new(worry, divisors))
+            }
+            Worry::Residues(residues) => Worry::Residues(residues.clone()),
+        }
+    }
+
+    fn divide_by_three(&mut self) {
+        match self {
+            Worry::Exact(worry) => {
+                *worry /= 3u32;
+            }
+            Worry::Residues(_) => {
+                panic!("worry levels tracked by residues can't be divided");
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Worry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Worry::Exact(worry) => write!(f, "{worry}"),
+            Worry::Residues(residues) => write!(f, "{residues}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(Operand, Operand),
+    Multiply(Operand, Operand),
+}
+
+impl Operation {
+    fn apply(&self, worry: &mut Worry) {
+        match worry {
+            Worry::Exact(old) => {
+                *old = match self {
+                    Operation::Add(op1, op2) => op1.exact(old) + op2.exact(old),
+                    Operation::Multiply(op1, op2) => op1.exact(old) * op2.exact(old),
+                };
+            }
+            Worry::Residues(residues) => {
+                // Update each residue in place, since this runs for every
+                // item on every turn
+                residues.update(|old, modulus| match self {
+                    Operation::Add(op1, op2) => modular::add_mod(
+                        op1.residue(old, modulus),
+                        op2.residue(old, modulus),
+                        modulus,
+                    ),
+                    Operation::Multiply(op1, op2) => modular::mul_mod(
+                        op1.residue(old, modulus),
+                        op2.residue(old, modulus),
+                        modulus,
+                    ),
+                });
+            }
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let operand_1 = tokens
+            .next()
+            .ok_or_else(|| eyre::eyre!("expected more tokens"))?;
+        let operand_1: Operand = operand_1.parse()?;
+
+        let operator = tokens
+            .next()
+            .ok_or_else(|| eyre::eyre!("expected more tokens"))?;
+
+        let operand_2 = tokens
+            .next()
+            .ok_or_else(|| eyre::eyre!("expected more tokens"))?;
+        let operand_2: Operand = operand_2.parse()?;
+
+        if tokens.next().is_some() {
+            eyre::bail!("unexpected token in operation: {s}");
+        }
+
+        match operator {
+            "+" => Ok(Self::Add(operand_1, operand_2)),
+            "*" => Ok(Self::Multiply(operand_1, operand_2)),
+            other => eyre::bail!("unknown operator {other:?} in operation: {s}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Value(u64),
+    Old,
+}
+
+impl Operand {
+    fn exact(&self, old: &BigInt) -> BigInt {
+        match self {
+            Operand::Value(value) => BigInt::from(*value),
+            Operand::Old => old.clone(),
+        }
+    }
+
+    /// The operand modulo `modulus`, where `old` is the old worry level's
+    /// residue
+    fn residue(&self, old: u64, modulus: u64) -> u64 {
+        match self {
+            Operand::Value(value) => value % modulus,
+            Operand::Old => old,
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "old" => Ok(Self::Old),
+            value => {
+                let value = value.parse()?;
+                Ok(Self::Value(value))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Condition {
+    test: Test,
+    if_true: Action,
+    if_false: Action,
+}
+
+impl Condition {
+    fn action(&self, value: &Worry) -> &Action {
+        if self.test.passes(value) {
+            &self.if_true
+        } else {
+            &self.if_false
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Test {
+    DivisibleBy(u64),
+}
+
+impl Test {
+    fn passes(&self, value: &Worry) -> bool {
+        match (self, value) {
+            (Test::DivisibleBy(divisor), Worry::Exact(value)) => (value % *divisor).is_zero(),
+            (Test::DivisibleBy(divisor), Worry::Residues(residues)) => {
+                residues.is_divisible_by(*divisor)
+            }
+        }
+    }
+}
+
+impl FromStr for Test {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once("divisible by ") {
+            Some(("", divisor)) => {
+                let divisor = divisor.parse()?;
+                Ok(Self::DivisibleBy(divisor))
+            }
+            _ => {
+                eyre::bail!("invalid condition: {s}");
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    ThrowToMonkey(usize),
+}
+
+impl FromStr for Action {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once("throw to monkey ") {
+            Some(("", to_monkey_index)) => {
+                let to_monkey_index = to_monkey_index.parse()?;
+                Ok(Self::ThrowToMonkey(to_monkey_index))
+            }
+            _ => {
+                eyre::bail!("invalid action: {s}");
+            }
+        }
+    }
+}
+
+enum Outcome {
+    ThrowToMonkey { item: Item, target: usize },
+}
@@ -0,0 +1,121 @@
+use common::{answer::Answer, grid::Position, pathfinding::bfs};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let grid = Grid::parse(input)?;
+    let fewest_steps = grid.find_fewest_steps_from_start()?;
+    Ok(fewest_steps.into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let grid = Grid::parse(input)?;
+    let fewest_steps = grid.find_fewest_steps()?;
+    Ok(fewest_steps.into())
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    cell_heights: common::grid::Grid<u8>,
+    start: Position,
+    peaks: Vec<Position>,
+    end: Position,
+}
+
+impl Grid {
+    pub fn parse(input: &str) -> eyre::Result<Self> {
+        let cells = common::grid::Grid::parse(input)?;
+        let mut start = None;
+        let mut peaks = vec![];
+        let mut end = None;
+        for position in cells.positions() {
+            match cells[position] {
+                b'a' => {
+                    peaks.push(position);
+                }
+                b'S' => {
+                    peaks.push(position);
+                    let old_start = start.replace(position);
+                    if let Some(old_start) = old_start {
+                        eyre::bail!("found multiple start points at {old_start:?} and {start:?}");
+                    }
+                }
+                b'E' => {
+                    let old_end = end.replace(position);
+
+                    if let Some(old_end) = old_end {
+                        eyre::bail!("found multiple end points at {old_end:?} and {end:?}");
+                    }
+                }
+                b'b'..=b'z' => {}
+                other => {
+                    eyre::bail!("could not parse byte {} at ({position:?})", other)
+                }
+            }
+        }
+
+        let start = start.ok_or_else(|| eyre::eyre!("start not set"))?;
+        let end = end.ok_or_else(|| eyre::eyre!("end not set"))?;
+
+        let cell_heights = cells.map(|byte| match byte {
+            b'S' => 0,
+            b'E' => 25,
+            height => height - b'a',
+        });
+
+        Ok(Self {
+            cell_heights,
+            start,
+            peaks,
+            end,
+        })
+    }
+
+    fn successors(&self, position: Position) -> eyre::Result<impl Iterator<Item = Position> + '_> {
+        let current_height = *self
+            .cell_heights
+            .get(position)
+            .ok_or_else(|| eyre::eyre!("could not get height at position {position:?}"))?;
+        let candidates = self.cell_heights.orthogonal_neighbors(position);
+        let successors = candidates.filter(move |&position| {
+            let height = self.cell_heights[position];
+            height <= current_height + 1
+        });
+
+        Ok(successors.collect::<Vec<_>>().into_iter())
+    }
+
+    pub fn find_fewest_steps_from_start(&self) -> eyre::Result<usize> {
+        let fewest_steps = self
+            .find_fewest_steps_from(self.start)
+            .ok_or_else(|| eyre::eyre!("no path found"))?;
+
+        Ok(fewest_steps)
+    }
+
+    pub fn find_fewest_steps(&self) -> eyre::Result<usize> {
+        let fewest_steps = self
+            .peaks
+            .iter()
+            .filter_map(|&peak| self.find_fewest_steps_from(peak))
+            .min();
+
+        let fewest_steps =
+            fewest_steps.ok_or_else(|| eyre::eyre!("no paths found for any peaks"))?;
+
+        Ok(fewest_steps)
+    }
+
+    fn find_fewest_steps_from(&self, start: Position) -> Option<usize> {
+        let path = bfs(
+            start,
+            |&pos| self.successors(pos).unwrap(),
+            |&pos| pos == self.end,
+        )?;
+
+        // Subtract 1 to get the number of movements required
+        let fewest_steps = path.len() - 1;
+
+        Some(fewest_steps)
+    }
+}
@@ -0,0 +1,166 @@
+use std::{fmt::Display, str::FromStr};
+
+use common::{answer::Answer, input::read_blocks};
+use joinery::JoinableIterator;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::{complete, map, map_res},
+    error::VerboseError,
+    multi::separated_list0,
+    sequence::delimited,
+    IResult,
+};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let mut sum_correctly_ordered_indices = 0;
+    for (index, block) in read_blocks(input.as_bytes()).enumerate() {
+        let block = block?;
+        let [line_left, line_right] = block.as_slice() else {
+            eyre::bail!("expected a pair of packets, got {} lines", block.len());
+        };
+
+        let left_packet: Packet = line_left.parse()?;
+        let right_packet: Packet = line_right.parse()?;
+
+        if left_packet < right_packet {
+            sum_correctly_ordered_indices += index + 1;
+        }
+    }
+
+    Ok(sum_correctly_ordered_indices.into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let mut packets = vec![];
+    for block in read_blocks(input.as_bytes()) {
+        for line in block? {
+            let packet: Packet = line.parse()?;
+            packets.push(packet);
+        }
+    }
+
+    let divider_packets = [
+        Packet::List(vec![Packet::List(vec![Packet::Number(2)])]),
+        Packet::List(vec![Packet::List(vec![Packet::Number(6)])]),
+    ];
+
+    packets.extend(divider_packets.clone());
+
+    packets.sort();
+
+    let decoder_key: usize = divider_packets
+        .iter()
+        .map(|divider| {
+            let divider_index = packets.iter().enumerate().find_map(|(index, packet)| {
+                if packet == divider {
+                    Some(index + 1)
+                } else {
+                    None
+                }
+            });
+            divider_index.expect("divider packet not found")
+        })
+        .product();
+
+    Ok(decoder_key.into())
+}
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let result = match (self, other) {
+            (Packet::Number(left), Packet::Number(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => {
+                let left = left.iter().map(Some).chain(std::iter::repeat(None));
+                let right = right.iter().map(Some).chain(std::iter::repeat(None));
+                for (left, right) in left.zip(right) {
+                    match (left, right) {
+                        (Some(left), Some(right)) => match left.cmp(right) {
+                            std::cmp::Ordering::Equal => {
+                                // Values are equal, so keep iterating
+                            }
+                            cmp => return cmp,
+                        },
+                        (None, None) => return std::cmp::Ordering::Equal,
+                        (None, Some(_)) => return std::cmp::Ordering::Less,
+                        (Some(_), None) => return std::cmp::Ordering::Greater,
+                    }
+                }
+
+                // The iterator above is infinite
+                unreachable!();
+            }
+            (Packet::Number(left), right @ Packet::List(_)) => {
+                Packet::List(vec![Packet::Number(*left)]).cmp(right)
+            }
+            (left @ Packet::List(_), Packet::Number(right)) => {
+                left.cmp(&Packet::List(vec![Packet::Number(*right)]))
+            }
+        };
+
+        result
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::Number(value) => write!(f, "{}", value),
+            Packet::List(values) => {
+                write!(f, "[{}]", values.iter().join_with(", "))
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = complete(parse_packet);
+        let (_, value) = parser(s).map_err(|err| eyre::eyre!("parse error: {err}"))?;
+
+        Ok(value)
+    }
+}
+
+fn parse_packet(i: &str) -> IResult<&str, Packet, VerboseError<&str>> {
+    let mut parser = alt((
+        map(parse_packet_number, Packet::Number),
+        map(parse_packet_list, Packet::List),
+    ));
+    parser(i)
+}
+
+fn parse_packet_number(i: &str) -> IResult<&str, u32, VerboseError<&str>> {
+    let mut parser = map_res(digit1, |s: &str| s.parse());
+    parser(i)
+}
+
+fn parse_packet_list(i: &str) -> IResult<&str, Vec<Packet>, VerboseError<&str>> {
+    let mut parser = delimited(tag("["), separated_list0(tag(","), parse_packet), tag("]"));
+    parser(i)
+}
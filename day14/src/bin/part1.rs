@@ -1,254 +1,47 @@
-use std::{
-    fmt::Display,
-    io::BufRead,
-    ops::{Index, IndexMut},
-};
+use std::{io::Write, ops::ControlFlow, path::PathBuf};
 
 use clap::Parser;
-use day14::{Bounds, Path, Point, Vector};
-use eyre::ContextCompat;
-use joinery::JoinableIterator;
+use cli::{DisplayArgs, InputArgs};
+use common::{config::Config, render::Renderable};
+use day14::{Floor, World, STARTING_POINT};
 
 #[derive(Parser)]
 struct Args {
-    #[clap(short, long)]
-    display: bool,
-    #[clap(short, long, default_value_t = 50)]
-    rate: u64,
+    #[clap(flatten)]
+    display: DisplayArgs,
+    #[clap(flatten)]
+    input: InputArgs,
+    #[clap(long)]
+    dump: Option<PathBuf>,
 }
 
 fn main() -> eyre::Result<()> {
     color_eyre::install()?;
 
     let args = Args::parse();
+    let config = Config::load()?;
+    let default_rate = config.get("day14", "rate")?.unwrap_or(50);
 
-    let stdin = std::io::stdin().lock();
+    let input = args.input.read(day14::EXAMPLE)?;
+    let paths = day14::parse_paths(&input)?;
 
-    let paths = stdin
-        .lines()
-        .map(|line| line?.parse())
-        .collect::<eyre::Result<Vec<Path>>>()?;
+    let mut world = World::new(STARTING_POINT, &paths, Floor::Abyss);
 
-    let mut world = World::new(STARTING_POINT, &paths);
+    let animation = args.display.animation(default_rate);
+    let steps = animation.run(&mut world, |_, ()| ControlFlow::Continue(()));
 
-    if args.display {
-        println!("{}", termion::clear::All);
-    }
-
-    let mut steps = 0;
-    loop {
-        if args.display {
-            println!(
-                "{}{}Steps: {steps}\n{}",
-                termion::cursor::Goto(1, 1),
-                termion::clear::CurrentLine,
-                world.display(),
-            );
-            std::thread::sleep(std::time::Duration::from_millis(args.rate));
-        }
-
-        let is_running = world.step();
-        if !is_running {
-            break;
-        }
-
-        steps += 1;
-    }
-
-    println!("Total steps: {steps}\n{}", world.display());
+    println!("Total steps: {steps}\n{}", world.render());
 
-    let resting_sand = world
-        .cells
-        .iter()
-        .filter(|&(_, cell)| cell == Cell::SettledSand)
-        .count();
+    let resting_sand = world.resting_sand();
     println!("Resting sand: {resting_sand}");
 
-    Ok(())
-}
-
-const STARTING_POINT: Point = Point { x: 500, y: 0 };
-
-struct World {
-    cells: Cells,
-    source: Point,
-}
-
-impl World {
-    fn new(source: Point, paths: &[Path]) -> Self {
-        let mut bounds = Bounds::new(source);
-
-        for path in paths {
-            for &point in &path.points {
-                bounds.add(point);
-            }
+    if let Some(dump) = &args.dump {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(dump)?);
+        for point in world.settled_sand() {
+            serde_json::to_writer(&mut file, &point)?;
+            writeln!(file)?;
         }
-
-        let mut cells = Cells::new(Cell::Air, bounds);
-
-        for path in paths {
-            for line in path.lines() {
-                for point in line.points() {
-                    cells[point] = Cell::Rock;
-                }
-            }
-        }
-
-        Self { cells, source }
-    }
-
-    fn display(&self) -> impl Display + '_ {
-        let ys = self.cells.bounds.y_bounds();
-
-        ys.map(move |y| {
-            let xs = self.cells.bounds.x_bounds();
-
-            xs.map(move |x| {
-                let point = Point { x, y };
-
-                if point == self.source {
-                    '+'
-                } else {
-                    match self.cells[point] {
-                        Cell::Air => '.',
-                        Cell::Rock => '#',
-                        Cell::FallingSand => '~',
-                        Cell::SettledSand => 'o',
-                    }
-                }
-            })
-            .join_concat()
-        })
-        .join_with("\n")
     }
 
-    fn step(&mut self) -> bool {
-        let falling_sand = self
-            .cells
-            .iter()
-            .find(|&(_, cell)| cell == Cell::FallingSand);
-
-        match falling_sand {
-            Some((current_sand_point, current_sand_cell)) => {
-                let mut new_point: Option<Point> = None;
-
-                for falling_vector in FALLING_SAND_VECTORS {
-                    let candidate_point = current_sand_point + falling_vector;
-                    match self.cells.get(candidate_point) {
-                        Some(Cell::Air) => {
-                            new_point = Some(candidate_point);
-                            break;
-                        }
-                        Some(Cell::Rock | Cell::FallingSand | Cell::SettledSand) => {}
-                        None => {
-                            // Next position doesn't exist, so sand flowed out of bounds.
-                            return false;
-                        }
-                    }
-                }
-
-                match new_point {
-                    Some(new_point) => {
-                        self.cells[new_point] = current_sand_cell;
-                        self.cells[current_sand_point] = Cell::Air;
-                    }
-                    None => {
-                        self.cells[current_sand_point] = Cell::SettledSand;
-                    }
-                }
-            }
-            None => {
-                self.cells[self.source] = Cell::FallingSand;
-            }
-        }
-
-        true
-    }
-}
-
-const FALLING_SAND_VECTORS: [Vector; 3] = [
-    Vector { x: 0, y: 1 },
-    Vector { x: -1, y: 1 },
-    Vector { x: 1, y: 1 },
-];
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Cell {
-    Air,
-    Rock,
-    FallingSand,
-    SettledSand,
-}
-
-struct Cells {
-    bounds: Bounds,
-    cells: Vec<Cell>,
-}
-
-impl Cells {
-    fn new(cell: Cell, bounds: Bounds) -> Self {
-        let num_cells = bounds.width() * bounds.height();
-        let num_cells = num_cells.try_into().unwrap();
-        let cells = vec![cell; num_cells];
-
-        Self { bounds, cells }
-    }
-
-    fn offset(&self, point: Point) -> Option<usize> {
-        if !self.bounds.contains(point) {
-            return None;
-        }
-
-        let row = point.x - self.bounds.min.x;
-        let col = point.y - self.bounds.min.y;
-
-        let offset = (col * self.bounds.width()) + row;
-        let offset = offset.try_into().unwrap();
-
-        Some(offset)
-    }
-
-    fn get(&self, point: Point) -> Option<&Cell> {
-        let offset = self.offset(point)?;
-        Some(&self.cells[offset])
-    }
-
-    fn get_mut(&mut self, point: Point) -> Option<&mut Cell> {
-        let offset = self.offset(point)?;
-        Some(&mut self.cells[offset])
-    }
-
-    fn iter(&self) -> impl Iterator<Item = (Point, Cell)> + '_ {
-        let ys = self.bounds.y_bounds();
-
-        ys.flat_map(move |y| {
-            let xs = self.bounds.x_bounds();
-
-            xs.map(move |x| {
-                let point = Point { x, y };
-                let cell = self[point];
-                (point, cell)
-            })
-        })
-    }
-}
-
-impl Index<Point> for Cells {
-    type Output = Cell;
-
-    fn index(&self, point: Point) -> &Cell {
-        let bounds = self.bounds;
-        self.get(point)
-            .with_context(|| format!("point {point} was out of bounds {bounds:?}"))
-            .unwrap()
-    }
-}
-
-impl IndexMut<Point> for Cells {
-    fn index_mut(&mut self, point: Point) -> &mut Cell {
-        let bounds = self.bounds;
-        self.get_mut(point)
-            .with_context(|| format!("point {point} was out of bounds {bounds:?}"))
-            .unwrap()
-    }
+    Ok(())
 }
@@ -1,291 +1,59 @@
-use std::{
-    fmt::Display,
-    io::BufRead,
-    ops::{Index, IndexMut},
-};
+use std::{io::Write, ops::ControlFlow, path::PathBuf};
 
 use clap::Parser;
-use day14::{Bounds, Path, Point, Vector};
-use eyre::ContextCompat;
-use joinery::JoinableIterator;
+use cli::{DisplayArgs, InputArgs};
+use common::{config::Config, render::Renderable};
+use day14::{Floor, World, STARTING_POINT};
 
 #[derive(Parser)]
 struct Args {
+    #[clap(flatten)]
+    display: DisplayArgs,
     #[clap(short, long)]
-    display: bool,
-    #[clap(short, long, default_value_t = 50)]
-    rate: u64,
-    #[clap(short, long)]
-    stop_at: Option<u64>,
+    stop_at: Option<usize>,
+    #[clap(flatten)]
+    input: InputArgs,
+    #[clap(long)]
+    dump: Option<PathBuf>,
 }
 
 fn main() -> eyre::Result<()> {
     color_eyre::install()?;
 
     let args = Args::parse();
+    let config = Config::load()?;
+    let default_rate = config.get("day14", "rate")?.unwrap_or(50);
 
-    let stdin = std::io::stdin().lock();
-
-    let paths = stdin
-        .lines()
-        .map(|line| line?.parse())
-        .collect::<eyre::Result<Vec<Path>>>()?;
+    let input = args.input.read(day14::EXAMPLE)?;
+    let paths = day14::parse_paths(&input)?;
 
-    let mut world = World::new(STARTING_POINT, &paths);
+    let mut world = World::new(STARTING_POINT, &paths, Floor::Rock);
 
-    if args.display {
-        println!("{}", termion::clear::All);
-    }
-
-    let mut steps = 0;
-    loop {
-        if args.display {
-            println!(
-                "{}{}Steps: {steps}\n{}",
-                termion::cursor::Goto(1, 1),
-                termion::clear::CurrentLine,
-                world.display(),
-            );
-            std::thread::sleep(std::time::Duration::from_millis(args.rate));
-        } else if steps % 1000 == 0 {
+    let animation = args.display.animation(default_rate);
+    let steps = animation.run(&mut world, |steps, ()| {
+        if animation.is_headless() && steps % 1000 == 0 {
             println!("Step: {steps}");
         }
 
-        let is_running = world.step();
-        if !is_running {
-            break;
-        }
-
-        steps += 1;
-
         if args.stop_at == Some(steps) {
-            break;
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
         }
-    }
+    });
 
-    println!("Total steps: {steps}\n{}", world.display());
+    println!("Total steps: {steps}\n{}", world.render());
 
-    let resting_sand = world
-        .cells
-        .iter()
-        .filter(|&(_, cell)| cell == Cell::SettledSand)
-        .count();
+    let resting_sand = world.resting_sand();
     println!("Resting sand: {resting_sand}");
 
-    Ok(())
-}
-
-const STARTING_POINT: Point = Point { x: 500, y: 0 };
-
-struct World {
-    cells: Cells,
-    source: Point,
-    current_sand_point: Option<Point>,
-}
-
-impl World {
-    fn new(source: Point, paths: &[Path]) -> Self {
-        let mut bounds = Bounds::new(source);
-
-        for path in paths {
-            for &point in &path.points {
-                bounds.add(point);
-            }
-        }
-
-        let floor_margin = std::cmp::max(bounds.width(), bounds.height());
-        let floor_y = bounds.bottom_left().y + 2;
-        let floor_x_min = bounds.bottom_left().x - floor_margin;
-        let floor_x_max = bounds.bottom_right().x + floor_margin;
-
-        bounds.add(Point {
-            x: floor_x_min - 1,
-            y: floor_y + 1,
-        });
-        bounds.add(Point {
-            x: floor_x_max + 1,
-            y: floor_y + 1,
-        });
-
-        let mut cells = Cells::new(Cell::Air, bounds);
-
-        for path in paths {
-            for line in path.lines() {
-                for point in line.points() {
-                    cells[point] = Cell::Rock;
-                }
-            }
-        }
-
-        for x in floor_x_min..=floor_x_max {
-            let point = Point { x, y: floor_y };
-            cells[point] = Cell::Rock;
-        }
-
-        Self {
-            cells,
-            source,
-            current_sand_point: None,
+    if let Some(dump) = &args.dump {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(dump)?);
+        for point in world.settled_sand() {
+            serde_json::to_writer(&mut file, &point)?;
+            writeln!(file)?;
         }
     }
 
-    fn display(&self) -> impl Display + '_ {
-        let ys = self.cells.bounds.y_bounds();
-
-        ys.map(move |y| {
-            let xs = self.cells.bounds.x_bounds();
-
-            xs.map(move |x| {
-                let point = Point { x, y };
-
-                if point == self.source {
-                    '+'
-                } else {
-                    match self.cells[point] {
-                        Cell::Air => '.',
-                        Cell::Rock => '#',
-                        Cell::FallingSand => '~',
-                        Cell::SettledSand => 'o',
-                    }
-                }
-            })
-            .join_concat()
-        })
-        .join_with("\n")
-    }
-
-    fn step(&mut self) -> bool {
-        match self.current_sand_point {
-            Some(current_sand_point) => {
-                let mut new_point: Option<Point> = None;
-                let mut out_of_bounds = false;
-
-                for falling_vector in FALLING_SAND_VECTORS {
-                    let candidate_point = current_sand_point + falling_vector;
-                    match self.cells.get(candidate_point) {
-                        Some(Cell::Air) => {
-                            new_point = Some(candidate_point);
-                            break;
-                        }
-                        Some(Cell::Rock | Cell::FallingSand | Cell::SettledSand) => {}
-                        None => {
-                            out_of_bounds = true;
-                        }
-                    }
-                }
-
-                match new_point {
-                    Some(new_point) => {
-                        self.cells[new_point] = Cell::FallingSand;
-                        self.cells[current_sand_point] = Cell::Air;
-                        self.current_sand_point = Some(new_point);
-                    }
-                    None => {
-                        assert!(!out_of_bounds, "sand fell out of bounds");
-                        self.cells[current_sand_point] = Cell::SettledSand;
-
-                        if current_sand_point == self.source {
-                            // Sand reached the source
-                            return false;
-                        }
-
-                        self.current_sand_point = None;
-                    }
-                }
-            }
-            None => {
-                self.cells[self.source] = Cell::FallingSand;
-                self.current_sand_point = Some(self.source);
-            }
-        }
-
-        true
-    }
-}
-
-const FALLING_SAND_VECTORS: [Vector; 3] = [
-    Vector { x: 0, y: 1 },
-    Vector { x: -1, y: 1 },
-    Vector { x: 1, y: 1 },
-];
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Cell {
-    Air,
-    Rock,
-    FallingSand,
-    SettledSand,
-}
-
-struct Cells {
-    bounds: Bounds,
-    cells: Vec<Cell>,
-}
-
-impl Cells {
-    fn new(cell: Cell, bounds: Bounds) -> Self {
-        let num_cells = bounds.width() * bounds.height();
-        let num_cells = num_cells.try_into().unwrap();
-        let cells = vec![cell; num_cells];
-
-        Self { bounds, cells }
-    }
-
-    fn offset(&self, point: Point) -> Option<usize> {
-        if !self.bounds.contains(point) {
-            return None;
-        }
-
-        let row = point.x - self.bounds.min.x;
-        let col = point.y - self.bounds.min.y;
-
-        let offset = (col * self.bounds.width()) + row;
-        let offset = offset.try_into().unwrap();
-
-        Some(offset)
-    }
-
-    fn get(&self, point: Point) -> Option<&Cell> {
-        let offset = self.offset(point)?;
-        Some(&self.cells[offset])
-    }
-
-    fn get_mut(&mut self, point: Point) -> Option<&mut Cell> {
-        let offset = self.offset(point)?;
-        Some(&mut self.cells[offset])
-    }
-
-    fn iter(&self) -> impl Iterator<Item = (Point, Cell)> + '_ {
-        let ys = self.bounds.y_bounds();
-
-        ys.flat_map(move |y| {
-            let xs = self.bounds.x_bounds();
-
-            xs.map(move |x| {
-                let point = Point { x, y };
-                let cell = self[point];
-                (point, cell)
-            })
-        })
-    }
-}
-
-impl Index<Point> for Cells {
-    type Output = Cell;
-
-    fn index(&self, point: Point) -> &Cell {
-        let bounds = self.bounds;
-        self.get(point)
-            .with_context(|| format!("point {point} was out of bounds {bounds:?}"))
-            .unwrap()
-    }
-}
-
-impl IndexMut<Point> for Cells {
-    fn index_mut(&mut self, point: Point) -> &mut Cell {
-        let bounds = self.bounds;
-        self.get_mut(point)
-            .with_context(|| format!("point {point} was out of bounds {bounds:?}"))
-            .unwrap()
-    }
+    Ok(())
 }
@@ -1,12 +1,44 @@
 use std::{
     fmt::Display,
-    ops::{Add, AddAssign, RangeInclusive, Sub},
+    ops::{Add, AddAssign, ControlFlow, Index, IndexMut, RangeInclusive, Sub},
     str::FromStr,
 };
 
+use common::{
+    answer::Answer,
+    diagnostics::parse_lines,
+    render::Renderable,
+    simulation::{self, Simulation},
+};
+use eyre::ContextCompat;
 use joinery::JoinableIterator;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let resting_sand = pour_sand(input, Floor::Abyss)?;
+    Ok(resting_sand.into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let resting_sand = pour_sand(input, Floor::Rock)?;
+    Ok(resting_sand.into())
+}
+
+pub fn parse_paths(input: &str) -> eyre::Result<Vec<Path>> {
+    Ok(parse_lines(input)?)
+}
+
+fn pour_sand(input: &str, floor: Floor) -> eyre::Result<usize> {
+    let paths = parse_paths(input)?;
+    let mut world = World::new(STARTING_POINT, &paths, floor);
+    simulation::run(&mut world);
+
+    Ok(world.resting_sand())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct Point {
     pub x: i32,
     pub y: i32,
@@ -221,3 +253,259 @@ impl Bounds {
         Point { x, y }
     }
 }
+
+pub const STARTING_POINT: Point = Point { x: 500, y: 0 };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Floor {
+    Abyss,
+    Rock,
+}
+
+pub struct World {
+    cells: Cells,
+    source: Point,
+    floor: Floor,
+    current_sand_point: Option<Point>,
+}
+
+impl World {
+    pub fn new(source: Point, paths: &[Path], floor: Floor) -> Self {
+        let mut bounds = Bounds::new(source);
+
+        for path in paths {
+            for &point in &path.points {
+                bounds.add(point);
+            }
+        }
+
+        let floor_x_bounds = match floor {
+            Floor::Abyss => None,
+            Floor::Rock => {
+                let floor_margin = std::cmp::max(bounds.width(), bounds.height());
+                let floor_y = bounds.bottom_left().y + 2;
+                let floor_x_min = bounds.bottom_left().x - floor_margin;
+                let floor_x_max = bounds.bottom_right().x + floor_margin;
+
+                bounds.add(Point {
+                    x: floor_x_min - 1,
+                    y: floor_y + 1,
+                });
+                bounds.add(Point {
+                    x: floor_x_max + 1,
+                    y: floor_y + 1,
+                });
+
+                Some((floor_y, floor_x_min..=floor_x_max))
+            }
+        };
+
+        let mut cells = Cells::new(Cell::Air, bounds);
+
+        for path in paths {
+            for line in path.lines() {
+                for point in line.points() {
+                    cells[point] = Cell::Rock;
+                }
+            }
+        }
+
+        if let Some((floor_y, floor_xs)) = floor_x_bounds {
+            for x in floor_xs {
+                let point = Point { x, y: floor_y };
+                cells[point] = Cell::Rock;
+            }
+        }
+
+        Self {
+            cells,
+            source,
+            floor,
+            current_sand_point: None,
+        }
+    }
+
+    pub fn resting_sand(&self) -> usize {
+        self.settled_sand().count()
+    }
+
+    pub fn settled_sand(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells
+            .iter()
+            .filter(|&(_, cell)| cell == Cell::SettledSand)
+            .map(|(point, _)| point)
+    }
+}
+
+impl Simulation for World {
+    type Event = ();
+
+    fn step(&mut self) -> ControlFlow<()> {
+        match self.current_sand_point {
+            Some(current_sand_point) => {
+                let mut new_point: Option<Point> = None;
+                let mut out_of_bounds = false;
+
+                for falling_vector in FALLING_SAND_VECTORS {
+                    let candidate_point = current_sand_point + falling_vector;
+                    match self.cells.get(candidate_point) {
+                        Some(Cell::Air) => {
+                            new_point = Some(candidate_point);
+                            break;
+                        }
+                        Some(Cell::Rock | Cell::FallingSand | Cell::SettledSand) => {}
+                        None if self.floor == Floor::Abyss => {
+                            // Next position doesn't exist, so sand flowed out of bounds.
+                            return ControlFlow::Break(());
+                        }
+                        None => {
+                            out_of_bounds = true;
+                        }
+                    }
+                }
+
+                match new_point {
+                    Some(new_point) => {
+                        self.cells[new_point] = Cell::FallingSand;
+                        self.cells[current_sand_point] = Cell::Air;
+                        self.current_sand_point = Some(new_point);
+                    }
+                    None => {
+                        assert!(!out_of_bounds, "sand fell out of bounds");
+                        self.cells[current_sand_point] = Cell::SettledSand;
+
+                        if current_sand_point == self.source {
+                            // Sand reached the source
+                            return ControlFlow::Break(());
+                        }
+
+                        self.current_sand_point = None;
+                    }
+                }
+            }
+            None => {
+                self.cells[self.source] = Cell::FallingSand;
+                self.current_sand_point = Some(self.source);
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl Renderable for World {
+    fn x_range(&self) -> RangeInclusive<i64> {
+        let xs = self.cells.bounds.x_bounds();
+        (*xs.start()).into()..=(*xs.end()).into()
+    }
+
+    fn y_range(&self) -> RangeInclusive<i64> {
+        let ys = self.cells.bounds.y_bounds();
+        (*ys.start()).into()..=(*ys.end()).into()
+    }
+
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        let point = Point {
+            x: x.try_into().unwrap(),
+            y: y.try_into().unwrap(),
+        };
+
+        if point == self.source {
+            '+'
+        } else {
+            match self.cells[point] {
+                Cell::Air => '.',
+                Cell::Rock => '#',
+                Cell::FallingSand => '~',
+                Cell::SettledSand => 'o',
+            }
+        }
+    }
+}
+
+const FALLING_SAND_VECTORS: [Vector; 3] = [
+    Vector { x: 0, y: 1 },
+    Vector { x: -1, y: 1 },
+    Vector { x: 1, y: 1 },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Air,
+    Rock,
+    FallingSand,
+    SettledSand,
+}
+
+struct Cells {
+    bounds: Bounds,
+    cells: Vec<Cell>,
+}
+
+impl Cells {
+    fn new(cell: Cell, bounds: Bounds) -> Self {
+        let num_cells = bounds.width() * bounds.height();
+        let num_cells = num_cells.try_into().unwrap();
+        let cells = vec![cell; num_cells];
+
+        Self { bounds, cells }
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        let row = point.x - self.bounds.min.x;
+        let col = point.y - self.bounds.min.y;
+
+        let offset = (col * self.bounds.width()) + row;
+        let offset = offset.try_into().unwrap();
+
+        Some(offset)
+    }
+
+    fn get(&self, point: Point) -> Option<&Cell> {
+        let offset = self.offset(point)?;
+        Some(&self.cells[offset])
+    }
+
+    fn get_mut(&mut self, point: Point) -> Option<&mut Cell> {
+        let offset = self.offset(point)?;
+        Some(&mut self.cells[offset])
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (Point, Cell)> + '_ {
+        let ys = self.bounds.y_bounds();
+
+        ys.flat_map(move |y| {
+            let xs = self.bounds.x_bounds();
+
+            xs.map(move |x| {
+                let point = Point { x, y };
+                let cell = self[point];
+                (point, cell)
+            })
+        })
+    }
+}
+
+impl Index<Point> for Cells {
+    type Output = Cell;
+
+    fn index(&self, point: Point) -> &Cell {
+        let bounds = self.bounds;
+        self.get(point)
+            .with_context(|| format!("point {point} was out of bounds {bounds:?}"))
+            .unwrap()
+    }
+}
+
+impl IndexMut<Point> for Cells {
+    fn index_mut(&mut self, point: Point) -> &mut Cell {
+        let bounds = self.bounds;
+        self.get_mut(point)
+            .with_context(|| format!("point {point} was out of bounds {bounds:?}"))
+            .unwrap()
+    }
+}
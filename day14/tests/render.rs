@@ -0,0 +1,22 @@
+use common::{render::Renderable, simulation};
+use day14::{Floor, World, STARTING_POINT};
+
+fn settled_world(floor: Floor) -> World {
+    let paths = day14::parse_paths(day14::EXAMPLE).unwrap();
+    let mut world = World::new(STARTING_POINT, &paths, floor);
+    simulation::run(&mut world);
+
+    world
+}
+
+#[test]
+fn abyss_example() {
+    let world = settled_world(Floor::Abyss);
+    insta::assert_snapshot!(world.render());
+}
+
+#[test]
+fn rock_floor_example() {
+    let world = settled_world(Floor::Rock);
+    insta::assert_snapshot!(world.render());
+}
@@ -1,74 +1,289 @@
-use std::ops::RangeInclusive;
+use std::{collections::HashSet, ops::RangeInclusive, str::FromStr};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
+pub use common::geometry::{Bounds, Point};
+use common::{
+    answer::Answer, diagnostics::parse_lines, parallel::prelude::*, ranges::RangeSet,
+    render::Renderable,
+};
+use itertools::Itertools;
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let sensor_reports = parse_sensor_reports(input)?;
+    let num_beaconless_points = count_beaconless_points(&sensor_reports, 2_000_000);
+    Ok(num_beaconless_points.into())
 }
 
-impl Point {
-    pub fn manhattan_distance(&self, other: &Point) -> i32 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
-    }
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let sensor_reports = parse_sensor_reports(input)?;
+    let point = find_distress_beacon(&sensor_reports, 4_000_000)?;
+    Ok(tuning_frequency(point).into())
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Bounds {
-    pub min: Point,
-    pub max: Point,
+pub fn parse_sensor_reports(input: &str) -> eyre::Result<Vec<SensorReport>> {
+    Ok(parse_lines(input)?)
 }
 
-impl Bounds {
-    pub fn new(point: Point) -> Self {
-        Self {
-            min: point,
-            max: point,
-        }
-    }
+pub fn count_beaconless_points(sensor_reports: &[SensorReport], search_row: i32) -> usize {
+    let covered: RangeSet<i64> = sensor_reports
+        .iter()
+        .filter_map(|report| report.covered_row(search_row))
+        .collect();
+
+    let beacons_in_row: HashSet<_> = sensor_reports
+        .iter()
+        .map(|report| report.closest_beacon)
+        .filter(|beacon| beacon.y == search_row && covered.contains(beacon.x.into()))
+        .collect();
+
+    let num_beaconless_points = covered.len() - beacons_in_row.len() as u64;
+    num_beaconless_points.try_into().unwrap()
+}
+
+// Check every point in the row, for comparing against `count_beaconless_points`
+pub fn count_beaconless_points_naive(sensor_reports: &[SensorReport], search_row: i32) -> usize {
+    let initial_bounds: Option<Bounds> = None;
+    let bounds = sensor_reports
+        .iter()
+        .fold(initial_bounds, |bounds, report| match bounds {
+            Some(mut bounds) => {
+                bounds.union(&report.covered_bounds());
+                Some(bounds)
+            }
+            None => Some(report.covered_bounds()),
+        });
+
+    let bounds = bounds.unwrap_or_else(|| Bounds::new(Point { x: 0, y: 0 }));
+
+    bounds
+        .points_row(search_row)
+        .filter(|&point| is_beaconless(sensor_reports, point))
+        .count()
+}
 
-    pub fn add(&mut self, point: Point) {
-        self.min.x = std::cmp::min(self.min.x, point.x);
-        self.min.y = std::cmp::min(self.min.y, point.y);
-        self.max.x = std::cmp::max(self.max.x, point.x);
-        self.max.y = std::cmp::max(self.max.y, point.y);
+pub fn find_distress_beacon(
+    sensor_reports: &[SensorReport],
+    max_bounds: i32,
+) -> eyre::Result<Point> {
+    let bounds = Bounds {
+        min: Point { x: 0, y: 0 },
+        max: Point {
+            x: max_bounds,
+            y: max_bounds,
+        },
+    };
+
+    let report_pairs: Vec<_> = sensor_reports
+        .iter()
+        .permutations(2)
+        .map(|pair| -> [_; 2] { pair.try_into().unwrap() })
+        .collect();
+    let candidate_points = report_pairs.into_maybe_par_iter().flat_map(|[a, b]| {
+        let a_edge: HashSet<_> = a
+            .outer_edge_points()
+            .filter(|&point| bounds.contains(point))
+            .collect();
+        let b_edge: HashSet<_> = b
+            .outer_edge_points()
+            .filter(|&point| bounds.contains(point))
+            .collect();
+
+        a_edge.intersection(&b_edge).cloned().collect::<Vec<_>>()
+    });
+
+    candidate_points
+        .find_any(|&point| {
+            sensor_reports
+                .iter()
+                .all(|report| !report.covers_point(point) && report.closest_beacon != point)
+        })
+        .ok_or_else(|| eyre::eyre!("point not found"))
+}
+
+#[derive(Debug)]
+pub struct SensorReport {
+    pub sensor: Point,
+    pub closest_beacon: Point,
+}
+
+impl SensorReport {
+    pub fn covers_point(&self, point: Point) -> bool {
+        let sensor_radius = self.sensor.manhattan_distance(&self.closest_beacon);
+        let distance = self.sensor.manhattan_distance(&point);
+
+        sensor_radius >= distance
     }
 
-    pub fn union(&mut self, bounds: &Bounds) {
-        self.min.x = std::cmp::min(self.min.x, bounds.min.x);
-        self.max.x = std::cmp::max(self.max.x, bounds.max.x);
-        self.min.y = std::cmp::min(self.min.y, bounds.min.y);
-        self.max.y = std::cmp::max(self.max.y, bounds.max.y);
+    pub fn covered_row(&self, row: i32) -> Option<RangeInclusive<i64>> {
+        let sensor_radius = self.sensor.manhattan_distance(&self.closest_beacon);
+        let row_radius = sensor_radius - (self.sensor.y - row).abs();
+        if row_radius < 0 {
+            return None;
+        }
+
+        let x: i64 = self.sensor.x.into();
+        let row_radius: i64 = row_radius.into();
+        Some((x - row_radius)..=(x + row_radius))
     }
 
-    pub fn x_bounds(&self) -> RangeInclusive<i32> {
-        self.min.x..=self.max.x
+    pub fn outer_edge_points(&self) -> impl Iterator<Item = Point> {
+        let sensor_radius = self.sensor.manhattan_distance(&self.closest_beacon);
+        let top = Point {
+            x: self.sensor.x,
+            y: self.sensor.y + sensor_radius + 1,
+        };
+        let right = Point {
+            x: self.sensor.x + sensor_radius + 1,
+            y: self.sensor.y,
+        };
+        let bottom = Point {
+            x: self.sensor.x,
+            y: self.sensor.y - sensor_radius - 1,
+        };
+        let left = Point {
+            x: self.sensor.x - sensor_radius - 1,
+            y: self.sensor.y,
+        };
+
+        walk_points(top, right, (1, -1))
+            .chain(walk_points(right, bottom, (-1, -1)))
+            .chain(walk_points(bottom, left, (-1, 1)))
+            .chain(walk_points(left, top, (1, 1)))
     }
 
-    pub fn y_bounds(&self) -> RangeInclusive<i32> {
-        self.min.y..=self.max.y
+    pub fn covered_bounds(&self) -> Bounds {
+        let sensor_radius = self.sensor.manhattan_distance(&self.closest_beacon);
+        let min_x = self.sensor.x - sensor_radius;
+        let max_x = self.sensor.x + sensor_radius;
+        let min_y = self.sensor.y - sensor_radius;
+        let max_y = self.sensor.y + sensor_radius;
+
+        Bounds {
+            min: Point { x: min_x, y: min_y },
+            max: Point { x: max_x, y: max_y },
+        }
     }
+}
+
+impl FromStr for SensorReport {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let caps = SENSOR_REPORT_REGEX
+            .captures(s)
+            .ok_or_else(|| eyre::eyre!("invalid report: {}", s))?;
+
+        let sensor_x = caps["sensor_x"].parse()?;
+        let sensor_y = caps["sensor_y"].parse()?;
+        let beacon_x = caps["beacon_x"].parse()?;
+        let beacon_y = caps["beacon_y"].parse()?;
 
-    pub fn contains(&self, point: Point) -> bool {
-        self.x_bounds().contains(&point.x) && self.y_bounds().contains(&point.y)
+        let sensor = Point {
+            x: sensor_x,
+            y: sensor_y,
+        };
+        let closest_beacon = Point {
+            x: beacon_x,
+            y: beacon_y,
+        };
+
+        Ok(Self {
+            sensor,
+            closest_beacon,
+        })
     }
+}
+
+lazy_static::lazy_static! {
+    static ref SENSOR_REPORT_REGEX: regex::Regex = regex::Regex::new(
+        r"^Sensor at x=(?P<sensor_x>-?\d+), y=(?P<sensor_y>-?\d+): closest beacon is at x=(?P<beacon_x>-?\d+), y=(?P<beacon_y>-?\d+)$",
+    ).unwrap();
+}
+
+fn walk_points(start: Point, end: Point, walk: (i32, i32)) -> impl Iterator<Item = Point> {
+    let mut current = start;
+    let (walk_x, walk_y) = walk;
 
-    pub fn width(&self) -> i32 {
-        (self.max.x - self.min.x) + 1
+    let mut is_running = true;
+    std::iter::from_fn(move || {
+        if !is_running {
+            None
+        } else if current == end {
+            is_running = false;
+            Some(current)
+        } else {
+            let last = current;
+            current = Point {
+                x: current.x + walk_x,
+                y: current.y + walk_y,
+            };
+            Some(last)
+        }
+    })
+}
+
+pub fn tuning_frequency(point: Point) -> i64 {
+    let x: i64 = point.x.into();
+    let y: i64 = point.y.into();
+    (x * 4_000_000) + y
+}
+
+pub struct CoverageMap<'a> {
+    pub sensor_reports: &'a [SensorReport],
+    pub bounds: Bounds,
+}
+
+impl Renderable for CoverageMap<'_> {
+    fn x_range(&self) -> RangeInclusive<i64> {
+        self.bounds.min.x.into()..=self.bounds.max.x.into()
     }
 
-    pub fn height(&self) -> i32 {
-        (self.max.y - self.min.y) + 1
+    fn y_range(&self) -> RangeInclusive<i64> {
+        self.bounds.min.y.into()..=self.bounds.max.y.into()
     }
 
-    pub fn points(&self) -> impl Iterator<Item = Point> {
-        let min_x = self.min.x;
-        let max_x = self.max.x;
-        let min_y = self.min.y;
-        let max_y = self.max.y;
-        (min_x..=max_x).flat_map(move |x| (min_y..=max_y).map(move |y| Point { x, y }))
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        let point = Point {
+            x: x.try_into().unwrap(),
+            y: y.try_into().unwrap(),
+        };
+
+        if self
+            .sensor_reports
+            .iter()
+            .any(|report| report.sensor == point)
+        {
+            'S'
+        } else if self
+            .sensor_reports
+            .iter()
+            .any(|report| report.closest_beacon == point)
+        {
+            'B'
+        } else if self
+            .sensor_reports
+            .iter()
+            .any(|report| report.covers_point(point))
+        {
+            '#'
+        } else {
+            '.'
+        }
     }
+}
 
-    pub fn points_row(&self, row: i32) -> impl Iterator<Item = Point> {
-        self.x_bounds().map(move |x| Point { x, y: row })
+pub fn is_beaconless<'a>(
+    sensor_reports: impl IntoIterator<Item = &'a SensorReport>,
+    point: Point,
+) -> bool {
+    for report in sensor_reports {
+        if report.closest_beacon == point {
+            return false;
+        } else if report.covers_point(point) {
+            return true;
+        }
     }
+
+    false
 }
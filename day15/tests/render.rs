@@ -0,0 +1,19 @@
+use common::render::Renderable;
+use day15::{Bounds, CoverageMap, Point};
+
+#[test]
+fn coverage_example() {
+    let sensor_reports = day15::parse_sensor_reports(day15::EXAMPLE).unwrap();
+
+    let mut bounds = Bounds::new(Point { x: 0, y: 0 });
+    for report in &sensor_reports {
+        bounds.add(report.sensor);
+        bounds.add(report.closest_beacon);
+    }
+
+    let map = CoverageMap {
+        sensor_reports: &sensor_reports,
+        bounds,
+    };
+    insta::assert_snapshot!(map.render());
+}
@@ -0,0 +1,178 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{answer::Answer, diagnostics::parse_lines, parallel::prelude::*};
+use petgraph::{prelude::DiGraph, stable_graph::NodeIndex};
+use regex::Regex;
+use serde::Serialize;
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let tunnel_scans = parse_tunnel_scans(input)?;
+    let tunnels = Tunnels::from_scans(&tunnel_scans);
+
+    let best_path = find_best_path(&tunnels, "AA", 30);
+    Ok(best_path.score(30).into())
+}
+
+pub fn solve_part2(_input: &str) -> eyre::Result<Answer> {
+    eyre::bail!("part 2 is not implemented");
+}
+
+pub fn parse_tunnel_scans(input: &str) -> eyre::Result<Vec<TunnelScan>> {
+    Ok(parse_lines(input)?)
+}
+
+pub struct TunnelScan {
+    pub valve: String,
+    pub flow_rate: u64,
+    pub paths: Vec<String>,
+}
+
+impl FromStr for TunnelScan {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> eyre::Result<Self> {
+        lazy_static::lazy_static! {
+            // Regex with match groups named value and paths
+            static ref TUNNEL_SCAN_REGEX: Regex = Regex::new(r#"^Valve (?P<valve>[A-Z]+) has flow rate=(?P<flow_rate>\d+); (tunnel leads to valve|tunnels lead to valves) (?P<paths>[A-Z, ]+)$"#).unwrap();
+        }
+
+        let captures = TUNNEL_SCAN_REGEX
+            .captures(s)
+            .ok_or_else(|| eyre::eyre!("invalid tunnel scan: {s:?}"))?;
+        let valve = captures.name("valve").unwrap().as_str().to_string();
+        let flow_rate = captures.name("flow_rate").unwrap().as_str().parse()?;
+        let paths = captures
+            .name("paths")
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .map(|s| s.to_string())
+            .collect();
+
+        Ok(Self {
+            valve,
+            flow_rate,
+            paths,
+        })
+    }
+}
+
+pub struct Tunnels {
+    room_nodes: HashMap<String, NodeIndex>,
+    room_graph: DiGraph<Room, ()>,
+}
+
+impl Tunnels {
+    pub fn from_scans(scans: &[TunnelScan]) -> Self {
+        let mut room_nodes: HashMap<String, NodeIndex> = HashMap::new();
+        let mut room_graph = DiGraph::new();
+        for scan in scans {
+            let node = room_graph.add_node(Room {
+                valve: scan.valve.clone(),
+                flow_rate: scan.flow_rate,
+            });
+            room_nodes.insert(scan.valve.clone(), node);
+        }
+
+        for scan in scans {
+            let node = room_nodes.get(&scan.valve).unwrap();
+            for path in &scan.paths {
+                let path_node = room_nodes.get(path).unwrap();
+                room_graph.add_edge(*node, *path_node, ());
+            }
+        }
+
+        Self {
+            room_nodes,
+            room_graph,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct Room {
+    pub valve: String,
+    pub flow_rate: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum Step<'a> {
+    Open { room: &'a Room },
+    Go { room: &'a Room },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Path<'a> {
+    pub steps: Vec<Step<'a>>,
+}
+
+impl<'a> Path<'a> {
+    pub fn empty() -> Self {
+        Path { steps: vec![] }
+    }
+
+    pub fn add(&mut self, step: Step<'a>) {
+        self.steps.push(step);
+    }
+
+    pub fn score(&self, mut time: u64) -> u64 {
+        let mut score = 0;
+        let mut open_valves: HashSet<&Room> = HashSet::new();
+        let mut steps = self.steps.iter();
+        while time > 0 {
+            if let Some(step) = steps.next() {
+                match step {
+                    Step::Open { room } => {
+                        open_valves.insert(room);
+                    }
+                    Step::Go { .. } => {}
+                }
+            }
+
+            let current_flow_rate: u64 = open_valves.iter().map(|room| room.flow_rate).sum();
+            score += current_flow_rate;
+            time -= 1;
+        }
+
+        score
+    }
+}
+
+pub fn find_best_path<'a>(tunnels: &'a Tunnels, starting_room: &str, time: u64) -> Path<'a> {
+    let node = tunnels.room_nodes.get(starting_room).unwrap();
+    let room = &tunnels.room_graph[*node];
+
+    if time == 0 {
+        return Path::empty();
+    }
+
+    let candidate_steps = tunnels
+        .room_graph
+        .neighbors(*node)
+        .map(|node| Step::Go {
+            room: &tunnels.room_graph[node],
+        })
+        .chain(std::iter::once(Step::Open { room }))
+        .collect::<Vec<_>>();
+
+    let best_path = candidate_steps
+        .into_maybe_par_iter()
+        .map(|step| {
+            let room = match step {
+                Step::Open { room } => room,
+                Step::Go { room } => room,
+            };
+            let mut path = find_best_path(tunnels, &room.valve, time - 1);
+            path.add(step);
+            path
+        })
+        .max_by_key(|path| path.score(time))
+        .unwrap_or_else(Path::empty);
+
+    best_path
+}
@@ -0,0 +1,39 @@
+use std::ops::ControlFlow;
+
+use clap::Parser;
+use cli::{DisplayArgs, InputArgs};
+use day17::Chamber;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    display: DisplayArgs,
+    #[clap(short, long, default_value_t = 2022)]
+    rocks: usize,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day17::EXAMPLE)?;
+    let jets = day17::parse_jets(&input)?;
+
+    let mut chamber = Chamber::new(jets);
+
+    let animation = args.display.animation(50);
+    animation.run(&mut chamber, |dropped, ()| {
+        if dropped >= args.rocks {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    println!("Tower height: {}", chamber.height());
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use clap::Parser;
+use cli::InputArgs;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long, default_value_t = 1_000_000_000_000)]
+    rocks: usize,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day17::EXAMPLE)?;
+    let jets = day17::parse_jets(&input)?;
+
+    let (cycle, height) = day17::extrapolate_tower_height(jets, args.rocks)?;
+
+    println!(
+        "Tower repeats every {} rocks after the first {}",
+        cycle.length, cycle.start
+    );
+    println!("Tower height: {height}");
+
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use std::ops::{ControlFlow, RangeInclusive};
+
+use common::{
+    answer::Answer,
+    cycles::{self, Cycle},
+    diagnostics::ParseError,
+    render::Renderable,
+    simulation::{self, Simulation},
+};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub const CHAMBER_WIDTH: usize = 7;
+
+// Each rock's rows from the bottom up, already shifted to start two units
+// from the left wall. Bit 6 is the leftmost column of the chamber.
+const ROCKS: [[u8; 4]; 5] = [
+    [0b0011110, 0, 0, 0],
+    [0b0001000, 0b0011100, 0b0001000, 0],
+    [0b0011100, 0b0000100, 0b0000100, 0],
+    [0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    [0b0011000, 0b0011000, 0, 0],
+];
+
+const LEFT_WALL: u8 = 1 << (CHAMBER_WIDTH - 1);
+const RIGHT_WALL: u8 = 1;
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let jets = parse_jets(input)?;
+    let height = tower_height(jets, 2022);
+    Ok(height.into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let jets = parse_jets(input)?;
+    let (_, height) = extrapolate_tower_height(jets, 1_000_000_000_000)?;
+    Ok(height.into())
+}
+
+pub fn parse_jets(input: &str) -> Result<Vec<Jet>, ParseError> {
+    let jets = input
+        .trim_end()
+        .char_indices()
+        .map(|(index, c)| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            c => Err(ParseError::new(
+                input,
+                index..index + c.len_utf8(),
+                format_args!("invalid jet {c:?}"),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if jets.is_empty() {
+        return Err(ParseError::new(input, 0..0, "no jets"));
+    }
+
+    Ok(jets)
+}
+
+/// The height of the tower after `rocks` rocks have come to rest.
+pub fn tower_height(jets: Vec<Jet>, rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets);
+    simulation::run_with(&mut chamber, |dropped, ()| {
+        if dropped >= rocks {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    chamber.height()
+}
+
+/// Like [`tower_height`], but only drops rocks until the tower starts
+/// repeating, then extrapolates the height from there. Also returns the
+/// cycle that was found.
+pub fn extrapolate_tower_height(jets: Vec<Jet>, rocks: usize) -> eyre::Result<(Cycle, i64)> {
+    // Every combination of rock and jet should come up well within this
+    let max_rocks = 10 * jets.len() * ROCKS.len();
+
+    let mut chamber = Chamber::new(jets);
+    let mut heights = vec![0];
+    let cycle = cycles::find_cycle(
+        &mut chamber,
+        |chamber| {
+            chamber.drop_rock();
+            heights.push(chamber.height() as i64);
+        },
+        Chamber::state,
+        max_rocks,
+    )
+    .ok_or_else(|| eyre::eyre!("tower did not repeat within {max_rocks} rocks"))?;
+
+    let height = cycle
+        .extrapolate(&heights, rocks)
+        .ok_or_else(|| eyre::eyre!("tower height out of range after {rocks} rocks"))?;
+    Ok((cycle, height))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+pub struct Chamber {
+    jets: Vec<Jet>,
+    // Settled rock in each row from the floor up, using the same bits as
+    // `ROCKS`. The top row is never empty.
+    rows: Vec<u8>,
+    next_rock: usize,
+    next_jet: usize,
+}
+
+impl Chamber {
+    pub fn new(jets: Vec<Jet>) -> Self {
+        assert!(!jets.is_empty(), "chamber needs at least one jet");
+
+        Self {
+            jets,
+            rows: vec![],
+            next_rock: 0,
+            next_jet: 0,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    // Drop the next rock until it comes to rest
+    pub fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.next_rock];
+        self.next_rock = (self.next_rock + 1) % ROCKS.len();
+
+        let mut bottom = self.height() + 3;
+        loop {
+            let jet = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+
+            let pushed = match jet {
+                Jet::Left if rock.iter().all(|row| row & LEFT_WALL == 0) => {
+                    Some(rock.map(|row| row << 1))
+                }
+                Jet::Right if rock.iter().all(|row| row & RIGHT_WALL == 0) => {
+                    Some(rock.map(|row| row >> 1))
+                }
+                _ => None,
+            };
+            if let Some(pushed) = pushed {
+                if !self.collides(&pushed, bottom) {
+                    rock = pushed;
+                }
+            }
+
+            if bottom == 0 || self.collides(&rock, bottom - 1) {
+                break;
+            }
+
+            bottom -= 1;
+        }
+
+        for (offset, &row) in rock.iter().enumerate() {
+            if row == 0 {
+                continue;
+            }
+
+            let y = bottom + offset;
+            if y >= self.rows.len() {
+                self.rows.resize(y + 1, 0);
+            }
+            self.rows[y] |= row;
+        }
+    }
+
+    // Everything that decides where future rocks will land: the next rock
+    // and jet, plus how far below the top of the tower each column is filled
+    pub fn state(&self) -> ChamberState {
+        let mut surface = [self.height(); CHAMBER_WIDTH];
+        for (column, depth) in surface.iter_mut().enumerate() {
+            let bit = 1 << (CHAMBER_WIDTH - 1 - column);
+            if let Some(filled) = self.rows.iter().rev().position(|row| row & bit != 0) {
+                *depth = filled;
+            }
+        }
+
+        ChamberState {
+            next_rock: self.next_rock,
+            next_jet: self.next_jet,
+            surface,
+        }
+    }
+
+    fn collides(&self, rock: &[u8; 4], bottom: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(offset, &row)| match self.rows.get(bottom + offset) {
+                Some(settled) => settled & row != 0,
+                None => false,
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChamberState {
+    pub next_rock: usize,
+    pub next_jet: usize,
+    pub surface: [usize; CHAMBER_WIDTH],
+}
+
+impl Simulation for Chamber {
+    type Event = ();
+
+    // Each step drops one rock. The rocks never stop falling.
+    fn step(&mut self) -> ControlFlow<(), ()> {
+        self.drop_rock();
+        ControlFlow::Continue(())
+    }
+}
+
+// Only the top of the tower is drawn, since the rest can get very tall
+const RENDER_ROWS: usize = 40;
+
+impl Renderable for Chamber {
+    const Y_UP: bool = true;
+
+    fn x_range(&self) -> RangeInclusive<i64> {
+        0..=(CHAMBER_WIDTH as i64 - 1)
+    }
+
+    fn y_range(&self) -> RangeInclusive<i64> {
+        let height = self.height() as i64;
+        (height - RENDER_ROWS as i64).max(0)..=(height - 1)
+    }
+
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        let bit = 1 << (CHAMBER_WIDTH as i64 - 1 - x);
+        if self.rows[y as usize] & bit != 0 {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
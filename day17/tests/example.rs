@@ -0,0 +1,33 @@
+use common::answer::Answer;
+
+#[test]
+fn example_tower_height() {
+    let jets = day17::parse_jets(day17::EXAMPLE).unwrap();
+    assert_eq!(day17::tower_height(jets, 2022), 3068);
+}
+
+#[test]
+fn example_extrapolated_tower_height() {
+    let jets = day17::parse_jets(day17::EXAMPLE).unwrap();
+    let (_, height) = day17::extrapolate_tower_height(jets, 1_000_000_000_000).unwrap();
+    assert_eq!(height, 1_514_285_714_288);
+}
+
+#[test]
+fn extrapolation_matches_simulation() {
+    let jets = day17::parse_jets(day17::EXAMPLE).unwrap();
+    let (_, height) = day17::extrapolate_tower_height(jets, 2022).unwrap();
+    assert_eq!(height, 3068);
+}
+
+#[test]
+fn example_answers() {
+    assert_eq!(
+        day17::solve_part1(day17::EXAMPLE).unwrap(),
+        Answer::from(3068_usize)
+    );
+    assert_eq!(
+        day17::solve_part2(day17::EXAMPLE).unwrap(),
+        Answer::from(1_514_285_714_288_i64)
+    );
+}
@@ -0,0 +1,24 @@
+use clap::Parser;
+use cli::InputArgs;
+use day18::Droplet;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day18::EXAMPLE)?;
+    let droplet = Droplet::parse(&input)?;
+
+    println!("Cubes: {}", droplet.cubes().len());
+    println!("Surface area: {}", droplet.surface_area());
+    println!("Exterior surface area: {}", droplet.exterior_surface_area());
+
+    Ok(())
+}
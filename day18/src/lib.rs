@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use common::{
+    answer::Answer,
+    diagnostics::parse_lines,
+    geometry::{Bounds3, Point3},
+    pathfinding::flood_fill,
+};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let droplet = Droplet::parse(input)?;
+    Ok(droplet.surface_area().into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let droplet = Droplet::parse(input)?;
+    Ok(droplet.exterior_surface_area().into())
+}
+
+#[derive(Debug, Clone)]
+pub struct Droplet {
+    cubes: HashSet<Point3>,
+}
+
+impl Droplet {
+    pub fn new(cubes: impl IntoIterator<Item = Point3>) -> Self {
+        Self {
+            cubes: cubes.into_iter().collect(),
+        }
+    }
+
+    pub fn parse(input: &str) -> eyre::Result<Self> {
+        let cubes: Vec<Point3> = parse_lines(input)?;
+        Ok(Self::new(cubes))
+    }
+
+    pub fn cubes(&self) -> &HashSet<Point3> {
+        &self.cubes
+    }
+
+    // Every face not touching another cube, including faces of air pockets
+    pub fn surface_area(&self) -> usize {
+        self.cubes
+            .iter()
+            .flat_map(|cube| cube.face_neighbors())
+            .filter(|neighbor| !self.cubes.contains(neighbor))
+            .count()
+    }
+
+    // Only the faces reachable by steam from outside the droplet
+    pub fn exterior_surface_area(&self) -> usize {
+        let Some(bounds) = Bounds3::from_points(self.cubes.iter().copied()) else {
+            return 0;
+        };
+
+        // Leave a layer of air around the droplet so the steam can flow all
+        // the way around it
+        let bounds = bounds.expand(1);
+        let steam = flood_fill(bounds.min, |&point: &Point3| {
+            point
+                .face_neighbors()
+                .filter(|neighbor| bounds.contains(*neighbor) && !self.cubes.contains(neighbor))
+                .collect::<Vec<_>>()
+        });
+
+        self.cubes
+            .iter()
+            .flat_map(|cube| cube.face_neighbors())
+            .filter(|neighbor| steam.contains(neighbor))
+            .count()
+    }
+}
@@ -0,0 +1,34 @@
+use common::geometry::Point3;
+use day18::Droplet;
+
+#[test]
+fn two_adjacent_cubes() {
+    let droplet = Droplet::new([Point3 { x: 1, y: 1, z: 1 }, Point3 { x: 2, y: 1, z: 1 }]);
+    assert_eq!(droplet.surface_area(), 10);
+}
+
+#[test]
+fn example_surface_area() {
+    let droplet = Droplet::parse(day18::EXAMPLE).unwrap();
+    assert_eq!(droplet.surface_area(), 64);
+}
+
+#[test]
+fn example_exterior_surface_area() {
+    let droplet = Droplet::parse(day18::EXAMPLE).unwrap();
+    assert_eq!(droplet.exterior_surface_area(), 58);
+}
+
+#[test]
+fn air_pocket_is_not_exterior() {
+    let center = Point3 { x: 1, y: 1, z: 1 };
+    let droplet = Droplet::new(center.face_neighbors());
+    assert_eq!(droplet.surface_area(), 36);
+    assert_eq!(droplet.exterior_surface_area(), 30);
+}
+
+#[test]
+fn invalid_cube() {
+    let error = Droplet::parse("1,2,3\n4,5\n").unwrap_err();
+    assert!(error.to_string().contains("line 2"), "{error}");
+}
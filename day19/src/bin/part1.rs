@@ -0,0 +1,35 @@
+use clap::Parser;
+use cli::InputArgs;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long, default_value_t = 24)]
+    minutes: u32,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day19::EXAMPLE)?;
+    let blueprints = day19::parse_blueprints(&input)?;
+
+    let mut total_quality_level = 0;
+    for blueprint in &blueprints {
+        let geodes = day19::max_geodes(blueprint, args.minutes);
+        let quality_level = blueprint.id * geodes;
+        println!(
+            "Blueprint {}: {geodes} geodes, quality level {quality_level}",
+            blueprint.id
+        );
+
+        total_quality_level += quality_level;
+    }
+
+    println!("Total quality level: {total_quality_level}");
+
+    Ok(())
+}
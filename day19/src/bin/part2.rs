@@ -0,0 +1,33 @@
+use clap::Parser;
+use cli::InputArgs;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long, default_value_t = 32)]
+    minutes: u32,
+    #[clap(short, long, default_value_t = 3)]
+    blueprints: usize,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day19::EXAMPLE)?;
+    let blueprints = day19::parse_blueprints(&input)?;
+
+    let mut product = 1;
+    for blueprint in blueprints.iter().take(args.blueprints) {
+        let geodes = day19::max_geodes(blueprint, args.minutes);
+        println!("Blueprint {}: {geodes} geodes", blueprint.id);
+
+        product *= geodes;
+    }
+
+    println!("Product: {product}");
+
+    Ok(())
+}
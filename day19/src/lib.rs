@@ -0,0 +1,200 @@
+use std::str::FromStr;
+
+use common::{answer::Answer, diagnostics::parse_lines, parallel::prelude::*};
+use regex::Regex;
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let blueprints = parse_blueprints(input)?;
+    let quality_levels: u32 = blueprints
+        .into_maybe_par_iter()
+        .map(|blueprint| blueprint.quality_level(24))
+        .sum();
+    Ok(quality_levels.into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let blueprints = parse_blueprints(input)?;
+    let blueprints = &blueprints[..blueprints.len().min(3)];
+    let geodes: u32 = blueprints
+        .into_maybe_par_iter()
+        .map(|blueprint| max_geodes(blueprint, 32))
+        .product();
+    Ok(geodes.into())
+}
+
+pub fn parse_blueprints(input: &str) -> eyre::Result<Vec<Blueprint>> {
+    Ok(parse_lines(input)?)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Resource {
+    pub const ALL: [Resource; 4] = [
+        Resource::Ore,
+        Resource::Clay,
+        Resource::Obsidian,
+        Resource::Geode,
+    ];
+}
+
+// An amount of each resource, indexed by `Resource`
+pub type Amounts = [u32; 4];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blueprint {
+    pub id: u32,
+    // The cost of each kind of robot, indexed by the resource it collects
+    pub costs: [Amounts; 4],
+}
+
+impl Blueprint {
+    pub fn cost(&self, robot: Resource) -> &Amounts {
+        &self.costs[robot as usize]
+    }
+
+    pub fn quality_level(&self, minutes: u32) -> u32 {
+        self.id * max_geodes(self, minutes)
+    }
+
+    // Only one robot can be built per minute, so there's no point having
+    // more robots collecting a resource than the most any robot costs
+    pub fn max_useful_robots(&self) -> Amounts {
+        let mut max_robots = [u32::MAX; 4];
+        for resource in [Resource::Ore, Resource::Clay, Resource::Obsidian] {
+            let index = resource as usize;
+            max_robots[index] = self.costs.iter().map(|cost| cost[index]).max().unwrap();
+        }
+
+        max_robots
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> eyre::Result<Self> {
+        lazy_static::lazy_static! {
+            static ref BLUEPRINT_REGEX: Regex = Regex::new(r#"^Blueprint (?P<id>\d+): Each ore robot costs (?P<ore_ore>\d+) ore\. Each clay robot costs (?P<clay_ore>\d+) ore\. Each obsidian robot costs (?P<obsidian_ore>\d+) ore and (?P<obsidian_clay>\d+) clay\. Each geode robot costs (?P<geode_ore>\d+) ore and (?P<geode_obsidian>\d+) obsidian\.$"#).unwrap();
+        }
+
+        let captures = BLUEPRINT_REGEX
+            .captures(s.trim())
+            .ok_or_else(|| eyre::eyre!("invalid blueprint: {s:?}"))?;
+        let number = |name: &str| -> eyre::Result<u32> {
+            Ok(captures.name(name).unwrap().as_str().parse()?)
+        };
+
+        Ok(Self {
+            id: number("id")?,
+            costs: [
+                [number("ore_ore")?, 0, 0, 0],
+                [number("clay_ore")?, 0, 0, 0],
+                [number("obsidian_ore")?, number("obsidian_clay")?, 0, 0],
+                [number("geode_ore")?, 0, number("geode_obsidian")?, 0],
+            ],
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct State {
+    pub time_left: u32,
+    pub resources: Amounts,
+    pub robots: Amounts,
+}
+
+impl State {
+    pub fn new(minutes: u32) -> Self {
+        Self {
+            time_left: minutes,
+            resources: [0; 4],
+            robots: [1, 0, 0, 0],
+        }
+    }
+
+    // Geodes cracked by the end if no more robots are built
+    pub fn final_geodes(&self) -> u32 {
+        let geode = Resource::Geode as usize;
+        self.resources[geode] + self.robots[geode] * self.time_left
+    }
+
+    // The most geodes possible if a geode robot could be built every minute
+    // from now on
+    pub fn upper_bound(&self) -> u32 {
+        self.final_geodes() + self.time_left * self.time_left.saturating_sub(1) / 2
+    }
+
+    /// Wait until `robot` can be afforded and build it, or return `None` if
+    /// it can't be finished before time runs out.
+    pub fn build(&self, blueprint: &Blueprint, robot: Resource) -> Option<State> {
+        let cost = blueprint.cost(robot);
+
+        let mut wait = 0;
+        for resource in Resource::ALL {
+            let index = resource as usize;
+            let missing = cost[index].saturating_sub(self.resources[index]);
+            if missing > 0 {
+                let robots = self.robots[index];
+                if robots == 0 {
+                    return None;
+                }
+
+                wait = wait.max((missing - 1) / robots + 1);
+            }
+        }
+
+        // A robot finished on the last minute can't collect anything
+        let elapsed = wait + 1;
+        if elapsed >= self.time_left {
+            return None;
+        }
+
+        let mut next = *self;
+        next.time_left -= elapsed;
+        for resource in Resource::ALL {
+            let index = resource as usize;
+            next.resources[index] += self.robots[index] * elapsed;
+            next.resources[index] -= cost[index];
+        }
+        next.robots[robot as usize] += 1;
+
+        Some(next)
+    }
+}
+
+/// The most geodes `blueprint` can crack in `minutes`. Each branch of the
+/// search picks the next robot to build, skipping straight to the minute
+/// it's finished. Branches that can't beat the best so far are skipped.
+pub fn max_geodes(blueprint: &Blueprint, minutes: u32) -> u32 {
+    let max_robots = blueprint.max_useful_robots();
+    let mut best = 0;
+    search(blueprint, &max_robots, State::new(minutes), &mut best);
+    best
+}
+
+fn search(blueprint: &Blueprint, max_robots: &Amounts, state: State, best: &mut u32) {
+    *best = (*best).max(state.final_geodes());
+    if state.upper_bound() <= *best {
+        return;
+    }
+
+    // Try geode robots first, since finding a good answer early lets more
+    // branches get pruned
+    for robot in Resource::ALL.into_iter().rev() {
+        if state.robots[robot as usize] >= max_robots[robot as usize] {
+            continue;
+        }
+
+        if let Some(next) = state.build(blueprint, robot) {
+            search(blueprint, max_robots, next, best);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use day19::{Blueprint, Resource, State};
+
+fn example_blueprints() -> Vec<Blueprint> {
+    day19::parse_blueprints(day19::EXAMPLE).unwrap()
+}
+
+#[test]
+fn parse_example() {
+    let blueprints = example_blueprints();
+    assert_eq!(blueprints.len(), 2);
+    assert_eq!(blueprints[0].id, 1);
+    assert_eq!(blueprints[0].cost(Resource::Obsidian), &[3, 14, 0, 0]);
+    assert_eq!(blueprints[1].cost(Resource::Geode), &[3, 0, 12, 0]);
+}
+
+#[test]
+fn build_waits_for_resources() {
+    let blueprint = &example_blueprints()[0];
+    let state = State::new(24).build(blueprint, Resource::Clay).unwrap();
+    assert_eq!(state.time_left, 21);
+    assert_eq!(state.resources, [1, 0, 0, 0]);
+    assert_eq!(state.robots, [1, 1, 0, 0]);
+
+    // No clay robots yet, so an obsidian robot can never be afforded
+    assert_eq!(State::new(24).build(blueprint, Resource::Obsidian), None);
+}
+
+#[test]
+fn example_max_geodes() {
+    let blueprints = example_blueprints();
+    assert_eq!(day19::max_geodes(&blueprints[0], 24), 9);
+    assert_eq!(day19::max_geodes(&blueprints[1], 24), 12);
+}
+
+#[test]
+fn example_max_geodes_32_minutes() {
+    let blueprints = example_blueprints();
+    assert_eq!(day19::max_geodes(&blueprints[0], 32), 56);
+    assert_eq!(day19::max_geodes(&blueprints[1], 32), 62);
+}
+
+#[test]
+fn max_useful_robots() {
+    let blueprints = example_blueprints();
+    assert_eq!(blueprints[0].max_useful_robots(), [4, 14, 7, u32::MAX]);
+}
@@ -0,0 +1,179 @@
+use common::{answer::Answer, diagnostics::ParseError};
+
+pub mod scoring;
+
+pub use scoring::{score_round, Move, Outcome};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+/// How to read the second column of the strategy guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Interpretation {
+    /// X, Y, and Z are the move to play (the part 1 reading)
+    Move,
+    /// X, Y, and Z are the outcome to aim for (the part 2 reading)
+    Outcome,
+}
+
+pub fn solve_part1(input: &str) -> anyhow::Result<Answer> {
+    let total_score = total_score(input, Interpretation::Move)?;
+    Ok(total_score.into())
+}
+
+pub fn solve_part2(input: &str) -> anyhow::Result<Answer> {
+    let total_score = total_score(input, Interpretation::Outcome)?;
+    Ok(total_score.into())
+}
+
+pub fn total_score(input: &str, interpretation: Interpretation) -> anyhow::Result<u64> {
+    let game = Game::parse(input, interpretation)?;
+    Ok(game.me().score)
+}
+
+/// The running state of a game of many rounds between my opponent and me
+#[derive(Debug, Default, Clone)]
+pub struct Game {
+    rounds: Vec<Round>,
+    opponent: Standing,
+    me: Standing,
+}
+
+impl Game {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Play every round in a strategy guide. Errors point at the line and
+    /// column of the token that couldn't be parsed.
+    pub fn parse(input: &str, interpretation: Interpretation) -> anyhow::Result<Self> {
+        let mut game = Game::new();
+        for line in input.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut columns = line.split_whitespace();
+            let (Some(opponent_move), Some(response)) = (columns.next(), columns.next()) else {
+                return Err(ParseError::at(input, line, "expected two columns").into());
+            };
+            if let Some(extra) = columns.next() {
+                return Err(ParseError::at(input, extra, "unexpected extra column").into());
+            }
+
+            let opponent_move = Move::parse_opponent_move(opponent_move)
+                .map_err(|error| ParseError::at(input, opponent_move, format_args!("{error:#}")))?;
+            let my_move = match interpretation {
+                Interpretation::Move => Move::parse_my_move(response),
+                Interpretation::Outcome => Outcome::parse_outcome(response)
+                    .map(|outcome| Move::determine_move(opponent_move, outcome)),
+            };
+            let my_move = my_move
+                .map_err(|error| ParseError::at(input, response, format_args!("{error:#}")))?;
+
+            game.play(opponent_move, my_move);
+        }
+
+        Ok(game)
+    }
+
+    pub fn play(&mut self, opponent: Move, mine: Move) -> &Round {
+        let outcome = Outcome::of(mine, opponent);
+        let round = Round {
+            opponent,
+            mine,
+            outcome,
+            opponent_score: score_round(mine, opponent),
+            my_score: score_round(opponent, mine),
+        };
+
+        self.opponent
+            .record(round.opponent_score, outcome.reversed());
+        self.me.record(round.my_score, outcome);
+        self.rounds.push(round);
+        &self.rounds[self.rounds.len() - 1]
+    }
+
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    pub fn opponent(&self) -> &Standing {
+        &self.opponent
+    }
+
+    pub fn me(&self) -> &Standing {
+        &self.me
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Move,
+    pub mine: Move,
+    /// The outcome from my side
+    pub outcome: Outcome,
+    pub opponent_score: u64,
+    pub my_score: u64,
+}
+
+/// One player's cumulative results
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    pub score: u64,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Standing {
+    fn record(&mut self, score: u64, outcome: Outcome) {
+        self.score += score;
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Loss => self.losses += 1,
+        }
+    }
+}
+
+/// How the strategy guide's score compares to other ways of responding to
+/// the same opponent moves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Analysis {
+    pub guide: u64,
+    /// Winning every round
+    pub best: u64,
+    /// Losing every round
+    pub worst: u64,
+    pub always_draw: u64,
+}
+
+pub fn analyze(input: &str, interpretation: Interpretation) -> anyhow::Result<Analysis> {
+    let game = Game::parse(input, interpretation)?;
+
+    let mut analysis = Analysis {
+        guide: game.me().score,
+        best: 0,
+        worst: 0,
+        always_draw: 0,
+    };
+    for round in game.rounds() {
+        let scores = Move::ALL.map(|mine| score_round(round.opponent, mine));
+        analysis.best += scores.iter().max().unwrap();
+        analysis.worst += scores.iter().min().unwrap();
+        analysis.always_draw += score_round(round.opponent, round.opponent);
+    }
+
+    Ok(analysis)
+}
+
+impl std::fmt::Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "strategy guide: {}", self.guide)?;
+        writeln!(f, "best possible: {}", self.best)?;
+        writeln!(f, "worst possible: {}", self.worst)?;
+        writeln!(f, "always draw: {}", self.always_draw)?;
+        Ok(())
+    }
+}
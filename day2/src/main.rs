@@ -1,99 +1,97 @@
-use std::io::BufRead;
+use clap::Parser;
+use cli::{InputArgs, PartArg};
+use common::config::Config;
+use day2::{Game, Outcome};
 
-use anyhow::Context;
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+    #[clap(flatten)]
+    part: PartArg,
+    /// How to read X/Y/Z. Defaults to `interpretation` under `[day2]` in
+    /// aoc.toml, then to the reading for `--part`.
+    #[clap(long, value_enum)]
+    interpretation: Option<day2::Interpretation>,
+    /// Compare the strategy guide's score to the best, worst, and
+    /// always-draw scores against the same opponent moves
+    #[clap(long)]
+    analyze: bool,
+    /// Print a round-by-round log and standings for both players
+    #[clap(long)]
+    tournament: bool,
+    /// Name of the player in the first column, for `--tournament`
+    #[clap(long, default_value = "Opponent")]
+    opponent: String,
+    /// Name of the player in the second column, for `--tournament`
+    #[clap(long, default_value = "Me")]
+    player: String,
+}
 
 fn main() -> anyhow::Result<()> {
-    let stdin = std::io::stdin().lock();
+    let args = Args::parse();
+    let config = Config::load()?;
 
-    let mut total_score = 0;
-    for line in stdin.lines() {
-        let line = line?;
-        let mut columns = line.split_whitespace();
-        let opponent_move = columns.next().context("no opponent move")?;
-        let outcome = columns.next().context("no outcome")?;
+    let input = args.input.read(day2::EXAMPLE)?;
 
-        let opponent_move = Move::parse_opponent_move(opponent_move)?;
-        let outcome = Outcome::parse_outcome(outcome)?;
-        let my_move = Move::determine_move(opponent_move, outcome);
+    let interpretation = match args.interpretation {
+        Some(interpretation) => interpretation,
+        None => config
+            .get("day2", "interpretation")?
+            .unwrap_or(match args.part.part {
+                1 => day2::Interpretation::Move,
+                _ => day2::Interpretation::Outcome,
+            }),
+    };
 
-        total_score += score_move(opponent_move, my_move);
+    if args.analyze {
+        let analysis = day2::analyze(&input, interpretation)?;
+        print!("{analysis}");
+        return Ok(());
     }
 
-    println!("{}", total_score);
+    if args.tournament {
+        let game = day2::Game::parse(&input, interpretation)?;
+        print_tournament(&game, &args.opponent, &args.player);
+        return Ok(());
+    }
 
-    Ok(())
-}
+    let total_score = day2::total_score(&input, interpretation)?;
+    println!("{total_score}");
 
-#[derive(Debug, Clone, Copy)]
-enum Move {
-    Rock,
-    Paper,
-    Scissors,
+    Ok(())
 }
 
-impl Move {
-    fn parse_opponent_move(s: &str) -> anyhow::Result<Self> {
-        match s {
-            "A" => Ok(Move::Rock),
-            "B" => Ok(Move::Paper),
-            "C" => Ok(Move::Scissors),
-            other => anyhow::bail!("unknown opponent move: {other:?}"),
-        }
-    }
+fn print_tournament(game: &Game, opponent: &str, player: &str) {
+    let mut opponent_total = 0;
+    let mut my_total = 0;
+    for (i, round) in game.rounds().iter().enumerate() {
+        opponent_total += round.opponent_score;
+        my_total += round.my_score;
 
-    fn determine_move(opponent: Move, outcome: Outcome) -> Self {
-        match (opponent, outcome) {
-            (mv, Outcome::Draw) => mv,
-            (Move::Rock, Outcome::Win) => Move::Paper,
-            (Move::Rock, Outcome::Loss) => Move::Scissors,
-            (Move::Paper, Outcome::Win) => Move::Scissors,
-            (Move::Paper, Outcome::Loss) => Move::Rock,
-            (Move::Scissors, Outcome::Win) => Move::Rock,
-            (Move::Scissors, Outcome::Loss) => Move::Paper,
-        }
+        let result = match round.outcome {
+            Outcome::Win => format!("{player} wins"),
+            Outcome::Draw => "draw".to_string(),
+            Outcome::Loss => format!("{opponent} wins"),
+        };
+        println!(
+            "Round {}: {opponent} plays {:?}, {player} plays {:?}, {result} ({opponent} {opponent_total}, {player} {my_total})",
+            i + 1,
+            round.opponent,
+            round.mine,
+        );
     }
-}
 
-fn score_move(opponent: Move, mine: Move) -> u64 {
-    let shape_score = match mine {
-        Move::Rock => 1,
-        Move::Paper => 2,
-        Move::Scissors => 3,
-    };
-    let outcome = match (mine, opponent) {
-        (Move::Rock, Move::Rock) => Outcome::Draw,
-        (Move::Rock, Move::Paper) => Outcome::Loss,
-        (Move::Rock, Move::Scissors) => Outcome::Win,
-        (Move::Paper, Move::Rock) => Outcome::Win,
-        (Move::Paper, Move::Paper) => Outcome::Draw,
-        (Move::Paper, Move::Scissors) => Outcome::Loss,
-        (Move::Scissors, Move::Rock) => Outcome::Loss,
-        (Move::Scissors, Move::Paper) => Outcome::Win,
-        (Move::Scissors, Move::Scissors) => Outcome::Draw,
-    };
-    let outcome_score = match outcome {
-        Outcome::Win => 6,
-        Outcome::Draw => 3,
-        Outcome::Loss => 0,
-    };
-
-    shape_score + outcome_score
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Outcome {
-    Win,
-    Loss,
-    Draw,
-}
+    let mut standings = [(opponent, game.opponent()), (player, game.me())];
+    standings.sort_by_key(|(_, standing)| std::cmp::Reverse(standing.score));
 
-impl Outcome {
-    fn parse_outcome(s: &str) -> anyhow::Result<Self> {
-        match s {
-            "X" => Ok(Outcome::Loss),
-            "Y" => Ok(Outcome::Draw),
-            "Z" => Ok(Outcome::Win),
-            other => anyhow::bail!("unknown outcome: {other:?}"),
-        }
+    let width = opponent.len().max(player.len());
+    println!();
+    println!("{:width$}  score  W  D  L", "");
+    for (name, standing) in standings {
+        println!(
+            "{name:width$}  {:5}  {}  {}  {}",
+            standing.score, standing.wins, standing.draws, standing.losses
+        );
     }
 }
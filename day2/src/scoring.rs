@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    pub const ALL: [Move; 3] = [Move::Rock, Move::Paper, Move::Scissors];
+
+    /// Parse the first column: `A`, `B`, or `C`, or the name of the move
+    pub fn parse_opponent_move(s: &str) -> anyhow::Result<Self> {
+        match &*s.to_ascii_lowercase() {
+            "a" | "rock" => Ok(Move::Rock),
+            "b" | "paper" => Ok(Move::Paper),
+            "c" | "scissors" => Ok(Move::Scissors),
+            _ => anyhow::bail!(
+                "unknown opponent move {s:?}, expected A, B, C, rock, paper, or scissors"
+            ),
+        }
+    }
+
+    /// Parse the second column as a move: `X`, `Y`, or `Z`, or the name of
+    /// the move
+    pub fn parse_my_move(s: &str) -> anyhow::Result<Self> {
+        match &*s.to_ascii_lowercase() {
+            "x" | "rock" => Ok(Move::Rock),
+            "y" | "paper" => Ok(Move::Paper),
+            "z" | "scissors" => Ok(Move::Scissors),
+            _ => anyhow::bail!("unknown move {s:?}, expected X, Y, Z, rock, paper, or scissors"),
+        }
+    }
+
+    pub fn determine_move(opponent: Move, outcome: Outcome) -> Self {
+        match (opponent, outcome) {
+            (mv, Outcome::Draw) => mv,
+            (Move::Rock, Outcome::Win) => Move::Paper,
+            (Move::Rock, Outcome::Loss) => Move::Scissors,
+            (Move::Paper, Outcome::Win) => Move::Scissors,
+            (Move::Paper, Outcome::Loss) => Move::Rock,
+            (Move::Scissors, Outcome::Win) => Move::Rock,
+            (Move::Scissors, Outcome::Loss) => Move::Paper,
+        }
+    }
+}
+
+/// My score for a round: the score for the shape I played, plus the score for
+/// the outcome
+pub fn score_round(opponent: Move, mine: Move) -> u64 {
+    let shape_score = match mine {
+        Move::Rock => 1,
+        Move::Paper => 2,
+        Move::Scissors => 3,
+    };
+    let outcome_score = match Outcome::of(mine, opponent) {
+        Outcome::Win => 6,
+        Outcome::Draw => 3,
+        Outcome::Loss => 0,
+    };
+
+    shape_score + outcome_score
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl Outcome {
+    /// The outcome for the player who played `mine`
+    pub fn of(mine: Move, opponent: Move) -> Self {
+        match (mine, opponent) {
+            (Move::Rock, Move::Rock) => Outcome::Draw,
+            (Move::Rock, Move::Paper) => Outcome::Loss,
+            (Move::Rock, Move::Scissors) => Outcome::Win,
+            (Move::Paper, Move::Rock) => Outcome::Win,
+            (Move::Paper, Move::Paper) => Outcome::Draw,
+            (Move::Paper, Move::Scissors) => Outcome::Loss,
+            (Move::Scissors, Move::Rock) => Outcome::Loss,
+            (Move::Scissors, Move::Paper) => Outcome::Win,
+            (Move::Scissors, Move::Scissors) => Outcome::Draw,
+        }
+    }
+
+    /// The same round's outcome for the other player
+    pub fn reversed(self) -> Self {
+        match self {
+            Outcome::Win => Outcome::Loss,
+            Outcome::Draw => Outcome::Draw,
+            Outcome::Loss => Outcome::Win,
+        }
+    }
+
+    /// Parse the second column as an outcome: `X`, `Y`, or `Z`, or `lose`,
+    /// `draw`, or `win`
+    pub fn parse_outcome(s: &str) -> anyhow::Result<Self> {
+        match &*s.to_ascii_lowercase() {
+            "x" | "lose" | "loss" => Ok(Outcome::Loss),
+            "y" | "draw" => Ok(Outcome::Draw),
+            "z" | "win" => Ok(Outcome::Win),
+            _ => anyhow::bail!("unknown outcome {s:?}, expected X, Y, Z, lose, draw, or win"),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use common::diagnostics::ParseError;
+use day2::{analyze, total_score, Analysis, Game, Interpretation, Outcome};
+
+#[test]
+fn example_as_moves() {
+    assert_eq!(
+        total_score(day2::EXAMPLE, Interpretation::Move).unwrap(),
+        15
+    );
+}
+
+#[test]
+fn example_as_outcomes() {
+    assert_eq!(
+        total_score(day2::EXAMPLE, Interpretation::Outcome).unwrap(),
+        12
+    );
+}
+
+#[test]
+fn example_tournament() {
+    let game = Game::parse(day2::EXAMPLE, Interpretation::Move).unwrap();
+    assert_eq!(game.rounds().len(), 3);
+    assert_eq!(game.me().score, 15);
+    assert_eq!(game.opponent().score, 15);
+    assert_eq!(
+        (game.me().wins, game.me().draws, game.me().losses),
+        (1, 1, 1)
+    );
+    assert_eq!(
+        (game.opponent().wins, game.opponent().losses),
+        (game.me().losses, game.me().wins)
+    );
+    assert_eq!(game.rounds()[0].outcome, Outcome::Win);
+}
+
+#[test]
+fn example_analysis() {
+    let analysis = analyze(day2::EXAMPLE, Interpretation::Move).unwrap();
+    assert_eq!(
+        analysis,
+        Analysis {
+            guide: 15,
+            best: 24,
+            worst: 6,
+            always_draw: 15,
+        }
+    );
+}
+
+#[test]
+fn word_notation() {
+    let guide = "rock Paper\nPAPER rock\n\nc scissors\n";
+    assert_eq!(total_score(guide, Interpretation::Move).unwrap(), 15);
+
+    let guide = "Rock draw\npaper LOSE\nscissors win\n";
+    assert_eq!(total_score(guide, Interpretation::Outcome).unwrap(), 12);
+}
+
+#[test]
+fn parse_errors_point_at_token() {
+    let error = total_score("A Y\nB spock\n", Interpretation::Move).unwrap_err();
+    let error = error.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((error.line(), error.column()), (2, 3));
+    assert!(error.message().contains("\"spock\""), "{error}");
+
+    let error = total_score("A Y\nB\n", Interpretation::Outcome).unwrap_err();
+    let error = error.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((error.line(), error.column()), (2, 1));
+}
@@ -0,0 +1,32 @@
+use day2::{score_round, Move, Outcome};
+
+#[test]
+fn all_matchups() {
+    use Move::*;
+
+    let cases = [
+        (Rock, Rock, Outcome::Draw, 1 + 3),
+        (Rock, Paper, Outcome::Win, 2 + 6),
+        (Rock, Scissors, Outcome::Loss, 3),
+        (Paper, Rock, Outcome::Loss, 1),
+        (Paper, Paper, Outcome::Draw, 2 + 3),
+        (Paper, Scissors, Outcome::Win, 3 + 6),
+        (Scissors, Rock, Outcome::Win, 1 + 6),
+        (Scissors, Paper, Outcome::Loss, 2),
+        (Scissors, Scissors, Outcome::Draw, 3 + 3),
+    ];
+    for (opponent, mine, outcome, score) in cases {
+        assert_eq!(
+            Outcome::of(mine, opponent),
+            outcome,
+            "{mine:?} vs {opponent:?}"
+        );
+        assert_eq!(Outcome::of(opponent, mine), outcome.reversed());
+        assert_eq!(
+            score_round(opponent, mine),
+            score,
+            "{mine:?} vs {opponent:?}"
+        );
+        assert_eq!(Move::determine_move(opponent, outcome), mine);
+    }
+}
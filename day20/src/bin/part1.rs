@@ -0,0 +1,29 @@
+use clap::Parser;
+use cli::InputArgs;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long, default_value_t = 1)]
+    key: i64,
+    #[clap(short, long, default_value_t = 1)]
+    rounds: usize,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day20::EXAMPLE)?;
+    let numbers = day20::parse_numbers(&input)?;
+
+    let mixed = day20::decrypt(numbers, args.key, args.rounds)?;
+
+    let [x, y, z] = day20::grove_coordinates(&mixed)?;
+    println!("Grove coordinates: {x}, {y}, {z}");
+    println!("Sum: {}", x + y + z);
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use common::{answer::Answer, diagnostics::parse_lines, modular::wrap_index};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub const DECRYPTION_KEY: i64 = 811589153;
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let numbers = parse_numbers(input)?;
+    let coordinates = grove_coordinates(&decrypt(numbers, 1, 1)?)?;
+    Ok(coordinates.iter().sum::<i64>().into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let numbers = parse_numbers(input)?;
+    let coordinates = grove_coordinates(&decrypt(numbers, DECRYPTION_KEY, 10)?)?;
+    Ok(coordinates.iter().sum::<i64>().into())
+}
+
+pub fn parse_numbers(input: &str) -> eyre::Result<Vec<i64>> {
+    Ok(parse_lines(input)?)
+}
+
+/// Multiply every number by `key`, then mix the file `rounds` times. Fails
+/// if any number overflows when multiplied.
+pub fn decrypt(numbers: Vec<i64>, key: i64, rounds: usize) -> eyre::Result<Vec<i64>> {
+    let numbers = numbers
+        .into_iter()
+        .map(|number| {
+            number
+                .checked_mul(key)
+                .ok_or_else(|| eyre::eyre!("{number} * {key} is out of range"))
+        })
+        .collect::<eyre::Result<_>>()?;
+
+    let mut mixer = Mixer::new(numbers);
+    for _ in 0..rounds {
+        mixer.mix();
+    }
+
+    Ok(mixer.mixed())
+}
+
+/// The 1000th, 2000th, and 3000th numbers after 0, wrapping around.
+pub fn grove_coordinates(mixed: &[i64]) -> eyre::Result<[i64; 3]> {
+    let zero = mixed
+        .iter()
+        .position(|&number| number == 0)
+        .ok_or_else(|| eyre::eyre!("no 0 in the file"))?;
+
+    Ok([1000, 2000, 3000].map(|offset| mixed[(zero + offset) % mixed.len()]))
+}
+
+// Mixes a file by moving numbers around a circular list. Numbers are
+// tracked by their original index, since the same number can show up
+// more than once.
+//
+// The list is split into buckets of about sqrt(n) numbers each, so finding
+// a number's position and moving it only has to walk one bucket plus the
+// bucket lengths, instead of the whole list.
+#[derive(Debug, Clone)]
+pub struct Mixer {
+    numbers: Vec<i64>,
+    // The original indices of the numbers in order, split into buckets
+    buckets: Vec<Vec<usize>>,
+    // Which bucket each original index is currently in
+    bucket_of: Vec<usize>,
+    bucket_size: usize,
+}
+
+impl Mixer {
+    pub fn new(numbers: Vec<i64>) -> Self {
+        let bucket_size = ((numbers.len() as f64).sqrt() as usize).max(1);
+        let mut mixer = Self {
+            buckets: vec![(0..numbers.len()).collect()],
+            bucket_of: vec![0; numbers.len()],
+            numbers,
+            bucket_size,
+        };
+        mixer.rebalance();
+        mixer
+    }
+
+    // Move every number once, in their original order
+    pub fn mix(&mut self) {
+        for original in 0..self.numbers.len() {
+            self.move_number(original);
+        }
+    }
+
+    /// Move the number originally at index `original` forward (or backward,
+    /// if negative) by its value.
+    pub fn move_number(&mut self, original: usize) {
+        // With the number itself removed, moving by the length of the rest
+        // of the list ends up back in the same spot
+        let others = self.numbers.len().saturating_sub(1);
+        if others == 0 {
+            return;
+        }
+
+        let bucket = self.bucket_of[original];
+        let offset = self.buckets[bucket]
+            .iter()
+            .position(|&index| index == original)
+            .expect("number not found");
+        let before: usize = self.buckets[..bucket].iter().map(Vec::len).sum();
+        self.buckets[bucket].remove(offset);
+
+        let mut new_position = wrap_index(before + offset, self.numbers[original], others);
+        let mut new_bucket = 0;
+        while new_position > self.buckets[new_bucket].len() {
+            new_position -= self.buckets[new_bucket].len();
+            new_bucket += 1;
+        }
+        self.buckets[new_bucket].insert(new_position, original);
+        self.bucket_of[original] = new_bucket;
+
+        if self.buckets[new_bucket].len() > 2 * self.bucket_size {
+            self.rebalance();
+        }
+    }
+
+    pub fn mixed(&self) -> Vec<i64> {
+        self.buckets
+            .iter()
+            .flatten()
+            .map(|&index| self.numbers[index])
+            .collect()
+    }
+
+    // Split the list back into evenly-sized buckets
+    fn rebalance(&mut self) {
+        let order: Vec<usize> = self.buckets.drain(..).flatten().collect();
+        self.buckets = order
+            .chunks(self.bucket_size)
+            .map(|bucket| bucket.to_vec())
+            .collect();
+        if self.buckets.is_empty() {
+            self.buckets.push(vec![]);
+        }
+
+        for (bucket, indices) in self.buckets.iter().enumerate() {
+            for &index in indices {
+                self.bucket_of[index] = bucket;
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use day20::Mixer;
+
+// The list rotated to start at its first 0, so circular lists can be
+// compared regardless of where they were split
+fn from_zero(numbers: &[i64]) -> Vec<i64> {
+    let zero = numbers.iter().position(|&number| number == 0).unwrap();
+    numbers[zero..]
+        .iter()
+        .chain(&numbers[..zero])
+        .copied()
+        .collect()
+}
+
+fn move_once(numbers: &[i64], original: usize) -> Vec<i64> {
+    let mut mixer = Mixer::new(numbers.to_vec());
+    mixer.move_number(original);
+    from_zero(&mixer.mixed())
+}
+
+#[test]
+fn example_mix() {
+    let numbers = day20::parse_numbers(day20::EXAMPLE).unwrap();
+    let mut mixer = Mixer::new(numbers);
+    mixer.mix();
+
+    assert_eq!(from_zero(&mixer.mixed()), [0, 3, -2, 1, 2, -3, 4]);
+    assert_eq!(
+        day20::grove_coordinates(&mixer.mixed()).unwrap(),
+        [4, -3, 2]
+    );
+}
+
+#[test]
+fn example_decrypt() {
+    let numbers = day20::parse_numbers(day20::EXAMPLE).unwrap();
+    let mixed = day20::decrypt(numbers, day20::DECRYPTION_KEY, 10).unwrap();
+
+    assert_eq!(
+        from_zero(&mixed),
+        [
+            0,
+            -2434767459,
+            1623178306,
+            3246356612,
+            -1623178306,
+            2434767459,
+            811589153
+        ]
+    );
+    assert_eq!(
+        day20::grove_coordinates(&mixed).unwrap(),
+        [811589153, 2434767459, -1623178306]
+    );
+}
+
+#[test]
+fn move_forward() {
+    assert_eq!(move_once(&[4, 5, 6, 1, 7, 8, 0], 3), [0, 4, 5, 6, 7, 1, 8]);
+}
+
+#[test]
+fn move_backward_past_start() {
+    // -2 moves back past the start, ending up between 8 and 0
+    assert_eq!(
+        move_once(&[4, -2, 5, 6, 7, 8, 0], 1),
+        from_zero(&[4, 5, 6, 7, 8, -2, 0])
+    );
+}
+
+#[test]
+fn move_backward_several_laps() {
+    // With 3 other numbers, -13 lands in the same spot as -1
+    assert_eq!(move_once(&[0, -13, 1, 2], 1), [0, 1, 2, -13]);
+    assert_eq!(move_once(&[0, -1, 1, 2], 1), [0, 1, 2, -1]);
+}
+
+#[test]
+fn move_by_list_length() {
+    // Moving by one less than the list length is a full lap
+    assert_eq!(move_once(&[0, 3, 1, 2], 1), [0, 3, 1, 2]);
+    assert_eq!(move_once(&[0, -3, 1, 2], 1), [0, -3, 1, 2]);
+}
+
+#[test]
+fn duplicate_numbers() {
+    let mut mixer = Mixer::new(vec![1, 1, 0]);
+    mixer.mix();
+    assert_eq!(from_zero(&mixer.mixed()), [0, 1, 1]);
+}
+
+#[test]
+fn decrypt_overflow() {
+    let error = day20::decrypt(vec![0, i64::MAX / 2], 3, 1).unwrap_err();
+    assert!(error.to_string().contains("out of range"), "{error}");
+    assert!(day20::decrypt(vec![0, i64::MIN], -1, 1).is_err());
+}
+
+#[test]
+fn bucketed_mix_matches_naive() {
+    // Enough numbers for several buckets, with repeats and large offsets
+    let mut seed: u64 = 1;
+    let numbers: Vec<i64> = (0..500)
+        .map(|i| {
+            if i == 0 {
+                return 0;
+            }
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as i64 % 10_000 - 5_000
+        })
+        .collect();
+
+    let mut naive: Vec<usize> = (0..numbers.len()).collect();
+    let mut mixer = Mixer::new(numbers.clone());
+    for _ in 0..3 {
+        for (original, &number) in numbers.iter().enumerate() {
+            let position = naive.iter().position(|&index| index == original).unwrap();
+            naive.remove(position);
+            let new_position = (position as i64 + number).rem_euclid(naive.len() as i64) as usize;
+            naive.insert(new_position, original);
+        }
+        mixer.mix();
+
+        let expected: Vec<i64> = naive.iter().map(|&index| numbers[index]).collect();
+        assert_eq!(from_zero(&mixer.mixed()), from_zero(&expected));
+    }
+}
@@ -0,0 +1,25 @@
+use clap::Parser;
+use cli::InputArgs;
+use day21::Monkeys;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long, default_value = day21::ROOT)]
+    monkey: String,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day21::EXAMPLE)?;
+    let monkeys = Monkeys::parse(&input)?;
+
+    let value = monkeys.evaluate(&args.monkey)?;
+    println!("{} yells {value}", args.monkey);
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use clap::Parser;
+use cli::InputArgs;
+use day21::Monkeys;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long, default_value = day21::ROOT)]
+    root: String,
+    #[clap(long, default_value = day21::HUMAN)]
+    human: String,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day21::EXAMPLE)?;
+    let monkeys = Monkeys::parse(&input)?;
+
+    let value = monkeys.solve_for(&args.root, &args.human)?;
+    println!("{} should yell {value}", args.human);
+
+    Ok(())
+}
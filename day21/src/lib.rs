@@ -0,0 +1,305 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{answer::Answer, diagnostics::parse_lines};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub const ROOT: &str = "root";
+pub const HUMAN: &str = "humn";
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let monkeys = Monkeys::parse(input)?;
+    let value = monkeys.evaluate(ROOT)?;
+    Ok(value.into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let monkeys = Monkeys::parse(input)?;
+    let value = monkeys.solve_for(ROOT, HUMAN)?;
+    Ok(value.into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    // `None` on overflow or division by zero
+    pub fn apply(&self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            Operator::Add => lhs.checked_add(rhs),
+            Operator::Sub => lhs.checked_sub(rhs),
+            Operator::Mul => lhs.checked_mul(rhs),
+            Operator::Div => lhs.checked_div(rhs),
+        }
+    }
+}
+
+impl FromStr for Operator {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> eyre::Result<Self> {
+        match s {
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Sub),
+            "*" => Ok(Operator::Mul),
+            "/" => Ok(Operator::Div),
+            other => eyre::bail!("invalid operator: {other:?}"),
+        }
+    }
+}
+
+impl std::fmt::Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self {
+            Operator::Add => "+",
+            Operator::Sub => "-",
+            Operator::Mul => "*",
+            Operator::Div => "/",
+        };
+        write!(f, "{operator}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Job {
+    Number(i64),
+    Operation(String, Operator, String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonkeyJob {
+    pub name: String,
+    pub job: Job,
+}
+
+impl FromStr for MonkeyJob {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> eyre::Result<Self> {
+        let (name, job) = s
+            .split_once(": ")
+            .ok_or_else(|| eyre::eyre!("invalid monkey job: {s:?}"))?;
+
+        let job = match job.split_whitespace().collect::<Vec<_>>()[..] {
+            [number] => Job::Number(number.parse()?),
+            [lhs, operator, rhs] => {
+                Job::Operation(lhs.to_string(), operator.parse()?, rhs.to_string())
+            }
+            _ => eyre::bail!("invalid job: {job:?}"),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            job,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvaluateError {
+    UnknownMonkey(String),
+    Cycle(String),
+    Overflow(String),
+    Unsolvable(String),
+}
+
+impl std::fmt::Display for EvaluateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvaluateError::UnknownMonkey(name) => write!(f, "no job for monkey {name:?}"),
+            EvaluateError::Cycle(name) => write!(f, "monkey {name:?} depends on itself"),
+            EvaluateError::Overflow(name) => {
+                write!(f, "monkey {name:?} overflowed or divided by zero")
+            }
+            EvaluateError::Unsolvable(name) => {
+                write!(f, "no exact number makes monkey {name:?} match")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvaluateError {}
+
+// Every monkey's job, which together form a DAG of expressions
+#[derive(Debug, Clone)]
+pub struct Monkeys {
+    jobs: HashMap<String, Job>,
+}
+
+impl Monkeys {
+    pub fn parse(input: &str) -> eyre::Result<Self> {
+        let monkey_jobs: Vec<MonkeyJob> = parse_lines(input)?;
+
+        let mut jobs = HashMap::new();
+        for MonkeyJob { name, job } in monkey_jobs {
+            if jobs.contains_key(&name) {
+                eyre::bail!("monkey {name:?} has more than one job");
+            }
+
+            jobs.insert(name, job);
+        }
+
+        Ok(Self { jobs })
+    }
+
+    pub fn job(&self, name: &str) -> Result<&Job, EvaluateError> {
+        self.jobs
+            .get(name)
+            .ok_or_else(|| EvaluateError::UnknownMonkey(name.to_string()))
+    }
+
+    /// The number yelled by the monkey `name`.
+    pub fn evaluate(&self, name: &str) -> Result<i64, EvaluateError> {
+        let mut values = HashMap::new();
+        let mut visiting = vec![];
+        self.evaluate_with(name, &mut values, &mut visiting)
+    }
+
+    fn evaluate_with<'a>(
+        &'a self,
+        name: &'a str,
+        values: &mut HashMap<&'a str, i64>,
+        visiting: &mut Vec<&'a str>,
+    ) -> Result<i64, EvaluateError> {
+        if let Some(&value) = values.get(name) {
+            return Ok(value);
+        }
+
+        if visiting.contains(&name) {
+            return Err(EvaluateError::Cycle(name.to_string()));
+        }
+
+        let value = match self.job(name)? {
+            Job::Number(number) => *number,
+            Job::Operation(lhs, operator, rhs) => {
+                visiting.push(name);
+                let lhs = self.evaluate_with(lhs, values, visiting)?;
+                let rhs = self.evaluate_with(rhs, values, visiting)?;
+                visiting.pop();
+
+                operator
+                    .apply(lhs, rhs)
+                    .ok_or_else(|| EvaluateError::Overflow(name.to_string()))?
+            }
+        };
+
+        values.insert(name, value);
+        Ok(value)
+    }
+
+    /// Find the number `human` needs to yell so both sides of `root`'s job
+    /// are equal. Only one side may depend on `human`, and it's solved by
+    /// undoing each operation between `root` and `human` in turn.
+    pub fn solve_for(&self, root: &str, human: &str) -> Result<i64, EvaluateError> {
+        let unsolvable = |name: &str| EvaluateError::Unsolvable(name.to_string());
+        let overflow = |name: &str| EvaluateError::Overflow(name.to_string());
+
+        let mut dependents = HashMap::new();
+        let mut depends_on_human =
+            |name| self.depends_on(name, human, &mut dependents, &mut vec![]);
+
+        let Job::Operation(lhs, _, rhs) = self.job(root)? else {
+            return Err(unsolvable(root));
+        };
+        let (mut current, known) = match (depends_on_human(lhs)?, depends_on_human(rhs)?) {
+            (true, false) => (lhs, rhs),
+            (false, true) => (rhs, lhs),
+            _ => return Err(unsolvable(root)),
+        };
+
+        // `target` is the number `current` must yell
+        let mut target = self.evaluate(known)?;
+        while current != human {
+            let Job::Operation(lhs, operator, rhs) = self.job(current)? else {
+                return Err(unsolvable(current));
+            };
+
+            let exact_div = |lhs: i64, rhs: i64| match lhs.checked_rem(rhs) {
+                Some(0) => Ok(lhs / rhs),
+                _ => Err(unsolvable(current)),
+            };
+
+            target = match (depends_on_human(lhs)?, depends_on_human(rhs)?) {
+                // x op rhs = target
+                (true, false) => {
+                    let rhs = self.evaluate(rhs)?;
+                    let x = match operator {
+                        Operator::Add => {
+                            target.checked_sub(rhs).ok_or_else(|| overflow(current))?
+                        }
+                        Operator::Sub => {
+                            target.checked_add(rhs).ok_or_else(|| overflow(current))?
+                        }
+                        Operator::Mul => exact_div(target, rhs)?,
+                        Operator::Div => {
+                            target.checked_mul(rhs).ok_or_else(|| overflow(current))?
+                        }
+                    };
+
+                    current = lhs;
+                    x
+                }
+                // lhs op x = target
+                (false, true) => {
+                    let lhs = self.evaluate(lhs)?;
+                    let x = match operator {
+                        Operator::Add => {
+                            target.checked_sub(lhs).ok_or_else(|| overflow(current))?
+                        }
+                        Operator::Sub => {
+                            lhs.checked_sub(target).ok_or_else(|| overflow(current))?
+                        }
+                        Operator::Mul => exact_div(target, lhs)?,
+                        Operator::Div => exact_div(lhs, target)?,
+                    };
+
+                    current = rhs;
+                    x
+                }
+                _ => return Err(unsolvable(current)),
+            };
+        }
+
+        Ok(target)
+    }
+
+    fn depends_on<'a>(
+        &'a self,
+        name: &'a str,
+        human: &str,
+        dependents: &mut HashMap<&'a str, bool>,
+        visiting: &mut Vec<&'a str>,
+    ) -> Result<bool, EvaluateError> {
+        if name == human {
+            return Ok(true);
+        }
+
+        if let Some(&depends) = dependents.get(name) {
+            return Ok(depends);
+        }
+
+        if visiting.contains(&name) {
+            return Err(EvaluateError::Cycle(name.to_string()));
+        }
+
+        let depends = match self.job(name)? {
+            Job::Number(_) => false,
+            Job::Operation(lhs, _, rhs) => {
+                visiting.push(name);
+                let depends = self.depends_on(lhs, human, dependents, visiting)?
+                    || self.depends_on(rhs, human, dependents, visiting)?;
+                visiting.pop();
+
+                depends
+            }
+        };
+
+        dependents.insert(name, depends);
+        Ok(depends)
+    }
+}
@@ -0,0 +1,62 @@
+use day21::{EvaluateError, Monkeys};
+
+#[test]
+fn example_root() {
+    let monkeys = Monkeys::parse(day21::EXAMPLE).unwrap();
+    assert_eq!(monkeys.evaluate("root"), Ok(152));
+    assert_eq!(monkeys.evaluate("sjmn"), Ok(150));
+}
+
+#[test]
+fn example_solve_for_human() {
+    let monkeys = Monkeys::parse(day21::EXAMPLE).unwrap();
+    assert_eq!(monkeys.solve_for("root", "humn"), Ok(301));
+}
+
+#[test]
+fn solve_for_human_on_right() {
+    let monkeys = Monkeys::parse(
+        "root: aaaa + bbbb\naaaa: cccc / dddd\nbbbb: 20\ncccc: 100\ndddd: eeee - humn\neeee: 10\nhumn: 0\n",
+    )
+    .unwrap();
+    assert_eq!(monkeys.solve_for("root", "humn"), Ok(5));
+}
+
+#[test]
+fn solve_for_human_inexact() {
+    let monkeys =
+        Monkeys::parse("root: aaaa + bbbb\naaaa: humn * cccc\nbbbb: 10\ncccc: 3\nhumn: 0\n")
+            .unwrap();
+    assert_eq!(
+        monkeys.solve_for("root", "humn"),
+        Err(EvaluateError::Unsolvable("aaaa".to_string()))
+    );
+}
+
+#[test]
+fn cycle() {
+    let monkeys =
+        Monkeys::parse("root: aaaa + bbbb\naaaa: 1\nbbbb: cccc * 2\ncccc: root - 1\n").unwrap();
+    assert!(matches!(
+        monkeys.evaluate("root"),
+        Err(EvaluateError::Cycle(_))
+    ));
+}
+
+#[test]
+fn overflow() {
+    let monkeys = Monkeys::parse("root: aaaa * aaaa\naaaa: 9223372036854775807\n").unwrap();
+    assert_eq!(
+        monkeys.evaluate("root"),
+        Err(EvaluateError::Overflow("root".to_string()))
+    );
+}
+
+#[test]
+fn unknown_monkey() {
+    let monkeys = Monkeys::parse("root: aaaa + bbbb\naaaa: 1\n").unwrap();
+    assert_eq!(
+        monkeys.evaluate("root"),
+        Err(EvaluateError::UnknownMonkey("bbbb".to_string()))
+    );
+}
@@ -0,0 +1,28 @@
+use clap::Parser;
+use cli::InputArgs;
+use day22::FlatWrapping;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day22::EXAMPLE)?;
+    let (board, path) = day22::parse_notes(&input)?;
+
+    let (position, facing) = day22::walk(&board, &path, &FlatWrapping)?;
+    println!(
+        "Ended at row {}, column {}, facing {facing:?}",
+        position.row + 1,
+        position.col + 1
+    );
+    println!("Password: {}", day22::password(position, facing));
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use clap::Parser;
+use cli::InputArgs;
+use day22::CubeWrapping;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day22::EXAMPLE)?;
+    let (board, path) = day22::parse_notes(&input)?;
+
+    let cube = CubeWrapping::fold(&board)?;
+    println!("Folded into a cube with faces of size {}", cube.size());
+
+    let (position, facing) = day22::walk(&board, &path, &cube)?;
+    println!(
+        "Ended at row {}, column {}, facing {facing:?}",
+        position.row + 1,
+        position.col + 1
+    );
+    println!("Password: {}", day22::password(position, facing));
+
+    Ok(())
+}
@@ -0,0 +1,368 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::{
+    answer::Answer,
+    geometry::Vector3,
+    grid::{Direction, Grid, Position},
+    input::blocks,
+};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let (board, path) = parse_notes(input)?;
+    let (position, facing) = walk(&board, &path, &FlatWrapping)?;
+    Ok(password(position, facing).into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let (board, path) = parse_notes(input)?;
+    let cube = CubeWrapping::fold(&board)?;
+    let (position, facing) = walk(&board, &path, &cube)?;
+    Ok(password(position, facing).into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Forward(usize),
+    TurnLeft,
+    TurnRight,
+}
+
+pub type Board = Grid<Tile>;
+
+pub fn parse_notes(input: &str) -> eyre::Result<(Board, Vec<Instruction>)> {
+    let mut blocks = blocks(input);
+    let board = blocks.next().ok_or_else(|| eyre::eyre!("no board"))?;
+    let path = blocks.next().ok_or_else(|| eyre::eyre!("no path"))?;
+    if blocks.next().is_some() {
+        eyre::bail!("unexpected input after path");
+    }
+
+    Ok((parse_board(board)?, parse_path(path)?))
+}
+
+pub fn parse_board(input: &str) -> eyre::Result<Board> {
+    let board = Grid::parse_padded(input, b' ')?;
+    if let Some(&invalid) = board
+        .cells()
+        .iter()
+        .find(|cell| !matches!(cell, b' ' | b'.' | b'#'))
+    {
+        eyre::bail!("invalid tile {:?}", invalid as char);
+    }
+
+    Ok(board.map(|cell| match cell {
+        b'.' => Tile::Open,
+        b'#' => Tile::Wall,
+        _ => Tile::Void,
+    }))
+}
+
+pub fn parse_path(input: &str) -> eyre::Result<Vec<Instruction>> {
+    let mut path = vec![];
+    let mut distance = None;
+    for c in input.trim().chars() {
+        if let Some(digit) = c.to_digit(10) {
+            let distance = distance.get_or_insert(0usize);
+            *distance = distance
+                .checked_mul(10)
+                .and_then(|distance| distance.checked_add(digit as usize))
+                .ok_or_else(|| eyre::eyre!("distance too large"))?;
+            continue;
+        }
+
+        if let Some(distance) = distance.take() {
+            path.push(Instruction::Forward(distance));
+        }
+
+        match c {
+            'L' => path.push(Instruction::TurnLeft),
+            'R' => path.push(Instruction::TurnRight),
+            c => eyre::bail!("invalid instruction {c:?}"),
+        }
+    }
+
+    if let Some(distance) = distance {
+        path.push(Instruction::Forward(distance));
+    }
+
+    Ok(path)
+}
+
+// Decides where you end up after stepping off the edge of the map
+pub trait Wrapping {
+    /// The tile (and facing) reached by moving one step from `position`
+    /// facing `facing`, when the tile straight ahead is off the map.
+    fn wrap(&self, board: &Board, position: Position, facing: Direction) -> (Position, Direction);
+}
+
+// Wraps around to the far side of the same row or column
+#[derive(Debug, Clone, Copy)]
+pub struct FlatWrapping;
+
+impl Wrapping for FlatWrapping {
+    fn wrap(&self, board: &Board, position: Position, facing: Direction) -> (Position, Direction) {
+        let backward = facing.opposite().offset();
+        let mut position = position;
+        while let Some(behind) = board.offset(position, backward) {
+            if board[behind] == Tile::Void {
+                break;
+            }
+
+            position = behind;
+        }
+
+        (position, facing)
+    }
+}
+
+// Wraps around the edges of the cube the map folds into
+#[derive(Debug, Clone)]
+pub struct CubeWrapping {
+    size: usize,
+    // Each face by its row and column in the net, measured in faces
+    faces: HashMap<(usize, usize), Face>,
+}
+
+// Where a face of the net ends up once folded, as the directions its
+// outside, its columns, and its rows point in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Face {
+    normal: Vector3,
+    right: Vector3,
+    down: Vector3,
+}
+
+impl Face {
+    // The face across the edge toward `direction`, with this face flat
+    // against the table and the next one folded down over the edge
+    fn roll(&self, direction: Direction) -> Self {
+        let Self {
+            normal,
+            right,
+            down,
+        } = *self;
+        match direction {
+            Direction::Right => Self {
+                normal: right,
+                right: -normal,
+                down,
+            },
+            Direction::Left => Self {
+                normal: -right,
+                right: normal,
+                down,
+            },
+            Direction::Down => Self {
+                normal: down,
+                right,
+                down: -normal,
+            },
+            Direction::Up => Self {
+                normal: -down,
+                right,
+                down: normal,
+            },
+        }
+    }
+
+    fn direction_of(&self, direction: Direction) -> Vector3 {
+        match direction {
+            Direction::Right => self.right,
+            Direction::Left => -self.right,
+            Direction::Down => self.down,
+            Direction::Up => -self.down,
+        }
+    }
+}
+
+impl CubeWrapping {
+    /// Fold `board` into a cube. The size of each face is worked out from
+    /// the number of tiles, so any net of six equal squares works.
+    pub fn fold(board: &Board) -> eyre::Result<Self> {
+        let tiles = board
+            .cells()
+            .iter()
+            .filter(|&&tile| tile != Tile::Void)
+            .count();
+        let size = (1..)
+            .take_while(|size| 6 * size * size <= tiles)
+            .last()
+            .filter(|size| 6 * size * size == tiles)
+            .ok_or_else(|| eyre::eyre!("{tiles} tiles can't cover the faces of a cube"))?;
+
+        let net_rows = board.height() / size;
+        let net_cols = board.width() / size;
+        if net_rows * size != board.height() || net_cols * size != board.width() {
+            eyre::bail!("map isn't made of {size}x{size} faces");
+        }
+        let is_face = |(row, col): (usize, usize)| {
+            let corner = Position {
+                row: row * size,
+                col: col * size,
+            };
+            matches!(board.get(corner), Some(&tile) if tile != Tile::Void)
+        };
+
+        let start = (0..net_cols)
+            .map(|col| (0, col))
+            .find(|&face| is_face(face))
+            .ok_or_else(|| eyre::eyre!("no faces in the top row"))?;
+
+        // Unfold the net one face at a time, starting from the first face
+        let mut faces = HashMap::new();
+        faces.insert(
+            start,
+            Face {
+                normal: Vector3 { x: 0, y: 0, z: -1 },
+                right: Vector3 { x: 1, y: 0, z: 0 },
+                down: Vector3 { x: 0, y: 1, z: 0 },
+            },
+        );
+        let mut queue = VecDeque::from([start]);
+        while let Some((row, col)) = queue.pop_front() {
+            let face = faces[&(row, col)];
+            for direction in Direction::ALL {
+                let (row_offset, col_offset) = direction.offset();
+                let next = match (
+                    row.checked_add_signed(row_offset),
+                    col.checked_add_signed(col_offset),
+                ) {
+                    (Some(row), Some(col)) if row < net_rows && col < net_cols => (row, col),
+                    _ => continue,
+                };
+
+                if is_face(next) && !faces.contains_key(&next) {
+                    faces.insert(next, face.roll(direction));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let net_faces = (0..net_rows)
+            .flat_map(|row| (0..net_cols).map(move |col| (row, col)))
+            .filter(|&face| is_face(face))
+            .count();
+        if faces.len() != 6 || net_faces != 6 {
+            eyre::bail!("map doesn't fold into a cube");
+        }
+
+        let mut normals: Vec<_> = faces.values().map(|face| face.normal).collect();
+        normals.sort();
+        normals.dedup();
+        if normals.len() != 6 {
+            eyre::bail!("map folds into overlapping faces");
+        }
+
+        Ok(Self { size, faces })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn face_with_normal(&self, normal: Vector3) -> ((usize, usize), Face) {
+        self.faces
+            .iter()
+            .find(|(_, face)| face.normal == normal)
+            .map(|(&net_position, &face)| (net_position, face))
+            .expect("cube is missing a face")
+    }
+}
+
+impl Wrapping for CubeWrapping {
+    fn wrap(&self, _board: &Board, position: Position, facing: Direction) -> (Position, Direction) {
+        let size = self.size as i32;
+        let face = self.faces[&(position.row / self.size, position.col / self.size)];
+        let row = (position.row % self.size) as i32;
+        let col = (position.col % self.size) as i32;
+
+        // The tile's center on a cube twice the size, centered at the
+        // origin, so every coordinate is a whole number
+        let point = face.normal * size
+            + face.right * (2 * col + 1 - size)
+            + face.down * (2 * row + 1 - size);
+
+        // Step over the edge and onto the face it leads to
+        let exit = face.direction_of(facing);
+        let point = point + exit - face.normal;
+        let ((net_row, net_col), next_face) = self.face_with_normal(exit);
+
+        let next_facing = Direction::ALL
+            .into_iter()
+            .find(|&direction| next_face.direction_of(direction) == -face.normal)
+            .expect("no direction away from the edge");
+        let next_row = (point.dot(next_face.down) + size - 1) / 2;
+        let next_col = (point.dot(next_face.right) + size - 1) / 2;
+
+        let next_position = Position {
+            row: net_row * self.size + next_row as usize,
+            col: net_col * self.size + next_col as usize,
+        };
+        (next_position, next_facing)
+    }
+}
+
+/// Follow `path` from the leftmost open tile in the top row, returning the
+/// final position and facing.
+pub fn walk(
+    board: &Board,
+    path: &[Instruction],
+    wrapping: &impl Wrapping,
+) -> eyre::Result<(Position, Direction)> {
+    let mut position = board
+        .positions()
+        .find(|&position| board[position] == Tile::Open)
+        .ok_or_else(|| eyre::eyre!("no open tiles"))?;
+    let mut facing = Direction::Right;
+
+    for instruction in path {
+        match instruction {
+            Instruction::TurnLeft => {
+                facing = facing.turn_left();
+            }
+            Instruction::TurnRight => {
+                facing = facing.turn_right();
+            }
+            Instruction::Forward(distance) => {
+                for _ in 0..*distance {
+                    let ahead = board
+                        .offset(position, facing.offset())
+                        .filter(|&ahead| board[ahead] != Tile::Void);
+                    let (next, next_facing) = match ahead {
+                        Some(ahead) => (ahead, facing),
+                        None => wrapping.wrap(board, position, facing),
+                    };
+
+                    if board[next] == Tile::Wall {
+                        break;
+                    }
+
+                    position = next;
+                    facing = next_facing;
+                }
+            }
+        }
+    }
+
+    Ok((position, facing))
+}
+
+pub fn password(position: Position, facing: Direction) -> usize {
+    let facing = match facing {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    };
+
+    1000 * (position.row + 1) + 4 * (position.col + 1) + facing
+}
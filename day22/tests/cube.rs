@@ -0,0 +1,106 @@
+use common::grid::{Direction, Grid, Position};
+use day22::{Board, CubeWrapping, Tile, Wrapping};
+
+// A board of open tiles laid out like `net`, where each `#` is a face
+fn open_board(net: &str, size: usize) -> Board {
+    let mut rows = vec![];
+    for line in net.lines() {
+        let row: String = line
+            .chars()
+            .map(|c| {
+                if c == '#' {
+                    ".".repeat(size)
+                } else {
+                    " ".repeat(size)
+                }
+            })
+            .collect();
+        rows.extend(vec![row; size]);
+    }
+
+    day22::parse_board(&rows.join("\n")).unwrap()
+}
+
+// Wrapping off any edge and then turning around should lead straight
+// back to where you started
+fn assert_edges_round_trip(board: &Board) {
+    let cube = CubeWrapping::fold(board).unwrap();
+    let mut edges = 0;
+    for position in board.positions() {
+        if board[position] == Tile::Void {
+            continue;
+        }
+
+        for facing in Direction::ALL {
+            let ahead = board.offset(position, facing.offset());
+            if matches!(ahead, Some(ahead) if board[ahead] != Tile::Void) {
+                continue;
+            }
+
+            let (next, next_facing) = cube.wrap(board, position, facing);
+            assert_ne!(board[next], Tile::Void, "wrapped into the void");
+
+            let (back, back_facing) = cube.wrap(board, next, next_facing.opposite());
+            assert_eq!(
+                (back, back_facing),
+                (position, facing.opposite()),
+                "{position:?} facing {facing:?} wrapped to {next:?}"
+            );
+            edges += 1;
+        }
+    }
+
+    // Unfolding a cube cuts 7 of its 12 edges, so the outline of the net
+    // has 14 face edges, each `size` tiles long
+    assert_eq!(edges, 14 * cube.size());
+}
+
+#[test]
+fn example_face_size() {
+    let (board, _) = day22::parse_notes(day22::EXAMPLE).unwrap();
+    let cube = CubeWrapping::fold(&board).unwrap();
+    assert_eq!(cube.size(), 4);
+    assert_edges_round_trip(&board);
+}
+
+#[test]
+fn example_wraps() {
+    let (board, _) = day22::parse_notes(day22::EXAMPLE).unwrap();
+    let cube = CubeWrapping::fold(&board).unwrap();
+
+    // A, B, and C from the puzzle description
+    let a = Position { row: 5, col: 11 };
+    assert_eq!(
+        cube.wrap(&board, a, Direction::Right),
+        (Position { row: 8, col: 14 }, Direction::Down)
+    );
+    let c = Position { row: 11, col: 10 };
+    assert_eq!(
+        cube.wrap(&board, c, Direction::Down),
+        (Position { row: 7, col: 1 }, Direction::Up)
+    );
+}
+
+#[test]
+fn other_nets() {
+    let nets = [
+        " ##\n #\n##\n#",
+        " #\n###\n #\n #",
+        "#\n##\n ##\n  #",
+        "###\n  ###",
+    ];
+    for net in nets {
+        for size in [1, 3, 50] {
+            assert_edges_round_trip(&open_board(net, size));
+        }
+    }
+}
+
+#[test]
+fn not_a_cube() {
+    let board = open_board("####\n#\n#", 2);
+    assert!(CubeWrapping::fold(&board).is_err());
+
+    let board: Board = Grid::new(5, vec![Tile::Open; 5]);
+    assert!(CubeWrapping::fold(&board).is_err());
+}
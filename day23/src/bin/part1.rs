@@ -0,0 +1,39 @@
+use std::ops::ControlFlow;
+
+use clap::Parser;
+use cli::{DisplayArgs, InputArgs};
+use common::render::Renderable;
+use day23::Grove;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    display: DisplayArgs,
+    #[clap(short, long, default_value_t = 10)]
+    rounds: usize,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day23::EXAMPLE)?;
+    let mut grove = Grove::parse(&input)?;
+
+    let animation = args.display.animation(200);
+    let rounds = animation.run(&mut grove, |round, _| {
+        if round >= args.rounds {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    println!("After {rounds} rounds:\n{}", grove.render());
+    println!("Empty ground: {}", grove.empty_ground());
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use std::ops::ControlFlow;
+
+use clap::Parser;
+use cli::{DisplayArgs, InputArgs};
+use day23::Grove;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    display: DisplayArgs,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day23::EXAMPLE)?;
+    let mut grove = Grove::parse(&input)?;
+
+    let animation = args.display.animation(50);
+    let rounds = animation.run(&mut grove, |_, _| ControlFlow::Continue(()));
+
+    println!("First round where no elf moved: {}", rounds + 1);
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{ControlFlow, RangeInclusive},
+};
+
+use common::{
+    answer::Answer,
+    grid::Direction,
+    render::Renderable,
+    simulation::{self, Simulation},
+};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+// The order elves consider moving in, starting from a different direction
+// each round
+const PROPOSAL_ORDER: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let mut grove = Grove::parse(input)?;
+    simulation::run_with(&mut grove, |round, _| {
+        if round >= 10 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    Ok(grove.empty_ground().into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let mut grove = Grove::parse(input)?;
+    let rounds = simulation::run(&mut grove);
+
+    // The round where nobody moved isn't counted as a step
+    Ok((rounds + 1).into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    fn offset(self, (row, col): (isize, isize)) -> Self {
+        Self {
+            row: self.row + row as i64,
+            col: self.col + col as i64,
+        }
+    }
+
+    fn neighbors(self) -> impl Iterator<Item = Point> {
+        (-1..=1).flat_map(move |row| {
+            (-1..=1)
+                .filter(move |&col| (row, col) != (0, 0))
+                .map(move |col| self.offset((row, col)))
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Grove {
+    elves: HashSet<Point>,
+    rounds: usize,
+}
+
+impl Grove {
+    pub fn parse(input: &str) -> eyre::Result<Self> {
+        let mut elves = HashSet::new();
+        for (row, line) in common::input::lines(input).enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        elves.insert(Point {
+                            row: row as i64,
+                            col: col as i64,
+                        });
+                    }
+                    '.' => {}
+                    c => eyre::bail!("invalid tile {c:?} on line {}", row + 1),
+                }
+            }
+        }
+
+        Ok(Self { elves, rounds: 0 })
+    }
+
+    pub fn elves(&self) -> &HashSet<Point> {
+        &self.elves
+    }
+
+    /// Run one round, returning how many elves moved.
+    pub fn round(&mut self) -> usize {
+        let mut proposals = HashMap::new();
+        for &elf in &self.elves {
+            if let Some(target) = self.proposal(elf) {
+                proposals
+                    .entry(target)
+                    .and_modify(|proposal| *proposal = None)
+                    .or_insert(Some(elf));
+            }
+        }
+
+        let mut moved = 0;
+        for (target, elf) in proposals {
+            // Elves proposing the same tile all stay put
+            if let Some(elf) = elf {
+                self.elves.remove(&elf);
+                self.elves.insert(target);
+                moved += 1;
+            }
+        }
+
+        self.rounds += 1;
+        moved
+    }
+
+    // Where `elf` wants to move this round, if anywhere
+    fn proposal(&self, elf: Point) -> Option<Point> {
+        if !elf
+            .neighbors()
+            .any(|neighbor| self.elves.contains(&neighbor))
+        {
+            return None;
+        }
+
+        (0..PROPOSAL_ORDER.len())
+            .map(|index| PROPOSAL_ORDER[(self.rounds + index) % PROPOSAL_ORDER.len()])
+            .find(|&direction| {
+                let ahead = elf.offset(direction.offset());
+                let side = direction.turn_right().offset();
+                let checked = [ahead, ahead.offset(side), ahead.offset((-side.0, -side.1))];
+                checked.iter().all(|point| !self.elves.contains(point))
+            })
+            .map(|direction| elf.offset(direction.offset()))
+    }
+
+    fn bounds(&self) -> (RangeInclusive<i64>, RangeInclusive<i64>) {
+        let rows = self.elves.iter().map(|elf| elf.row);
+        let cols = self.elves.iter().map(|elf| elf.col);
+        (
+            rows.clone().min().unwrap_or(0)..=rows.max().unwrap_or(-1),
+            cols.clone().min().unwrap_or(0)..=cols.max().unwrap_or(-1),
+        )
+    }
+
+    // Empty tiles in the smallest rectangle containing every elf
+    pub fn empty_ground(&self) -> usize {
+        let (rows, cols) = self.bounds();
+        let area = rows.count() * cols.count();
+        area - self.elves.len()
+    }
+}
+
+impl Simulation for Grove {
+    // How many elves moved
+    type Event = usize;
+
+    // Finishes once a round goes by where nobody moves
+    fn step(&mut self) -> ControlFlow<(), usize> {
+        match self.round() {
+            0 => ControlFlow::Break(()),
+            moved => ControlFlow::Continue(moved),
+        }
+    }
+}
+
+impl Renderable for Grove {
+    fn x_range(&self) -> RangeInclusive<i64> {
+        self.bounds().1
+    }
+
+    fn y_range(&self) -> RangeInclusive<i64> {
+        self.bounds().0
+    }
+
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        if self.elves.contains(&Point { row: y, col: x }) {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use common::answer::Answer;
+use day23::{Grove, Point};
+
+fn elves(input: &str) -> HashSet<Point> {
+    Grove::parse(input).unwrap().elves().clone()
+}
+
+#[test]
+fn small_example() {
+    let mut grove = Grove::parse(".....\n..##.\n..#..\n.....\n..##.\n.....\n").unwrap();
+    // The elves in the middle and bottom left both propose the same tile, so
+    // they stay put
+    assert_eq!(grove.round(), 3);
+    assert_eq!(
+        grove.elves(),
+        &elves("..##.\n.....\n..#..\n...#.\n..#..\n.....\n")
+    );
+
+    grove.round();
+    grove.round();
+    assert_eq!(
+        grove.elves(),
+        &elves("..#..\n....#\n#....\n....#\n.....\n..#..\n")
+    );
+
+    // Nobody has any neighbors left to move away from
+    assert_eq!(grove.round(), 0);
+}
+
+#[test]
+fn example_empty_ground_after_ten_rounds() {
+    let mut grove = Grove::parse(day23::EXAMPLE).unwrap();
+    for _ in 0..10 {
+        grove.round();
+    }
+    assert_eq!(grove.empty_ground(), 110);
+}
+
+#[test]
+fn example_first_round_without_movement() {
+    let mut grove = Grove::parse(day23::EXAMPLE).unwrap();
+    let rounds = (1..).find(|_| grove.round() == 0).unwrap();
+    assert_eq!(rounds, 20);
+}
+
+#[test]
+fn example_answers() {
+    assert_eq!(
+        day23::solve_part1(day23::EXAMPLE).unwrap(),
+        Answer::from(110_usize)
+    );
+    assert_eq!(
+        day23::solve_part2(day23::EXAMPLE).unwrap(),
+        Answer::from(20_usize)
+    );
+}
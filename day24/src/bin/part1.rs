@@ -0,0 +1,26 @@
+use clap::Parser;
+use cli::InputArgs;
+use day24::Valley;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day24::EXAMPLE)?;
+    let valley = Valley::parse(&input)?;
+    println!("Blizzards repeat every {} minutes", valley.period());
+
+    let minutes = valley
+        .crossing_time(valley.start(), valley.end(), 0)
+        .ok_or_else(|| eyre::eyre!("no way through the valley"))?;
+    println!("Reached the end after {minutes} minutes");
+
+    Ok(())
+}
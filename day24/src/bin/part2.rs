@@ -0,0 +1,34 @@
+use clap::Parser;
+use cli::InputArgs;
+use day24::Valley;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day24::EXAMPLE)?;
+    let valley = Valley::parse(&input)?;
+
+    let trips = [
+        ("end", valley.start(), valley.end()),
+        ("start", valley.end(), valley.start()),
+        ("end", valley.start(), valley.end()),
+    ];
+
+    let mut minutes = 0;
+    for (name, from, to) in trips {
+        minutes += valley
+            .crossing_time(from, to, minutes)
+            .ok_or_else(|| eyre::eyre!("no way through the valley"))?;
+        println!("Reached the {name} after {minutes} minutes");
+    }
+
+    Ok(())
+}
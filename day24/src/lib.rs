@@ -0,0 +1,179 @@
+use common::{
+    answer::Answer,
+    grid::{Direction, Grid, Position},
+    modular::lcm,
+    pathfinding::bfs,
+};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let valley = Valley::parse(input)?;
+    let minutes = valley
+        .crossing_time(valley.start(), valley.end(), 0)
+        .ok_or_else(|| eyre::eyre!("no way through the valley"))?;
+    Ok(minutes.into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let valley = Valley::parse(input)?;
+    let trips = [
+        (valley.start(), valley.end()),
+        (valley.end(), valley.start()),
+        (valley.start(), valley.end()),
+    ];
+
+    let mut minutes = 0;
+    for (from, to) in trips {
+        minutes += valley
+            .crossing_time(from, to, minutes)
+            .ok_or_else(|| eyre::eyre!("no way through the valley"))?;
+    }
+
+    Ok(minutes.into())
+}
+
+#[derive(Debug, Clone)]
+pub struct Valley {
+    walls: Grid<bool>,
+    start: Position,
+    end: Position,
+    // Where the blizzards are at each minute, until they start repeating
+    blizzards: Vec<Grid<bool>>,
+}
+
+impl Valley {
+    pub fn parse(input: &str) -> eyre::Result<Self> {
+        let tiles = Grid::parse(input)?;
+        let (width, height) = (tiles.width(), tiles.height());
+        // Everything below assumes there's at least one tile inside the walls
+        if width < 3 || height < 3 {
+            eyre::bail!("valley is too small: {width}x{height}");
+        }
+
+        // The openings can't be in the corners, where there'd be nowhere
+        // to go but back out
+        let opening = |row: usize| -> eyre::Result<Position> {
+            let mut openings = (1..width - 1)
+                .map(|col| Position { row, col })
+                .filter(|&position| tiles[position] == b'.');
+            match (openings.next(), openings.next()) {
+                (Some(opening), None) => Ok(opening),
+                _ => eyre::bail!("expected one opening in row {}", row + 1),
+            }
+        };
+        let start = opening(0)?;
+        let end = opening(height - 1)?;
+
+        // Blizzards by their starting position inside the walls
+        let mut initial_blizzards = vec![];
+        for position in tiles.positions() {
+            let is_border = position.row == 0
+                || position.row == height - 1
+                || position.col == 0
+                || position.col == width - 1;
+            let tile = tiles[position];
+            let direction = match tile {
+                b'^' => Direction::Up,
+                b'>' => Direction::Right,
+                b'v' => Direction::Down,
+                b'<' => Direction::Left,
+                b'.' if !is_border || position == start || position == end => continue,
+                b'#' if is_border => continue,
+                b'.' => eyre::bail!(
+                    "gap in the wall at row {}, column {}",
+                    position.row + 1,
+                    position.col + 1
+                ),
+                _ => eyre::bail!(
+                    "invalid tile {:?} at row {}, column {}",
+                    tile as char,
+                    position.row + 1,
+                    position.col + 1
+                ),
+            };
+
+            if is_border {
+                eyre::bail!("blizzard in the wall");
+            }
+
+            initial_blizzards.push((position.row - 1, position.col - 1, direction));
+        }
+
+        // Blizzards wrap around inside the walls, so they all line up again
+        // after this many minutes
+        let (inner_width, inner_height) = (width - 2, height - 2);
+        let period = lcm(inner_width as u64, inner_height as u64) as usize;
+
+        let blizzards = (0..period)
+            .map(|minute| {
+                let mut blizzards = Grid::new(width, vec![false; width * height]);
+                for &(row, col, direction) in &initial_blizzards {
+                    let (row_offset, col_offset) = direction.offset();
+                    let row = (row as isize + row_offset * minute as isize)
+                        .rem_euclid(inner_height as isize);
+                    let col = (col as isize + col_offset * minute as isize)
+                        .rem_euclid(inner_width as isize);
+                    let position = Position {
+                        row: row as usize + 1,
+                        col: col as usize + 1,
+                    };
+                    blizzards[position] = true;
+                }
+
+                blizzards
+            })
+            .collect();
+
+        Ok(Self {
+            walls: tiles.map(|tile| tile == b'#'),
+            start,
+            end,
+            blizzards,
+        })
+    }
+
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    pub fn period(&self) -> usize {
+        self.blizzards.len()
+    }
+
+    pub fn is_clear(&self, position: Position, minute: usize) -> bool {
+        !self.walls[position] && !self.blizzards[minute % self.period()][position]
+    }
+
+    /// The fewest minutes needed to get from `from` to `to`, setting off
+    /// at minute `start_minute`. Each state in the search is a position
+    /// and a minute within the blizzards' period.
+    pub fn crossing_time(
+        &self,
+        from: Position,
+        to: Position,
+        start_minute: usize,
+    ) -> Option<usize> {
+        let period = self.period();
+        let path = bfs(
+            (from, start_minute % period),
+            |&(position, minute)| {
+                let next_minute = (minute + 1) % period;
+                Direction::ALL
+                    .iter()
+                    .filter_map(|direction| self.walls.offset(position, direction.offset()))
+                    .chain([position])
+                    .filter(|&next| self.is_clear(next, next_minute))
+                    .map(|next| (next, next_minute))
+                    .collect::<Vec<_>>()
+            },
+            |&(position, _)| position == to,
+        )?;
+
+        Some(path.len() - 1)
+    }
+}
@@ -0,0 +1,77 @@
+use common::answer::Answer;
+use day24::Valley;
+
+#[test]
+fn example_crossing() {
+    let valley = Valley::parse(day24::EXAMPLE).unwrap();
+    assert_eq!(valley.period(), 12);
+    assert_eq!(
+        valley.crossing_time(valley.start(), valley.end(), 0),
+        Some(18)
+    );
+}
+
+#[test]
+fn example_round_trip() {
+    let valley = Valley::parse(day24::EXAMPLE).unwrap();
+    assert_eq!(
+        valley.crossing_time(valley.end(), valley.start(), 18),
+        Some(23)
+    );
+    assert_eq!(
+        valley.crossing_time(valley.start(), valley.end(), 41),
+        Some(13)
+    );
+}
+
+#[test]
+fn example_answers() {
+    assert_eq!(
+        day24::solve_part1(day24::EXAMPLE).unwrap(),
+        Answer::from(18_usize)
+    );
+    assert_eq!(
+        day24::solve_part2(day24::EXAMPLE).unwrap(),
+        Answer::from(54_usize)
+    );
+}
+
+#[test]
+fn smallest_valley() {
+    let valley = Valley::parse("#.#\n#.#\n#.#\n").unwrap();
+    assert_eq!(
+        valley.crossing_time(valley.start(), valley.end(), 0),
+        Some(2)
+    );
+}
+
+#[test]
+fn degenerate_valleys() {
+    let cases = [
+        ("#.\n#.\n", "valley is too small: 2x2"),
+        ("#.#\n#.#\n", "valley is too small: 3x2"),
+        ("#\n#\n#\n", "valley is too small: 1x3"),
+        ("...\n...\n...\n", "gap in the wall at row 1, column 1"),
+    ];
+    for (input, expected) in cases {
+        let error = Valley::parse(input).unwrap_err();
+        assert!(error.to_string().contains(expected), "{input:?}: {error}");
+    }
+}
+
+#[test]
+fn opening_in_corner() {
+    let error = Valley::parse(".###\n#..#\n##.#\n").unwrap_err();
+    assert!(error.to_string().contains("opening in row 1"), "{error}");
+}
+
+#[test]
+fn gap_in_wall() {
+    let error = Valley::parse("#.##\n...#\n##.#\n").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("gap in the wall at row 2, column 1"),
+        "{error}"
+    );
+}
@@ -0,0 +1,23 @@
+use clap::Parser;
+use cli::InputArgs;
+use day25::Snafu;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    let input = args.input.read(day25::EXAMPLE)?;
+    let numbers: Vec<Snafu> = common::diagnostics::parse_lines(&input)?;
+
+    let total = day25::sum(&numbers).ok_or_else(|| eyre::eyre!("sum overflowed"))?;
+    println!("Total: {} ({total})", total.0);
+
+    Ok(())
+}
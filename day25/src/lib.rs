@@ -0,0 +1,118 @@
+use std::{fmt::Display, str::FromStr};
+
+use common::{answer::Answer, diagnostics::parse_lines};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let numbers: Vec<Snafu> = parse_lines(input)?;
+    let total = sum(&numbers).ok_or_else(|| eyre::eyre!("sum overflowed"))?;
+    Ok(total.to_string().into())
+}
+
+pub fn solve_part2(_input: &str) -> eyre::Result<Answer> {
+    eyre::bail!("day 25 has no part 2");
+}
+
+pub fn sum(numbers: &[Snafu]) -> Option<Snafu> {
+    numbers
+        .iter()
+        .try_fold(0i64, |total, number| total.checked_add(number.0))
+        .map(Snafu)
+}
+
+// A number written in balanced base 5, where each digit is one of `=`
+// (-2), `-` (-1), `0`, `1`, or `2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Snafu(pub i64);
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Self {
+        Snafu(value)
+    }
+}
+
+impl From<Snafu> for i64 {
+    fn from(value: Snafu) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseSnafuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseSnafuError::Empty);
+        }
+
+        // Partial values can go past the range of i64 even if the whole
+        // number doesn't, since later digits can be negative
+        let mut value: i128 = 0;
+        for c in s.chars() {
+            let digit = match c {
+                '=' => -2,
+                '-' => -1,
+                '0' => 0,
+                '1' => 1,
+                '2' => 2,
+                c => return Err(ParseSnafuError::InvalidDigit(c)),
+            };
+
+            value = value
+                .checked_mul(5)
+                .and_then(|value| value.checked_add(digit))
+                .ok_or(ParseSnafuError::Overflow)?;
+        }
+
+        let value = value.try_into().map_err(|_| ParseSnafuError::Overflow)?;
+        Ok(Snafu(value))
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0");
+        }
+
+        // Widened so the carry can't overflow at the edges of i64
+        let mut value = i128::from(self.0);
+        let mut digits = vec![];
+        while value != 0 {
+            let (digit, c) = match value.rem_euclid(5) {
+                0 => (0, '0'),
+                1 => (1, '1'),
+                2 => (2, '2'),
+                3 => (-2, '='),
+                _ => (-1, '-'),
+            };
+
+            digits.push(c);
+            value = (value - digit) / 5;
+        }
+
+        let digits: String = digits.iter().rev().collect();
+        write!(f, "{digits}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSnafuError {
+    Empty,
+    InvalidDigit(char),
+    Overflow,
+}
+
+impl Display for ParseSnafuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseSnafuError::Empty => write!(f, "empty SNAFU number"),
+            ParseSnafuError::InvalidDigit(c) => write!(f, "invalid SNAFU digit {c:?}"),
+            ParseSnafuError::Overflow => write!(f, "SNAFU number is too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseSnafuError {}
@@ -0,0 +1,63 @@
+use day25::{ParseSnafuError, Snafu};
+
+const EXAMPLES: &[(i64, &str)] = &[
+    (1, "1"),
+    (2, "2"),
+    (3, "1="),
+    (4, "1-"),
+    (5, "10"),
+    (6, "11"),
+    (7, "12"),
+    (8, "2="),
+    (9, "2-"),
+    (10, "20"),
+    (15, "1=0"),
+    (20, "1-0"),
+    (2022, "1=11-2"),
+    (12345, "1-0---0"),
+    (314159265, "1121-1110-1=0"),
+];
+
+#[test]
+fn examples() {
+    for &(decimal, snafu) in EXAMPLES {
+        assert_eq!(snafu.parse(), Ok(Snafu(decimal)), "{snafu}");
+        assert_eq!(Snafu(decimal).to_string(), snafu, "{decimal}");
+    }
+}
+
+#[test]
+fn example_sum() {
+    assert_eq!(
+        day25::solve_part1(day25::EXAMPLE).unwrap().to_string(),
+        "2=-1=0"
+    );
+}
+
+// Every value printed as SNAFU should parse back to itself, including
+// negative numbers and the extremes of i64
+#[test]
+fn round_trip() {
+    let edges = [i64::MIN, i64::MIN + 1, i64::MAX - 1, i64::MAX];
+    let values = (-10_000..=10_000)
+        .chain((0..63).flat_map(|power| [1i64 << power, -(1i64 << power)]))
+        .chain(edges);
+
+    for value in values {
+        let snafu = Snafu(value).to_string();
+        assert_eq!(snafu.parse(), Ok(Snafu(value)), "{value} as {snafu}");
+    }
+}
+
+#[test]
+fn invalid() {
+    assert_eq!("".parse::<Snafu>(), Err(ParseSnafuError::Empty));
+    assert_eq!(
+        "12x".parse::<Snafu>(),
+        Err(ParseSnafuError::InvalidDigit('x'))
+    );
+    assert_eq!(
+        "2".repeat(30).parse::<Snafu>(),
+        Err(ParseSnafuError::Overflow)
+    );
+}
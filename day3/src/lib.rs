@@ -0,0 +1,394 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use common::answer::Answer;
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+/// Which items' priorities to sum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Mode {
+    /// The item in both compartments of each rucksack (part 1)
+    Compartments,
+    /// The badge item carried by every rucksack in each group (part 2)
+    Groups,
+}
+
+pub const COMPARTMENTS: usize = 2;
+
+pub const GROUP_SIZE: usize = 3;
+
+pub fn solve_part1(input: &str) -> anyhow::Result<Answer> {
+    let total_priority = compartment_priorities(input, COMPARTMENTS, &Priorities::default())?;
+    Ok(total_priority.into())
+}
+
+pub fn solve_part2(input: &str) -> anyhow::Result<Answer> {
+    let total_priority = badge_priorities(input, GROUP_SIZE, &Priorities::default())?;
+    Ok(total_priority.into())
+}
+
+pub fn compartment_priorities(
+    input: &str,
+    compartments: usize,
+    priorities: &Priorities,
+) -> anyhow::Result<u64> {
+    compartment_priorities_with(input, compartments, priorities, Intersection::default())
+}
+
+/// Like [`compartment_priorities`], but with an explicit intersection
+/// strategy
+pub fn compartment_priorities_with(
+    input: &str,
+    compartments: usize,
+    priorities: &Priorities,
+    intersection: Intersection,
+) -> anyhow::Result<u64> {
+    anyhow::ensure!(compartments > 0, "there must be at least 1 compartment");
+
+    let mut total_priority = 0;
+    for (i, line) in input.lines().enumerate() {
+        let rucksack = Rucksack::parse(line, compartments)
+            .map_err(|error| error.context(format!("line {}", i + 1)))?;
+        total_priority += common_item(rucksack.compartments(), intersection)
+            .and_then(|item| priorities.get(item))
+            .map_err(|error| error.context(format!("line {}", i + 1)))?;
+    }
+
+    Ok(total_priority)
+}
+
+/// Everything found in each rucksack, for `--report`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<'a> {
+    pub rucksacks: Vec<RucksackReport<'a>>,
+    /// How many rucksacks each item was duplicated in
+    pub histogram: BTreeMap<char, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackReport<'a> {
+    /// The rucksack's 1-based line number
+    pub line: usize,
+    pub compartments: Vec<&'a str>,
+    /// Each item that's in every compartment, with its priority
+    pub duplicates: Vec<(char, u64)>,
+}
+
+pub fn report<'a>(
+    input: &'a str,
+    compartments: usize,
+    priorities: &Priorities,
+    intersection: Intersection,
+) -> anyhow::Result<Report<'a>> {
+    anyhow::ensure!(compartments > 0, "there must be at least 1 compartment");
+
+    let mut report = Report {
+        rucksacks: vec![],
+        histogram: BTreeMap::new(),
+    };
+    for (i, line) in input.lines().enumerate() {
+        let rucksack = Rucksack::parse(line, compartments)
+            .map_err(|error| error.context(format!("line {}", i + 1)))?;
+        let duplicates = rucksack
+            .duplicate_items(intersection)
+            .into_iter()
+            .map(|item| Ok((item, priorities.get(item)?)))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|error| error.context(format!("line {}", i + 1)))?;
+
+        for &(item, _) in &duplicates {
+            *report.histogram.entry(item).or_default() += 1;
+        }
+        report.rucksacks.push(RucksackReport {
+            line: i + 1,
+            compartments: rucksack.compartments,
+            duplicates,
+        });
+    }
+
+    Ok(report)
+}
+
+impl std::fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for rucksack in &self.rucksacks {
+            write!(
+                f,
+                "line {}: {} ->",
+                rucksack.line,
+                rucksack.compartments.join(" | ")
+            )?;
+            if rucksack.duplicates.is_empty() {
+                write!(f, " (none)")?;
+            }
+            for (item, priority) in &rucksack.duplicates {
+                write!(f, " {item} ({priority})")?;
+            }
+            writeln!(f)?;
+        }
+
+        let mut histogram: Vec<_> = self.histogram.iter().collect();
+        histogram.sort_by_key(|&(_, &count)| std::cmp::Reverse(count));
+
+        writeln!(f)?;
+        writeln!(f, "duplicated items:")?;
+        for (item, count) in histogram {
+            writeln!(f, "{item} {count:4} {}", "#".repeat(*count))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// One rucksack, split into compartments with the same number of items
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack<'a> {
+    compartments: Vec<&'a str>,
+}
+
+impl<'a> Rucksack<'a> {
+    /// Split `items` into `compartments` parts. Fails if the items can't be
+    /// split evenly.
+    pub fn parse(items: &'a str, compartments: usize) -> anyhow::Result<Self> {
+        anyhow::ensure!(compartments > 0, "there must be at least 1 compartment");
+
+        let mut boundaries: Vec<usize> = items.char_indices().map(|(index, _)| index).collect();
+        let count = boundaries.len();
+        anyhow::ensure!(
+            count % compartments == 0,
+            "{count} items can't be split evenly into {compartments} compartments"
+        );
+
+        boundaries.push(items.len());
+        let compartment_size = count / compartments;
+        let compartments = (0..compartments)
+            .map(|n| {
+                let start = boundaries[n * compartment_size];
+                let end = boundaries[(n + 1) * compartment_size];
+                &items[start..end]
+            })
+            .collect();
+        Ok(Self { compartments })
+    }
+
+    pub fn compartments(&self) -> &[&'a str] {
+        &self.compartments
+    }
+
+    /// Every item that's in all of the compartments, in sorted order
+    pub fn duplicate_items(&self, intersection: Intersection) -> Vec<char> {
+        common_items(&self.compartments, intersection)
+    }
+
+    /// The total priority of the duplicated items. The puzzle promises one
+    /// duplicate per rucksack, but this counts every one there is.
+    pub fn priority_sum(
+        &self,
+        priorities: &Priorities,
+        intersection: Intersection,
+    ) -> anyhow::Result<u64> {
+        self.duplicate_items(intersection)
+            .into_iter()
+            .map(|item| priorities.get(item))
+            .sum()
+    }
+}
+
+pub fn badge_priorities(
+    input: &str,
+    group_size: usize,
+    priorities: &Priorities,
+) -> anyhow::Result<u64> {
+    badge_priorities_with(input, group_size, priorities, Intersection::default())
+}
+
+/// Like [`badge_priorities`], but with an explicit intersection strategy
+pub fn badge_priorities_with(
+    input: &str,
+    group_size: usize,
+    priorities: &Priorities,
+    intersection: Intersection,
+) -> anyhow::Result<u64> {
+    anyhow::ensure!(group_size > 0, "group size must be at least 1");
+
+    let lines: Vec<&str> = input.lines().collect();
+    anyhow::ensure!(
+        lines.len() % group_size == 0,
+        "{} rucksacks can't be split into groups of {group_size}",
+        lines.len()
+    );
+
+    let mut total_priority = 0;
+    for (i, group) in lines.chunks(group_size).enumerate() {
+        let first_line = i * group_size + 1;
+        let badge_priority =
+            common_item(group, intersection).and_then(|badge| priorities.get(badge));
+        total_priority += badge_priority.map_err(|error| {
+            error.context(format!(
+                "group on lines {first_line}-{}",
+                first_line + group_size - 1
+            ))
+        })?;
+    }
+
+    Ok(total_priority)
+}
+
+/// How to find the items common to several lists of items
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Intersection {
+    /// AND together a 64-bit mask per list, with one bit per letter. Lists
+    /// with items other than a-z and A-Z fall back to `btree-set`.
+    #[default]
+    Bitmask,
+    /// Intersect a `BTreeSet` of the items in each list
+    #[value(name = "btree-set")]
+    BTreeSet,
+}
+
+/// Find the one item that's in every one of `lists`
+fn common_item(lists: &[&str], intersection: Intersection) -> anyhow::Result<char> {
+    // Skip building the list of items when there's exactly one bit set
+    if intersection == Intersection::Bitmask {
+        if let Some(mask) = common_mask(lists) {
+            if mask.count_ones() == 1 {
+                return Ok(priority_item(mask.trailing_zeros()));
+            }
+        }
+    }
+
+    let common = common_items(lists, intersection);
+    match &common[..] {
+        [item] => Ok(*item),
+        [] => anyhow::bail!("no item is in common"),
+        items => {
+            let items: String = items.iter().collect();
+            anyhow::bail!("expected one item in common, found {items:?}")
+        }
+    }
+}
+
+/// Find every item that's in all of `lists`, in sorted order
+pub fn common_items(lists: &[&str], intersection: Intersection) -> Vec<char> {
+    match intersection {
+        Intersection::Bitmask => match common_mask(lists) {
+            Some(common) => mask_items(common),
+            None => common_items(lists, Intersection::BTreeSet),
+        },
+        Intersection::BTreeSet => {
+            let mut lists = lists.iter();
+            let mut common: BTreeSet<char> = match lists.next() {
+                Some(first) => first.chars().collect(),
+                None => BTreeSet::new(),
+            };
+            for list in lists {
+                let items: BTreeSet<char> = list.chars().collect();
+                common = common.intersection(&items).copied().collect();
+            }
+
+            common.into_iter().collect()
+        }
+    }
+}
+
+/// Set the bit for each item's default priority, or return `None` if there's
+/// an item without one
+fn item_mask(list: &str) -> Option<u64> {
+    let mut mask = 0;
+    for item in list.chars() {
+        mask |= 1 << default_priority(item)?;
+    }
+
+    Some(mask)
+}
+
+/// AND together the masks of each of `lists`, or return `None` if any of
+/// them has an item without a default priority
+fn common_mask(lists: &[&str]) -> Option<u64> {
+    let Some((first, rest)) = lists.split_first() else {
+        return Some(0);
+    };
+    rest.iter().try_fold(item_mask(first)?, |common, list| {
+        Some(common & item_mask(list)?)
+    })
+}
+
+/// The item with the default priority `priority`, the inverse of
+/// [`default_priority`]
+fn priority_item(priority: u32) -> char {
+    match priority {
+        1..=26 => char::from(b'a' + (priority - 1) as u8),
+        27..=52 => char::from(b'A' + (priority - 27) as u8),
+        _ => unreachable!("no item has priority {priority}"),
+    }
+}
+
+fn mask_items(mask: u64) -> Vec<char> {
+    // Uppercase first, to match the order of a `BTreeSet<char>`
+    (27..=52)
+        .chain(1..=26)
+        .filter(|&priority| mask & (1 << priority) != 0)
+        .map(priority_item)
+        .collect()
+}
+
+/// Item priorities, from a custom table with the puzzle's priorities as a
+/// fallback
+#[derive(Debug, Default, Clone)]
+pub struct Priorities {
+    custom: HashMap<char, u64>,
+}
+
+impl Priorities {
+    /// Parse a table with one `item priority` pair per line, like `a 1`
+    pub fn parse(table: &str) -> anyhow::Result<Self> {
+        let mut custom = HashMap::new();
+        for (i, line) in table.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut columns = line.split_whitespace();
+            let (Some(item), Some(priority), None) =
+                (columns.next(), columns.next(), columns.next())
+            else {
+                anyhow::bail!(
+                    "line {}: expected an item and a priority, got {line:?}",
+                    i + 1
+                );
+            };
+
+            let mut chars = item.chars();
+            let (Some(item), None) = (chars.next(), chars.next()) else {
+                anyhow::bail!(
+                    "line {}: expected a single-character item, got {item:?}",
+                    i + 1
+                );
+            };
+            let priority: u64 = priority.parse().map_err(|error| {
+                anyhow::anyhow!("line {}: invalid priority {priority:?}: {error}", i + 1)
+            })?;
+
+            custom.insert(item, priority);
+        }
+
+        Ok(Self { custom })
+    }
+
+    pub fn get(&self, item: char) -> anyhow::Result<u64> {
+        match self.custom.get(&item) {
+            Some(&priority) => Ok(priority),
+            None => default_priority(item)
+                .map(u64::from)
+                .ok_or_else(|| anyhow::anyhow!("no priority for item {item:?}")),
+        }
+    }
+}
+
+fn default_priority(item: char) -> Option<u8> {
+    match u8::try_from(item) {
+        Ok(item @ b'a'..=b'z') => Some(item - b'a' + 1),
+        Ok(item @ b'A'..=b'Z') => Some(item - b'A' + 27),
+        _ => None,
+    }
+}
@@ -1,35 +1,76 @@
-#![feature(iter_array_chunks)]
+use std::path::PathBuf;
 
-use std::{collections::BTreeSet, io::BufRead};
+use anyhow::Context;
+use clap::Parser;
+use cli::{InputArgs, PartArg};
+use day3::{Intersection, Mode, Priorities};
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+    #[clap(flatten)]
+    part: PartArg,
+    /// Which items to sum. Defaults to the mode for `--part`.
+    #[clap(long, value_enum)]
+    mode: Option<Mode>,
+    /// How many equal parts each rucksack is split into, for `--mode
+    /// compartments`
+    #[clap(long, default_value_t = day3::COMPARTMENTS)]
+    compartments: usize,
+    /// How many consecutive rucksacks make up a group, for `--mode groups`
+    #[clap(long, default_value_t = day3::GROUP_SIZE)]
+    group_size: usize,
+    /// How to find the items rucksacks have in common
+    #[clap(long, value_enum, default_value = "bitmask")]
+    intersection: Intersection,
+    /// Print each rucksack's compartments and duplicated items, plus how
+    /// often each item was duplicated
+    #[clap(long)]
+    report: bool,
+    /// A file with one `item priority` pair per line, overriding the
+    /// puzzle's priorities for those items
+    #[clap(long)]
+    priorities: Option<PathBuf>,
+}
 
 fn main() -> anyhow::Result<()> {
-    let stdin = std::io::stdin().lock();
-
-    let mut badges: Vec<char> = vec![];
-    for [a, b, c] in stdin.lines().array_chunks() {
-        let [a, b, c] = [a?, b?, c?];
-        let a: BTreeSet<char> = a.chars().collect();
-        let b: BTreeSet<char> = b.chars().collect();
-        let c: BTreeSet<char> = c.chars().collect();
-
-        let ab: BTreeSet<char> = a.intersection(&b).copied().collect();
-        let abc = ab.intersection(&c);
-        badges.extend(abc);
-    }
+    let args = Args::parse();
 
-    let total_priority: u64 = badges
-        .iter()
-        .map(|&item| -> u64 { priority(item).into() })
-        .sum();
-    println!("{}", total_priority);
+    let input = args.input.read(day3::EXAMPLE)?;
 
-    Ok(())
-}
+    let priorities = match &args.priorities {
+        Some(path) => {
+            let table = std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            Priorities::parse(&table)
+                .with_context(|| format!("invalid priority table {}", path.display()))?
+        }
+        None => Priorities::default(),
+    };
 
-fn priority(item: char) -> u8 {
-    match u8::try_from(item) {
-        Ok(item @ b'a'..=b'z') => item - b'a' + 1,
-        Ok(item @ b'A'..=b'Z') => item - b'A' + 27,
-        _ => panic!("could not compute priority for item: {item:?}"),
+    if args.report {
+        let report = day3::report(&input, args.compartments, &priorities, args.intersection)?;
+        print!("{report}");
+        return Ok(());
     }
+
+    let mode = args.mode.unwrap_or(match args.part.part {
+        1 => Mode::Compartments,
+        _ => Mode::Groups,
+    });
+    let total_priority = match mode {
+        Mode::Compartments => day3::compartment_priorities_with(
+            &input,
+            args.compartments,
+            &priorities,
+            args.intersection,
+        )?,
+        Mode::Groups => {
+            day3::badge_priorities_with(&input, args.group_size, &priorities, args.intersection)?
+        }
+    };
+    println!("{total_priority}");
+
+    Ok(())
 }
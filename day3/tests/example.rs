@@ -0,0 +1,146 @@
+use day3::{
+    badge_priorities, common_items, compartment_priorities, compartment_priorities_with, report,
+    Intersection, Priorities,
+};
+
+#[test]
+fn example_compartments() {
+    assert_eq!(
+        compartment_priorities(day3::EXAMPLE, 2, &Priorities::default()).unwrap(),
+        157
+    );
+}
+
+#[test]
+fn example_groups() {
+    assert_eq!(
+        badge_priorities(day3::EXAMPLE, 3, &Priorities::default()).unwrap(),
+        70
+    );
+}
+
+#[test]
+fn other_group_sizes() {
+    assert_eq!(
+        badge_priorities("abc\ncde\nfgH\nHij\n", 2, &Priorities::default()).unwrap(),
+        3 + 34
+    );
+    assert!(badge_priorities("abc\nbcd\n", 2, &Priorities::default()).is_err());
+    assert!(badge_priorities(day3::EXAMPLE, 4, &Priorities::default()).is_err());
+}
+
+#[test]
+fn custom_priorities() {
+    let priorities = Priorities::parse("p 100\né 7\n").unwrap();
+    assert_eq!(priorities.get('p').unwrap(), 100);
+    assert_eq!(priorities.get('é').unwrap(), 7);
+    assert_eq!(priorities.get('L').unwrap(), 38);
+    assert!(priorities.get('ü').is_err());
+
+    // The first example rucksack shares `p`, worth 16 by default
+    let total = compartment_priorities(day3::EXAMPLE, 2, &priorities).unwrap();
+    assert_eq!(total, 157 - 16 + 100);
+
+    assert_eq!(
+        compartment_priorities("ééxyzé\n", 2, &priorities).unwrap(),
+        7
+    );
+}
+
+#[test]
+fn invalid_priority_tables() {
+    assert!(Priorities::parse("ab 1\n").is_err());
+    assert!(Priorities::parse("a\n").is_err());
+    assert!(Priorities::parse("a one\n").is_err());
+}
+
+#[test]
+fn more_compartments() {
+    let priorities = Priorities::default();
+    assert_eq!(
+        compartment_priorities("abbcbd\n", 3, &priorities).unwrap(),
+        2
+    );
+    assert!(compartment_priorities("abcb\n", 4, &priorities).is_err());
+
+    let error = compartment_priorities("abacad\nabcab\n", 3, &priorities).unwrap_err();
+    let message = format!("{error}");
+    assert!(message.contains("line 2"), "{message}");
+    assert!(message.contains("5 items"), "{message}");
+}
+
+#[test]
+fn intersections_agree() {
+    let cases: [&[&str]; 5] = [
+        &["vJrwpWtwJgWr", "hcsFMMfFFhFp"],
+        &["abcXYZ", "cbaZYX", "zyxCBA"],
+        &["abc", "def"],
+        &["aé", "éa"],
+        &["AbcD", "AxyD"],
+    ];
+    for lists in cases {
+        assert_eq!(
+            common_items(lists, Intersection::Bitmask),
+            common_items(lists, Intersection::BTreeSet),
+            "{lists:?}"
+        );
+    }
+
+    assert_eq!(
+        common_items(&["aé", "éa"], Intersection::Bitmask),
+        ['a', 'é']
+    );
+    assert_eq!(
+        common_items(&["AbcD", "AxyD"], Intersection::Bitmask),
+        ['A', 'D']
+    );
+}
+
+#[test]
+fn one_item_in_common() {
+    let priorities = Priorities::default();
+    for intersection in [Intersection::Bitmask, Intersection::BTreeSet] {
+        let total = |input| {
+            compartment_priorities_with(input, 2, &priorities, intersection)
+                .map_err(|error| format!("{error:#}"))
+        };
+
+        assert_eq!(total("aZbZ\nAzAy\n"), Ok(52 + 27));
+
+        let error = total("abcd\n").unwrap_err();
+        assert!(error.contains("no item is in common"), "{error}");
+        let error = total("abab\n").unwrap_err();
+        assert!(error.contains("found \"ab\""), "{error}");
+    }
+}
+
+#[test]
+fn example_report() {
+    let priorities = Priorities::default();
+    let report = report(day3::EXAMPLE, 2, &priorities, Intersection::Bitmask).unwrap();
+
+    assert_eq!(report.rucksacks.len(), 6);
+    assert_eq!(
+        report.rucksacks[0].compartments,
+        ["vJrwpWtwJgWr", "hcsFMMfFFhFp"]
+    );
+    assert_eq!(report.rucksacks[0].duplicates, [('p', 16)]);
+
+    let total: u64 = report
+        .rucksacks
+        .iter()
+        .flat_map(|rucksack| &rucksack.duplicates)
+        .map(|&(_, priority)| priority)
+        .sum();
+    assert_eq!(total, 157);
+    assert_eq!(report.histogram.values().sum::<usize>(), 6);
+}
+
+#[test]
+fn report_lists_every_duplicate() {
+    let priorities = Priorities::default();
+    let report = report("abXabY\nxy\n", 2, &priorities, Intersection::Bitmask).unwrap();
+    assert_eq!(report.rucksacks[0].duplicates, [('a', 1), ('b', 2)]);
+    assert_eq!(report.rucksacks[1].duplicates, []);
+    assert!(report.to_string().contains("line 2: x | y -> (none)"));
+}
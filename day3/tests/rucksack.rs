@@ -0,0 +1,47 @@
+use day3::{Intersection, Priorities, Rucksack};
+
+#[test]
+fn compartments() {
+    let rucksack = Rucksack::parse("vJrwpWtwJgWrhcsFMMfFFhFp", 2).unwrap();
+    assert_eq!(rucksack.compartments(), ["vJrwpWtwJgWr", "hcsFMMfFFhFp"]);
+
+    let rucksack = Rucksack::parse("abcdef", 3).unwrap();
+    assert_eq!(rucksack.compartments(), ["ab", "cd", "ef"]);
+
+    let rucksack = Rucksack::parse("", 2).unwrap();
+    assert_eq!(rucksack.compartments(), ["", ""]);
+}
+
+#[test]
+fn odd_length_lines() {
+    let error = Rucksack::parse("abcab", 2).unwrap_err();
+    assert!(error.to_string().contains("5 items"), "{error}");
+
+    // Multi-byte items count as one item each
+    assert!(Rucksack::parse("éé", 2).is_ok());
+    assert!(Rucksack::parse("éa", 3).is_err());
+    assert!(Rucksack::parse("abc", 0).is_err());
+}
+
+#[test]
+fn multiple_duplicates() {
+    let priorities = Priorities::default();
+    for intersection in [Intersection::Bitmask, Intersection::BTreeSet] {
+        let rucksack = Rucksack::parse("aBcxyzcBa", 3).unwrap();
+        assert_eq!(rucksack.duplicate_items(intersection), [] as [char; 0]);
+
+        let rucksack = Rucksack::parse("abZbaZ", 2).unwrap();
+        assert_eq!(rucksack.duplicate_items(intersection), ['Z', 'a', 'b']);
+        assert_eq!(
+            rucksack.priority_sum(&priorities, intersection).unwrap(),
+            52 + 1 + 2
+        );
+    }
+}
+
+#[test]
+fn no_duplicates() {
+    let rucksack = Rucksack::parse("abcdef", 2).unwrap();
+    let sum = rucksack.priority_sum(&Priorities::default(), Intersection::Bitmask);
+    assert_eq!(sum.unwrap(), 0);
+}
@@ -0,0 +1,136 @@
+use anyhow::Context;
+use std::ops::RangeInclusive;
+
+use common::{answer::Answer, diagnostics::ParseError, ranges};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+/// Which kind of overlap to count
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Mode {
+    /// One range fully contains the other (part 1)
+    Complete,
+    /// The ranges share at least one section (part 2)
+    Partial,
+    /// Count both kinds
+    Both,
+}
+
+pub fn solve_part1(input: &str) -> anyhow::Result<Answer> {
+    let complete_overlaps = count_complete_overlaps(input)?;
+    Ok(complete_overlaps.into())
+}
+
+pub fn solve_part2(input: &str) -> anyhow::Result<Answer> {
+    let partial_overlaps = count_partial_overlaps(input)?;
+    Ok(partial_overlaps.into())
+}
+
+pub fn count_complete_overlaps(input: &str) -> anyhow::Result<u64> {
+    count_overlaps(input, complete_overlap)
+}
+
+pub fn count_partial_overlaps(input: &str) -> anyhow::Result<u64> {
+    count_overlaps(input, partial_overlap)
+}
+
+fn count_overlaps(
+    input: &str,
+    overlaps: impl Fn(&RangeInclusive<u64>, &RangeInclusive<u64>) -> bool,
+) -> anyhow::Result<u64> {
+    let mut count = 0;
+    for pair in parse_pairs(input)? {
+        if overlaps(&pair.first, &pair.second) {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// One line of the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair<'a> {
+    pub line: &'a str,
+    pub first: RangeInclusive<u64>,
+    pub second: RangeInclusive<u64>,
+}
+
+impl Pair<'_> {
+    /// The sections both elves were assigned, if any
+    pub fn overlap(&self) -> Option<RangeInclusive<u64>> {
+        ranges::intersection(&self.first, &self.second)
+    }
+}
+
+/// Parse each non-blank line of `input` as a pair of ranges
+pub fn parse_pairs(input: &str) -> Result<Vec<Pair<'_>>, ParseError> {
+    let mut pairs = vec![];
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (first, second) = parse_pair(line)
+            .map_err(|error| ParseError::at(input, line, format_args!("{error:#}")))?;
+        pairs.push(Pair {
+            line,
+            first,
+            second,
+        });
+    }
+
+    Ok(pairs)
+}
+
+/// Format a range the way [`parse_range`] reads it, like `3-7`, `5`, or `3-`
+pub fn format_range(range: &RangeInclusive<u64>) -> String {
+    match (*range.start(), *range.end()) {
+        (start, u64::MAX) => format!("{start}-"),
+        (start, end) if start == end => format!("{start}"),
+        (start, end) => format!("{start}-{end}"),
+    }
+}
+
+/// Parse a line with two comma-separated ranges, like `2-4,6-8`
+pub fn parse_pair(line: &str) -> anyhow::Result<(RangeInclusive<u64>, RangeInclusive<u64>)> {
+    let (first, second) = line.split_once(',').context("could not split pair")?;
+    let first = parse_range(first).context("invalid first range")?;
+    let second = parse_range(second).context("invalid second range")?;
+    Ok((first, second))
+}
+
+/// Parse a range of sections like `2-4`. Also accepts:
+///
+/// - Descending ranges, so `7-3` is the same as `3-7`
+/// - Single sections, so `5` is the same as `5-5`
+/// - Open-ended ranges, so `3-` covers every section from 3 up to `u64::MAX`
+pub fn parse_range(s: &str) -> anyhow::Result<RangeInclusive<u64>> {
+    let parse_section = |section: &str| {
+        section
+            .trim()
+            .parse::<u64>()
+            .with_context(|| format!("invalid section {section:?}"))
+    };
+
+    match s.split_once('-') {
+        Some((start, end)) if end.trim().is_empty() => Ok(parse_section(start)?..=u64::MAX),
+        Some((start, end)) => {
+            let start = parse_section(start)?;
+            let end = parse_section(end)?;
+            Ok(std::cmp::min(start, end)..=std::cmp::max(start, end))
+        }
+        None => {
+            let section = parse_section(s)?;
+            Ok(section..=section)
+        }
+    }
+}
+
+fn complete_overlap(first: &RangeInclusive<u64>, second: &RangeInclusive<u64>) -> bool {
+    ranges::contains(first, second) || ranges::contains(second, first)
+}
+
+fn partial_overlap(first: &RangeInclusive<u64>, second: &RangeInclusive<u64>) -> bool {
+    ranges::overlaps(first, second)
+}
@@ -1,44 +1,49 @@
-use std::{io::BufRead, ops::RangeInclusive};
+use clap::Parser;
+use cli::{InputArgs, PartArg};
+use day4::Mode;
 
-use anyhow::Context;
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+    #[clap(flatten)]
+    part: PartArg,
+    /// Which overlaps to count. Defaults to the mode for `--part`.
+    #[clap(long, value_enum)]
+    mode: Option<Mode>,
+    /// Print each pair with the sections both elves were assigned
+    #[clap(long)]
+    show_overlaps: bool,
+}
 
 fn main() -> anyhow::Result<()> {
-    let stdin = std::io::stdin().lock();
+    let args = Args::parse();
+
+    let input = args.input.read(day4::EXAMPLE)?;
 
-    let mut partial_overlaps = 0;
-    for line in stdin.lines() {
-        let line = line?;
-        let (first, second) = line.split_once(',').context("could not split pair")?;
-        let (first_a, first_b) = first
-            .split_once('-')
-            .context("could not split first range")?;
-        let (second_a, second_b) = second
-            .split_once('-')
-            .context("could not split second range")?;
-        let first = first_a.parse::<u64>()?..=first_b.parse::<u64>()?;
-        let second = second_a.parse::<u64>()?..=second_b.parse::<u64>()?;
-        if partial_overlap(&first, &second) {
-            partial_overlaps += 1;
+    if args.show_overlaps {
+        for pair in day4::parse_pairs(&input)? {
+            match pair.overlap() {
+                Some(overlap) => println!("{}: {}", pair.line, day4::format_range(&overlap)),
+                None => println!("{}: none", pair.line),
+            }
         }
+
+        return Ok(());
     }
 
-    println!("{partial_overlaps}");
+    let mode = args.mode.unwrap_or(match args.part.part {
+        1 => Mode::Complete,
+        _ => Mode::Partial,
+    });
+    match mode {
+        Mode::Complete => println!("{}", day4::count_complete_overlaps(&input)?),
+        Mode::Partial => println!("{}", day4::count_partial_overlaps(&input)?),
+        Mode::Both => {
+            println!("complete: {}", day4::count_complete_overlaps(&input)?);
+            println!("partial: {}", day4::count_partial_overlaps(&input)?);
+        }
+    }
 
     Ok(())
 }
-
-fn complete_overlap(first: &RangeInclusive<u64>, second: &RangeInclusive<u64>) -> bool {
-    // |--------------|
-    //     |-----|
-    // fs             fe
-    //     ss    se
-    // fs <= ss && fe >= se
-    (first.contains(second.start()) && first.contains(second.end()))
-        || (second.contains(first.start()) && second.contains(first.end()))
-}
-
-fn partial_overlap(first: &RangeInclusive<u64>, second: &RangeInclusive<u64>) -> bool {
-    complete_overlap(first, second)
-        || ((first.contains(second.start()) || first.contains(second.end()))
-            && (second.contains(first.start()) || second.contains(second.end())))
-}
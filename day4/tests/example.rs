@@ -0,0 +1,56 @@
+use common::diagnostics::ParseError;
+use day4::parse_range;
+
+#[test]
+fn example_overlaps() {
+    assert_eq!(day4::count_complete_overlaps(day4::EXAMPLE).unwrap(), 2);
+    assert_eq!(day4::count_partial_overlaps(day4::EXAMPLE).unwrap(), 4);
+}
+
+#[test]
+fn range_syntax() {
+    assert_eq!(parse_range("2-4").unwrap(), 2..=4);
+    assert_eq!(parse_range("7-3").unwrap(), 3..=7);
+    assert_eq!(parse_range("5").unwrap(), 5..=5);
+    assert_eq!(parse_range("3-").unwrap(), 3..=u64::MAX);
+    assert_eq!(parse_range(" 1 - 2 ").unwrap(), 1..=2);
+
+    for invalid in ["", "-", "-3", "a-4", "1-2-3", "1--2"] {
+        assert!(parse_range(invalid).is_err(), "{invalid:?}");
+    }
+}
+
+#[test]
+fn mixed_syntax_pairs() {
+    let input = "8-2,4\n10-,3-5\n5,5-\n";
+    assert_eq!(day4::count_complete_overlaps(input).unwrap(), 2);
+    assert_eq!(day4::count_partial_overlaps(input).unwrap(), 2);
+}
+
+#[test]
+fn parse_errors_have_line_numbers() {
+    let error = day4::count_partial_overlaps("2-4,6-8\n2-4;6-8\n").unwrap_err();
+    let error = error.downcast_ref::<ParseError>().unwrap();
+    assert_eq!(error.line(), 2);
+}
+
+#[test]
+fn example_overlap_ranges() {
+    let pairs = day4::parse_pairs(day4::EXAMPLE).unwrap();
+    let overlaps: Vec<Option<String>> = pairs
+        .iter()
+        .map(|pair| pair.overlap().map(|overlap| day4::format_range(&overlap)))
+        .collect();
+    assert_eq!(
+        overlaps,
+        [
+            None,
+            None,
+            Some("7".to_string()),
+            Some("3-7".to_string()),
+            Some("6".to_string()),
+            Some("4-6".to_string()),
+        ]
+    );
+    assert_eq!(day4::format_range(&(9..=u64::MAX)), "9-");
+}
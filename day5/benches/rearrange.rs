@@ -0,0 +1,124 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use day5::{Crane, CrateMover9000, CrateMover9001, Move, Stacks};
+
+const STACKS: usize = 9;
+const CRATES: usize = 1_000;
+const MOVES: usize = 1_000_000;
+
+/// A big puzzle input with only valid moves, from a fixed seed so every run
+/// does the same work
+fn synthetic_input() -> String {
+    let mut rng = 0x2022_1205_u64;
+    let mut next = |bound: usize| {
+        // xorshift64
+        rng ^= rng << 13;
+        rng ^= rng >> 7;
+        rng ^= rng << 17;
+        (rng % bound as u64) as usize
+    };
+
+    let height = CRATES / STACKS;
+    let mut input = String::new();
+    for level in 0..height {
+        let row: Vec<_> = (0..STACKS)
+            .map(|index| {
+                let name = (b'A' + ((level * STACKS + index) % 26) as u8) as char;
+                format!("[{name}]")
+            })
+            .collect();
+        input += &row.join(" ");
+        input += "\n";
+    }
+    let labels: Vec<_> = (1..=STACKS).map(|label| format!(" {label} ")).collect();
+    input += &labels.join(" ");
+    input += "\n\n";
+
+    let mut depths = vec![height; STACKS];
+    for _ in 0..MOVES {
+        let from = loop {
+            let from = next(STACKS);
+            if depths[from] > 0 {
+                break from;
+            }
+        };
+        let to = (from + 1 + next(STACKS - 1)) % STACKS;
+        let count = 1 + next(depths[from].min(20));
+        depths[from] -= count;
+        depths[to] += count;
+        input += &format!("move {count} from {} to {}\n", from + 1, to + 1);
+    }
+
+    input
+}
+
+/// How stacks used to be stored, moving each pair of stacks out of the map
+/// and back in again for every move
+fn apply_with_map(stacks: &Stacks, moves: &[Move], keep_order: bool) -> String {
+    let mut columns: BTreeMap<usize, VecDeque<char>> = (0..stacks.len())
+        .map(|index| {
+            (
+                index,
+                stacks.stack(index).unwrap().iter().copied().collect(),
+            )
+        })
+        .collect();
+
+    for mv in moves {
+        let mut from = std::mem::take(columns.entry(mv.from).or_default());
+        let mut to = std::mem::take(columns.entry(mv.to).or_default());
+
+        let popped = from.drain(from.len() - mv.count..);
+        if keep_order {
+            to.extend(popped);
+        } else {
+            to.extend(popped.rev());
+        }
+
+        columns.insert(mv.from, from);
+        columns.insert(mv.to, to);
+    }
+
+    columns
+        .values()
+        .filter_map(|column| column.back().copied())
+        .collect()
+}
+
+fn apply_with_vecs(stacks: &Stacks, moves: &[Move], crane: &dyn Crane) -> String {
+    let mut stacks = stacks.clone();
+    for mv in moves {
+        stacks.apply(mv, crane).unwrap();
+    }
+    stacks.top_crates()
+}
+
+fn rearrange(c: &mut Criterion) {
+    let input = synthetic_input();
+    let (stacks, moves) = day5::parse(&input).unwrap();
+
+    c.bench_function("crate mover 9000, map of deques", |b| {
+        b.iter(|| apply_with_map(black_box(&stacks), black_box(&moves), false))
+    });
+    c.bench_function("crate mover 9000, vecs", |b| {
+        b.iter(|| apply_with_vecs(black_box(&stacks), black_box(&moves), &CrateMover9000))
+    });
+    c.bench_function("crate mover 9001, map of deques", |b| {
+        b.iter(|| apply_with_map(black_box(&stacks), black_box(&moves), true))
+    });
+    c.bench_function("crate mover 9001, vecs", |b| {
+        b.iter(|| apply_with_vecs(black_box(&stacks), black_box(&moves), &CrateMover9001))
+    });
+
+    c.bench_function("parse", |b| {
+        b.iter_batched(
+            || input.clone(),
+            |input| day5::parse(&input).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, rearrange);
+criterion_main!(benches);
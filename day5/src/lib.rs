@@ -0,0 +1,532 @@
+#![feature(byte_slice_trim_ascii)]
+
+use std::ops::{ControlFlow, RangeInclusive};
+
+use anyhow::Context;
+use common::{answer::Answer, render::Renderable, simulation::Simulation};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> anyhow::Result<Answer> {
+    let top_crates = top_crates(input, &CrateMover9000)?;
+    Ok(top_crates.into())
+}
+
+pub fn solve_part2(input: &str) -> anyhow::Result<Answer> {
+    let top_crates = top_crates(input, &CrateMover9001)?;
+    Ok(top_crates.into())
+}
+
+/// Run every move in `input` with `crane`, then read off the top crate of
+/// each stack
+pub fn top_crates(input: &str, crane: &dyn Crane) -> anyhow::Result<String> {
+    let (mut stacks, moves) = parse(input)?;
+    for mv in &moves {
+        stacks.apply(mv, crane)?;
+    }
+
+    Ok(stacks.top_crates())
+}
+
+/// Moves crates from one stack to another
+pub trait Crane {
+    /// Move `count` crates from the top of `from` to the top of `to`
+    fn move_crates(&self, from: &mut Vec<char>, to: &mut Vec<char>, count: usize);
+
+    /// The moves that put the crates back where they were before `mv`
+    fn undo(&self, mv: &Move) -> Vec<Move>;
+}
+
+/// Moves crates one at a time, so they end up in reverse order (part 1)
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn move_crates(&self, from: &mut Vec<char>, to: &mut Vec<char>, count: usize) {
+        let popped = from.split_off(from.len() - count);
+        to.extend(popped.into_iter().rev());
+    }
+
+    fn undo(&self, mv: &Move) -> Vec<Move> {
+        // Moving them back one at a time reverses them a second time
+        vec![mv.reversed()]
+    }
+}
+
+/// Moves several crates at once, so they keep their order (part 2)
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn move_crates(&self, from: &mut Vec<char>, to: &mut Vec<char>, count: usize) {
+        let popped = from.split_off(from.len() - count);
+        to.extend(popped);
+    }
+
+    fn undo(&self, mv: &Move) -> Vec<Move> {
+        vec![mv.reversed()]
+    }
+}
+
+/// Which crane to use, by model number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CraneModel {
+    #[value(name = "9000")]
+    CrateMover9000,
+    #[value(name = "9001")]
+    CrateMover9001,
+}
+
+impl CraneModel {
+    pub fn crane(self) -> &'static dyn Crane {
+        match self {
+            CraneModel::CrateMover9000 => &CrateMover9000,
+            CraneModel::CrateMover9001 => &CrateMover9001,
+        }
+    }
+}
+
+/// The stacks of crates, with the bottom crate of each stack first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stacks {
+    columns: Vec<Vec<char>>,
+}
+
+impl Stacks {
+    /// Run `mv` with `crane`, or leave the stacks untouched if the move isn't
+    /// possible
+    pub fn apply(&mut self, mv: &Move, crane: &dyn Crane) -> Result<(), MoveError> {
+        for index in [mv.from, mv.to] {
+            if index >= self.len() {
+                return Err(MoveError::NoSuchStack {
+                    line: mv.line,
+                    stack: index + 1,
+                });
+            }
+        }
+
+        let depth = self.columns[mv.from].len();
+        if mv.count > depth {
+            return Err(MoveError::NotEnoughCrates {
+                line: mv.line,
+                count: mv.count,
+                stack: mv.from + 1,
+                depth,
+            });
+        }
+
+        if mv.from == mv.to {
+            // Either crane puts the crates right back where they were
+            return Ok(());
+        }
+
+        let (from_column, to_column) = if mv.from < mv.to {
+            let (left, right) = self.columns.split_at_mut(mv.to);
+            (&mut left[mv.from], &mut right[0])
+        } else {
+            let (left, right) = self.columns.split_at_mut(mv.from);
+            (&mut right[0], &mut left[mv.to])
+        };
+        crane.move_crates(from_column, to_column, mv.count);
+        Ok(())
+    }
+
+    /// How many stacks there are, including empty ones
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn ensure_len(&mut self, len: usize) {
+        if self.columns.len() < len {
+            self.columns.resize_with(len, Vec::new);
+        }
+    }
+
+    /// The crates in the stack at 0-based `index`, from the bottom up
+    pub fn stack(&self, index: usize) -> Option<&[char]> {
+        self.columns.get(index).map(Vec::as_slice)
+    }
+
+    pub fn top_crates(&self) -> String {
+        self.columns
+            .iter()
+            .filter_map(|column| column.last().copied())
+            .collect()
+    }
+}
+
+/// Draws the stacks in the same bracketed format as the puzzle input, so the
+/// output can be parsed again
+impl std::fmt::Display for Stacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let height = self.columns.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row = (0..self.len()).map(|index| {
+                match self.stack(index).and_then(|stack| stack.get(level)) {
+                    Some(name) => format!("[{name}]"),
+                    None => "   ".to_string(),
+                }
+            });
+            writeln!(f, "{}", row.collect::<Vec<_>>().join(" "))?;
+        }
+
+        let labels = (1..=self.len()).map(|label| format!("{label:^3}"));
+        writeln!(f, "{}", labels.collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// A move that can't be made from the current stacks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    NoSuchStack {
+        line: usize,
+        stack: usize,
+    },
+    NotEnoughCrates {
+        line: usize,
+        count: usize,
+        stack: usize,
+        depth: usize,
+    },
+}
+
+impl std::fmt::Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveError::NoSuchStack { line, stack } => {
+                write!(f, "line {line}: there is no stack {stack}")
+            }
+            MoveError::NotEnoughCrates {
+                line,
+                count,
+                stack,
+                depth,
+            } => write!(
+                f,
+                "line {line}: can't move {count} crates from stack {stack}, which only has {depth}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+/// Runs a list of moves one at a time, remembering which crates moved last.
+/// Stops at the first impossible move, unless it's lenient.
+pub struct Rearrangement<'a> {
+    stacks: Stacks,
+    moves: Vec<Move>,
+    next_move: usize,
+    crane: &'a dyn Crane,
+    crate_count: usize,
+    last_move: Option<Move>,
+    applied: Vec<Move>,
+    lenient: bool,
+    skipped: Vec<MoveError>,
+    error: Option<MoveError>,
+}
+
+impl<'a> Rearrangement<'a> {
+    pub fn new(stacks: Stacks, moves: Vec<Move>, crane: &'a dyn Crane) -> Self {
+        let crate_count = stacks.columns.iter().map(Vec::len).sum();
+        Self {
+            stacks,
+            moves,
+            next_move: 0,
+            crane,
+            crate_count,
+            last_move: None,
+            applied: vec![],
+            lenient: false,
+            skipped: vec![],
+            error: None,
+        }
+    }
+
+    /// Skip impossible moves instead of stopping at them
+    pub fn lenient(self) -> Self {
+        Self {
+            lenient: true,
+            ..self
+        }
+    }
+
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    /// The impossible moves skipped so far, if lenient
+    pub fn skipped(&self) -> &[MoveError] {
+        &self.skipped
+    }
+
+    /// The impossible move that stopped the rearrangement, if not lenient
+    pub fn error(&self) -> Option<&MoveError> {
+        self.error.as_ref()
+    }
+
+    /// The moves that take the stacks from where they are now back to where
+    /// they started, using the same crane. Skipped moves are left out.
+    pub fn undo_moves(&self) -> Vec<Move> {
+        self.applied
+            .iter()
+            .rev()
+            .flat_map(|mv| self.crane.undo(mv))
+            .collect()
+    }
+
+    /// A puzzle input with the current stacks and the moves that undo the
+    /// rearrangement so far
+    pub fn undo_input(&self) -> String {
+        let mut input = format!("{}\n", self.stacks);
+        for mv in self.undo_moves() {
+            input += &format!("{mv}\n");
+        }
+        input
+    }
+}
+
+impl Simulation for Rearrangement<'_> {
+    type Event = Move;
+
+    fn step(&mut self) -> ControlFlow<(), Move> {
+        let Some(&mv) = self.moves.get(self.next_move) else {
+            return ControlFlow::Break(());
+        };
+
+        self.next_move += 1;
+        match self.stacks.apply(&mv, self.crane) {
+            Ok(()) => {
+                self.last_move = Some(mv);
+                self.applied.push(mv);
+            }
+            Err(error) if self.lenient => {
+                self.last_move = None;
+                self.skipped.push(error);
+            }
+            Err(error) => {
+                self.error = Some(error);
+                return ControlFlow::Break(());
+            }
+        }
+
+        ControlFlow::Continue(mv)
+    }
+}
+
+/// Draws the stacks like the puzzle's diagram, with the crates that just
+/// moved drawn as `<X>` instead of `[X]`
+impl Renderable for Rearrangement<'_> {
+    const Y_UP: bool = true;
+
+    fn x_range(&self) -> RangeInclusive<i64> {
+        0..=(self.stacks.len() as i64 * 4 - 2)
+    }
+
+    fn y_range(&self) -> RangeInclusive<i64> {
+        // Tall enough for every crate to be in one stack, so the frame
+        // doesn't change size as crates move. The bottom row is the labels.
+        -1..=(self.crate_count as i64 - 1)
+    }
+
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        let index = (x / 4) as usize;
+        let offset = x % 4;
+        if y < 0 {
+            return match offset {
+                1 => char::from_digit(((index + 1) % 10) as u32, 10).unwrap(),
+                _ => ' ',
+            };
+        }
+
+        let height = y as usize;
+        let Some(&name) = self.stacks.stack(index).and_then(|stack| stack.get(height)) else {
+            return ' ';
+        };
+
+        let moved = match self.last_move {
+            Some(mv) if mv.to == index => {
+                let stack_height = self.stacks.stack(index).map_or(0, <[char]>::len);
+                height + mv.count >= stack_height
+            }
+            _ => false,
+        };
+        match (offset, moved) {
+            (0, false) => '[',
+            (0, true) => '<',
+            (1, _) => name,
+            (2, false) => ']',
+            (2, true) => '>',
+            _ => ' ',
+        }
+    }
+}
+
+/// Move `count` crates between the stacks at 0-based indices `from` and `to`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+    /// The 1-based line the move came from, for error messages. For JSON
+    /// input, this is the move's 1-based position in the list instead.
+    pub line: usize,
+}
+
+impl Move {
+    /// The same move, but from `to` back to `from`
+    pub fn reversed(&self) -> Move {
+        Move {
+            from: self.to,
+            to: self.from,
+            ..*self
+        }
+    }
+}
+
+/// Writes the move like a line of the puzzle input
+impl std::fmt::Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+/// How the starting stacks and moves are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// The stack diagram and move list from the puzzle
+    #[default]
+    Diagram,
+    /// `{"stacks": [["Z", "N"], ...], "moves": [{"count": 1, "from": 2, "to": 1}, ...]}`,
+    /// with stacks listed bottom crate first and numbered from 1
+    Json,
+}
+
+/// Parse the starting stacks and moves written in `format`
+pub fn parse_with(input: &str, format: Format) -> anyhow::Result<(Stacks, Vec<Move>)> {
+    match format {
+        Format::Diagram => parse(input),
+        Format::Json => parse_json(input),
+    }
+}
+
+/// Parse the starting stack diagram and the list of moves
+pub fn parse(input: &str) -> anyhow::Result<(Stacks, Vec<Move>)> {
+    let mut lines = input.lines().enumerate();
+
+    // Rows are listed from the top down, so each stack gets built upside
+    // down and flipped at the end
+    let mut stacks = Stacks::default();
+    for (_, line) in &mut lines {
+        if line.trim_start().starts_with('[') {
+            // Parse a row of shipping containers
+            for (index, container) in line.as_bytes().chunks(4).enumerate() {
+                let name = match container.trim_ascii() {
+                    [b'[', name, b']'] => Some(name),
+                    [] => None,
+                    _ => {
+                        anyhow::bail!(
+                            "could not parse container: {:?}",
+                            String::from_utf8_lossy(container)
+                        );
+                    }
+                };
+
+                if let Some(&name) = name {
+                    stacks.ensure_len(index + 1);
+                    stacks.columns[index].push(name.into());
+                }
+            }
+        } else {
+            // This is the last line with shipping container indices. Make
+            // sure stacks that start out empty still count.
+            stacks.ensure_len(line.split_whitespace().count());
+            break;
+        }
+    }
+    for column in &mut stacks.columns {
+        column.reverse();
+    }
+
+    let mut moves = vec![];
+    for (i, line) in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mv = parse_move(line, i + 1).with_context(|| format!("line {}", i + 1))?;
+        moves.push(mv);
+    }
+
+    Ok((stacks, moves))
+}
+
+#[derive(serde::Deserialize)]
+struct JsonScenario {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<JsonMove>,
+}
+
+#[derive(serde::Deserialize)]
+struct JsonMove {
+    count: usize,
+    from: u32,
+    to: u32,
+}
+
+fn parse_json(input: &str) -> anyhow::Result<(Stacks, Vec<Move>)> {
+    let scenario: JsonScenario = serde_json::from_str(input).context("failed to parse JSON")?;
+
+    let stacks = Stacks {
+        columns: scenario.stacks,
+    };
+
+    let mut moves = vec![];
+    for (i, mv) in scenario.moves.into_iter().enumerate() {
+        let context = || format!("move {}", i + 1);
+        moves.push(Move {
+            count: mv.count,
+            from: column_index(mv.from).with_context(context)?,
+            to: column_index(mv.to).with_context(context)?,
+            line: i + 1,
+        });
+    }
+
+    Ok((stacks, moves))
+}
+
+fn parse_move(line: &str, line_number: usize) -> anyhow::Result<Move> {
+    let (prefix, line) = line
+        .split_once("move ")
+        .context("failed to parse move command")?;
+    anyhow::ensure!(prefix.is_empty());
+    let (count, line) = line
+        .split_once(" from ")
+        .context("failed to parse move count")?;
+    let (from_column, to_column) = line
+        .split_once(" to ")
+        .context("failed to parse move columns")?;
+    let count: usize = count.parse()?;
+    let from_column: u32 = from_column.parse()?;
+    let to_column: u32 = to_column.parse()?;
+
+    Ok(Move {
+        count,
+        from: column_index(from_column)?,
+        to: column_index(to_column)?,
+        line: line_number,
+    })
+}
+
+fn column_index(label: u32) -> anyhow::Result<usize> {
+    let label: usize = label.try_into()?;
+    label.checked_sub(1).context("stacks are numbered from 1")
+}
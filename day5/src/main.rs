@@ -1,89 +1,83 @@
-#![feature(byte_slice_trim_ascii)]
-
-use std::{
-    collections::{BTreeMap, VecDeque},
-    io::BufRead,
-};
+use std::{ops::ControlFlow, path::PathBuf};
 
 use anyhow::Context;
+use clap::Parser;
+use cli::{DisplayArgs, InputArgs, PartArg};
+use common::config::Config;
+use day5::{CraneModel, Format, Rearrangement};
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+    #[clap(flatten)]
+    part: PartArg,
+    #[clap(flatten)]
+    display: DisplayArgs,
+    /// How the input is written
+    #[clap(long, value_enum, default_value = "diagram")]
+    format: Format,
+    /// Skip impossible moves and list them at the end, instead of stopping
+    /// at the first one
+    #[clap(long)]
+    lenient: bool,
+    /// Print the whole final stack diagram instead of just the top crates
+    #[clap(long)]
+    print_stacks: bool,
+    /// Which CrateMover model moves the crates. Defaults to the model for
+    /// `--part`.
+    #[clap(long, value_enum)]
+    crane: Option<CraneModel>,
+    /// Write an input to this file that starts from the final stacks and
+    /// moves them back to the starting stacks with the same crane
+    #[clap(long)]
+    emit_undo: Option<PathBuf>,
+}
 
 fn main() -> anyhow::Result<()> {
-    let stdin = std::io::stdin().lock();
-    let mut lines = stdin.lines();
-
-    let mut columns: BTreeMap<usize, VecDeque<char>> = BTreeMap::new();
-    for line in &mut lines {
-        let line = line?;
+    let args = Args::parse();
+    let config = Config::load()?;
+    let default_rate = config.get("day5", "rate")?.unwrap_or(500);
+
+    let input = args.input.read(day5::EXAMPLE)?;
+
+    let crane = args.crane.unwrap_or(match args.part.part {
+        1 => CraneModel::CrateMover9000,
+        _ => CraneModel::CrateMover9001,
+    });
+    let (stacks, moves) = day5::parse_with(&input, args.format)?;
+    let mut rearrangement = Rearrangement::new(stacks, moves, crane.crane());
+    if args.lenient {
+        rearrangement = rearrangement.lenient();
+    }
 
-        if line.trim_start().starts_with('[') {
-            // Parse a row of shipping containers
-            for (index, container) in line.as_bytes().chunks(4).enumerate() {
-                let name = match container.trim_ascii() {
-                    [b'[', name, b']'] => Some(name.into()),
-                    [] => None,
-                    _ => {
-                        anyhow::bail!(
-                            "could not parse container: {:?}",
-                            String::from_utf8_lossy(container)
-                        );
-                    }
-                };
+    let animation = args.display.animation(default_rate);
+    animation.run(&mut rearrangement, |_, _| ControlFlow::Continue(()));
+    if let Some(error) = rearrangement.error() {
+        return Err(error.clone().into());
+    }
 
-                if let Some(&name) = name {
-                    let column = columns.entry(index).or_default();
-                    column.push_front(name.into());
-                }
-            }
-        } else {
-            // This is the last line with shipping container indices.
-            break;
-        }
+    if args.print_stacks {
+        print!("{}", rearrangement.stacks());
+    } else {
+        let top_crates = rearrangement.stacks().top_crates();
+        println!("{top_crates}");
     }
 
-    for line in lines {
-        let line = line?;
+    if let Some(path) = &args.emit_undo {
+        std::fs::write(path, rearrangement.undo_input())
+            .with_context(|| format!("failed to write undo moves to {}", path.display()))?;
+    }
 
-        if line.trim().is_empty() {
-            continue;
+    if !rearrangement.skipped().is_empty() {
+        eprintln!(
+            "Skipped {} impossible moves:",
+            rearrangement.skipped().len()
+        );
+        for error in rearrangement.skipped() {
+            eprintln!("  {error}");
         }
-
-        let (prefix, line) = line
-            .split_once("move ")
-            .context("failed to parse move command")?;
-        anyhow::ensure!(prefix.is_empty());
-        let (count, line) = line
-            .split_once(" from ")
-            .context("failed to parse move count")?;
-        let (from_column, to_column) = line
-            .split_once(" to ")
-            .context("failed to parse move columns")?;
-        let count: usize = count.parse()?;
-        let from_column: u32 = from_column.parse()?;
-        let to_column: u32 = to_column.parse()?;
-
-        let from_index = column_index(from_column)?;
-        let to_index = column_index(to_column)?;
-        let mut from_column = std::mem::take(columns.entry(from_index).or_default());
-        let mut to_column = std::mem::take(columns.entry(to_index).or_default());
-
-        let popped = from_column.drain(from_column.len() - count..);
-        to_column.extend(popped);
-
-        columns.insert(from_index, from_column);
-        columns.insert(to_index, to_column);
     }
 
-    let top_crates = columns
-        .values()
-        .filter_map(|column| column.back().map(|&name| char::from(name)))
-        .collect::<String>();
-
-    println!("{top_crates}");
-
     Ok(())
 }
-
-fn column_index(label: u32) -> anyhow::Result<usize> {
-    let label: usize = label.try_into()?;
-    Ok(label - 1)
-}
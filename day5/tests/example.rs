@@ -0,0 +1,103 @@
+use common::simulation;
+use day5::{top_crates, CrateMover9000, CrateMover9001, MoveError, Rearrangement};
+
+#[test]
+fn example_crate_mover_9000() {
+    assert_eq!(top_crates(day5::EXAMPLE, &CrateMover9000).unwrap(), "CMZ");
+}
+
+#[test]
+fn example_crate_mover_9001() {
+    assert_eq!(top_crates(day5::EXAMPLE, &CrateMover9001).unwrap(), "MCD");
+}
+
+#[test]
+fn stacks_display_matches_input() {
+    let (stacks, _) = day5::parse(day5::EXAMPLE).unwrap();
+    let diagram: String = day5::EXAMPLE
+        .lines()
+        .take_while(|line| !line.trim().is_empty())
+        .map(|line| format!("{line}\n"))
+        .collect();
+    assert_eq!(stacks.to_string(), diagram);
+}
+
+#[test]
+fn final_stacks_round_trip() {
+    let (mut stacks, moves) = day5::parse(day5::EXAMPLE).unwrap();
+    for mv in &moves {
+        stacks.apply(mv, &CrateMover9000).unwrap();
+    }
+
+    let (reparsed, no_moves) = day5::parse(&stacks.to_string()).unwrap();
+    assert!(no_moves.is_empty());
+    assert_eq!(reparsed.top_crates(), "CMZ");
+    assert_eq!(reparsed.to_string(), stacks.to_string());
+}
+
+const IMPOSSIBLE: &str = "\
+[A]    
+[B] [C]
+ 1   2 
+
+move 1 from 1 to 2
+move 3 from 1 to 2
+move 1 from 3 to 1
+move 2 from 2 to 2
+move 1 from 1 to 2
+";
+
+#[test]
+fn impossible_moves_are_errors() {
+    let error = top_crates(IMPOSSIBLE, &CrateMover9000).unwrap_err();
+    let error = error.downcast_ref::<MoveError>().unwrap();
+    assert_eq!(
+        *error,
+        MoveError::NotEnoughCrates {
+            line: 6,
+            count: 3,
+            stack: 1,
+            depth: 1,
+        }
+    );
+    assert!(error.to_string().starts_with("line 6:"));
+}
+
+#[test]
+fn lenient_rearrangement_skips_impossible_moves() {
+    let (stacks, moves) = day5::parse(IMPOSSIBLE).unwrap();
+    let mut rearrangement = Rearrangement::new(stacks, moves, &CrateMover9000).lenient();
+    simulation::run(&mut rearrangement);
+
+    assert_eq!(rearrangement.error(), None);
+    assert_eq!(
+        rearrangement.skipped(),
+        [
+            MoveError::NotEnoughCrates {
+                line: 6,
+                count: 3,
+                stack: 1,
+                depth: 1,
+            },
+            MoveError::NoSuchStack { line: 7, stack: 3 },
+        ]
+    );
+    assert_eq!(rearrangement.stacks().top_crates(), "B");
+    assert_eq!(rearrangement.stacks().stack(1).unwrap(), &['C', 'A', 'B']);
+}
+
+#[test]
+fn strict_rearrangement_stops_at_impossible_moves() {
+    let (stacks, moves) = day5::parse(IMPOSSIBLE).unwrap();
+    let mut rearrangement = Rearrangement::new(stacks, moves, &CrateMover9000);
+    let steps = simulation::run(&mut rearrangement);
+
+    assert_eq!(steps, 1);
+    assert!(rearrangement.error().is_some());
+    assert_eq!(rearrangement.stacks().top_crates(), "BA");
+}
+
+#[test]
+fn stack_zero_is_a_parse_error() {
+    assert!(day5::parse(" 1 \n\nmove 1 from 0 to 1\n").is_err());
+}
@@ -0,0 +1,53 @@
+use day5::{Format, Move};
+
+const EXAMPLE_JSON: &str = include_str!("../fixtures/example.json");
+
+#[test]
+fn json_matches_the_diagram() {
+    let (stacks, moves) = day5::parse_with(EXAMPLE_JSON, Format::Json).unwrap();
+    let (expected_stacks, expected_moves) = day5::parse(day5::EXAMPLE).unwrap();
+
+    assert_eq!(stacks, expected_stacks);
+    assert_eq!(stacks.to_string(), expected_stacks.to_string());
+
+    let without_lines = |moves: Vec<Move>| {
+        moves
+            .into_iter()
+            .map(|mv| (mv.count, mv.from, mv.to))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(without_lines(moves), without_lines(expected_moves));
+}
+
+#[test]
+fn json_moves_are_numbered_by_position() {
+    let (_, moves) = day5::parse_with(EXAMPLE_JSON, Format::Json).unwrap();
+    let lines: Vec<_> = moves.iter().map(|mv| mv.line).collect();
+    assert_eq!(lines, [1, 2, 3, 4]);
+}
+
+#[test]
+fn json_empty_stacks_count() {
+    let input = r#"{"stacks": [["A"], [], ["B"], []], "moves": []}"#;
+    let (stacks, moves) = day5::parse_with(input, Format::Json).unwrap();
+
+    assert!(moves.is_empty());
+    assert_eq!(stacks.len(), 4);
+    assert_eq!(stacks.top_crates(), "AB");
+}
+
+#[test]
+fn invalid_json() {
+    let invalid = [
+        r#"{"stacks": [["AB"]], "moves": []}"#,
+        r#"{"stacks": [["A"]], "moves": [{"count": 1, "from": 0, "to": 1}]}"#,
+        r#"{"stacks": [["A"]]}"#,
+        "[",
+    ];
+    for input in invalid {
+        assert!(
+            day5::parse_with(input, Format::Json).is_err(),
+            "{input} should be invalid"
+        );
+    }
+}
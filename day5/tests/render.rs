@@ -0,0 +1,27 @@
+use common::{render::Renderable, simulation::Simulation};
+use day5::{CrateMover9000, CrateMover9001, Rearrangement};
+
+fn after_moves(crane: &dyn day5::Crane, moves: usize) -> String {
+    let (stacks, all_moves) = day5::parse(day5::EXAMPLE).unwrap();
+    let mut rearrangement = Rearrangement::new(stacks, all_moves, crane);
+    for _ in 0..moves {
+        let _ = rearrangement.step();
+    }
+
+    rearrangement.render()
+}
+
+#[test]
+fn starting_stacks() {
+    insta::assert_snapshot!(after_moves(&CrateMover9000, 0));
+}
+
+#[test]
+fn crate_mover_9000_second_move() {
+    insta::assert_snapshot!(after_moves(&CrateMover9000, 2));
+}
+
+#[test]
+fn crate_mover_9001_second_move() {
+    insta::assert_snapshot!(after_moves(&CrateMover9001, 2));
+}
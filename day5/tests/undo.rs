@@ -0,0 +1,76 @@
+use common::simulation;
+use day5::{Crane, CrateMover9000, CrateMover9001, Rearrangement, Stacks};
+
+fn rearrange<'a>(input: &str, crane: &'a dyn Crane) -> Rearrangement<'a> {
+    let (stacks, moves) = day5::parse(input).unwrap();
+    let mut rearrangement = Rearrangement::new(stacks, moves, crane).lenient();
+    simulation::run(&mut rearrangement);
+    rearrangement
+}
+
+fn starting_stacks(input: &str) -> Stacks {
+    day5::parse(input).unwrap().0
+}
+
+#[test]
+fn undo_restores_the_example() {
+    let cranes: [&dyn Crane; 2] = [&CrateMover9000, &CrateMover9001];
+    for crane in cranes {
+        let rearrangement = rearrange(day5::EXAMPLE, crane);
+        let undo_input = rearrangement.undo_input();
+
+        let undone = rearrange(&undo_input, crane);
+        assert!(undone.skipped().is_empty());
+        assert_eq!(*undone.stacks(), starting_stacks(day5::EXAMPLE));
+    }
+}
+
+#[test]
+fn undo_moves_for_the_example() {
+    let rearrangement = rearrange(day5::EXAMPLE, &CrateMover9000);
+    let undo_moves: Vec<_> = rearrangement
+        .undo_moves()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        undo_moves,
+        [
+            "move 1 from 2 to 1",
+            "move 2 from 1 to 2",
+            "move 3 from 3 to 1",
+            "move 1 from 1 to 2",
+        ]
+    );
+}
+
+#[test]
+fn undo_leaves_out_skipped_moves() {
+    let input = "\
+[A]    
+[B]    
+ 1   2 
+
+move 1 from 1 to 2
+move 5 from 1 to 2
+move 1 from 1 to 2
+";
+    let cranes: [&dyn Crane; 2] = [&CrateMover9000, &CrateMover9001];
+    for crane in cranes {
+        let rearrangement = rearrange(input, crane);
+        assert_eq!(rearrangement.skipped().len(), 1);
+        assert_eq!(rearrangement.undo_moves().len(), 2);
+
+        // Stack 1 ends up empty, but still needs to be there to move back to
+        let undone = rearrange(&rearrangement.undo_input(), crane);
+        assert!(undone.skipped().is_empty());
+        assert_eq!(*undone.stacks(), starting_stacks(input));
+    }
+}
+
+#[test]
+fn empty_stacks_keep_their_labels() {
+    let (stacks, _) = day5::parse("[A]        \n 1   2   3 \n").unwrap();
+    assert_eq!(stacks.len(), 3);
+    assert_eq!(stacks.to_string(), "[A]        \n 1   2   3 \n");
+}
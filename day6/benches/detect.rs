@@ -0,0 +1,35 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day6::{Detector, MESSAGE_MARKER_LEN, PACKET_MARKER_LEN};
+
+const STREAM_LEN: usize = 4 * 1024 * 1024;
+
+/// A long stream that cycles through fewer letters than either marker needs,
+/// then ends with the whole alphabet, so the only marker is at the very end
+fn synthetic_stream() -> Vec<u8> {
+    let filler = b"abc";
+    let mut stream: Vec<u8> = filler.iter().copied().cycle().take(STREAM_LEN).collect();
+    stream.extend(b'a'..=b'z');
+    stream
+}
+
+fn detect(c: &mut Criterion) {
+    let stream = synthetic_stream();
+
+    let mut group = c.benchmark_group("marker_end");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(stream.len() as u64));
+    for window in [PACKET_MARKER_LEN, MESSAGE_MARKER_LEN] {
+        for (name, detector) in [
+            ("rolling", Detector::Rolling),
+            ("combinations", Detector::Combinations),
+        ] {
+            group.bench_with_input(BenchmarkId::new(name, window), &stream, |b, stream| {
+                b.iter(|| day6::marker_end(black_box(stream), window, detector))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, detect);
+criterion_main!(benches);
@@ -0,0 +1,225 @@
+use std::{collections::VecDeque, io::Read};
+
+use anyhow::Context;
+use common::answer::Answer;
+use itertools::Itertools;
+
+pub const EXAMPLES: [&str; 5] = [
+    include_str!("../fixtures/example1.txt"),
+    include_str!("../fixtures/example2.txt"),
+    include_str!("../fixtures/example3.txt"),
+    include_str!("../fixtures/example4.txt"),
+    include_str!("../fixtures/example5.txt"),
+];
+pub const EXAMPLE: &str = EXAMPLES[0];
+
+/// How many distinct characters make a start-of-packet marker (part 1)
+pub const PACKET_MARKER_LEN: usize = 4;
+
+/// How many distinct characters make a start-of-message marker (part 2)
+pub const MESSAGE_MARKER_LEN: usize = 14;
+
+pub fn solve_part1(input: &str) -> anyhow::Result<Answer> {
+    let sync_index = find_marker(input, PACKET_MARKER_LEN)?;
+    Ok(sync_index.into())
+}
+
+pub fn solve_part2(input: &str) -> anyhow::Result<Answer> {
+    let sync_index = find_marker(input, MESSAGE_MARKER_LEN)?;
+    Ok(sync_index.into())
+}
+
+/// Find how many characters need to be read before the first `window`
+/// distinct characters in a row
+pub fn find_marker(input: &str, window: usize) -> anyhow::Result<usize> {
+    find_marker_with(input, window, Detector::default())
+}
+
+pub fn find_marker_with(input: &str, window: usize, detector: Detector) -> anyhow::Result<usize> {
+    let datastream = input.lines().next().context("no input provided")?;
+    find_marker_in_bytes(datastream.as_bytes(), window, detector)
+}
+
+/// Treat each non-blank line of `input` as its own datastream, and find the
+/// marker in each one. Each line comes with its 1-based line number.
+pub fn line_markers(
+    input: &str,
+    window: usize,
+    detector: Detector,
+) -> impl Iterator<Item = (usize, &str, anyhow::Result<usize>)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(move |(i, line)| {
+            (
+                i + 1,
+                line,
+                find_marker_in_bytes(line.as_bytes(), window, detector),
+            )
+        })
+}
+
+/// Like `find_marker_with`, but every byte is part of the datastream,
+/// including line breaks
+pub fn find_marker_in_bytes(
+    datastream: &[u8],
+    window: usize,
+    detector: Detector,
+) -> anyhow::Result<usize> {
+    anyhow::ensure!(window > 0, "marker window must be at least 1");
+
+    let sync_index = marker_end(datastream, window, detector);
+    let sync_index = sync_index.context("could not sync datastream")?;
+
+    Ok(sync_index)
+}
+
+/// How to check each window for repeated bytes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Detector {
+    /// Keep a count of each byte in the window as it slides, plus how many
+    /// bytes are in it more than once. O(n) for any window size.
+    #[default]
+    Rolling,
+    /// Compare every pair of bytes in every window. O(n·w²).
+    Combinations,
+}
+
+/// The index just past the first `window` distinct bytes in a row of
+/// `datastream`, if there are any. An empty window is never a marker.
+pub fn marker_end(datastream: &[u8], window: usize, detector: Detector) -> Option<usize> {
+    if window == 0 {
+        return None;
+    }
+
+    match detector {
+        Detector::Rolling => rolling_marker_end(datastream, window),
+        Detector::Combinations => combinations_marker_end(datastream, window),
+    }
+}
+
+fn rolling_marker_end(datastream: &[u8], window: usize) -> Option<usize> {
+    let mut scanner = MarkerScanner::new(window);
+    let index = datastream.iter().position(|&byte| scanner.push(byte))?;
+    Some(index + 1)
+}
+
+/// How many bytes `stream_marker_end` reads at a time
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Like `find_marker`, but reading the datastream from `reader` a chunk at
+/// a time, so it never holds more than a chunk and a window in memory. The
+/// datastream ends at the first line break or the end of the reader.
+pub fn stream_marker_end<R: Read>(reader: R, window: usize) -> anyhow::Result<usize> {
+    stream_marker_end_with(reader, window, Framing::Line)
+}
+
+/// Where a datastream read from a `Read` ends
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Framing {
+    /// At the first line break, like the puzzle input
+    #[default]
+    Line,
+    /// Only at the end of the reader, since any byte can be part of a
+    /// binary datastream, including line breaks
+    Binary,
+}
+
+pub fn stream_marker_end_with<R: Read>(
+    mut reader: R,
+    window: usize,
+    framing: Framing,
+) -> anyhow::Result<usize> {
+    anyhow::ensure!(window > 0, "marker window must be at least 1");
+
+    let mut scanner = MarkerScanner::new(window);
+    let mut chunk = vec![0; CHUNK_SIZE];
+    loop {
+        let len = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error).context("failed to read datastream"),
+        };
+
+        for &byte in &chunk[..len] {
+            if framing == Framing::Line && (byte == b'\n' || byte == b'\r') {
+                anyhow::bail!("could not sync datastream");
+            }
+            if scanner.push(byte) {
+                return Ok(scanner.position());
+            }
+        }
+    }
+
+    anyhow::bail!("could not sync datastream")
+}
+
+/// Watches a datastream one byte at a time for `window` distinct bytes in a
+/// row. Keeps the last `window` bytes in a ring buffer, along with a count
+/// of each byte in it and how many bytes are in it more than once.
+pub struct MarkerScanner {
+    window: usize,
+    recent: VecDeque<u8>,
+    counts: [usize; 256],
+    repeated: usize,
+    position: usize,
+}
+
+impl MarkerScanner {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            recent: VecDeque::with_capacity(window),
+            counts: [0; 256],
+            repeated: 0,
+            position: 0,
+        }
+    }
+
+    /// Add the next byte, returning whether the last `window` bytes are all
+    /// different
+    pub fn push(&mut self, byte: u8) -> bool {
+        self.position += 1;
+
+        if self.recent.len() == self.window {
+            if let Some(old) = self.recent.pop_front() {
+                let count = &mut self.counts[old as usize];
+                *count -= 1;
+                if *count == 1 {
+                    self.repeated -= 1;
+                }
+            }
+        }
+
+        self.recent.push_back(byte);
+        let count = &mut self.counts[byte as usize];
+        *count += 1;
+        if *count == 2 {
+            self.repeated += 1;
+        }
+
+        self.recent.len() == self.window && self.repeated == 0
+    }
+
+    /// How many bytes have been pushed so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+fn combinations_marker_end(datastream: &[u8], window: usize) -> Option<usize> {
+    datastream
+        .windows(window)
+        .enumerate()
+        .find_map(|(start_index, bytes)| {
+            for (a, b) in bytes.iter().tuple_combinations() {
+                if a == b {
+                    return None;
+                }
+            }
+
+            Some(start_index + bytes.len())
+        })
+}
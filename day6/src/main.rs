@@ -1,30 +1,82 @@
+use std::io::Read;
+
 use anyhow::Context;
-use itertools::Itertools;
+use clap::Parser;
+use cli::{InputArgs, PartArg};
+use day6::Framing;
+
+#[derive(Parser)]
+struct Args {
+    #[clap(flatten)]
+    input: InputArgs,
+    #[clap(flatten)]
+    part: PartArg,
+    /// How many distinct characters in a row make a marker. Defaults to a
+    /// packet marker for part 1 and a message marker for part 2.
+    #[clap(long)]
+    window: Option<usize>,
+    /// How to check for repeated characters
+    #[clap(long, value_enum, default_value = "rolling")]
+    detector: day6::Detector,
+    /// Read the datastream a chunk at a time instead of all at once, for
+    /// inputs too big to fit in memory. Always uses the rolling detector.
+    #[clap(long)]
+    stream: bool,
+    /// Treat the input as raw bytes instead of a line of text, so line
+    /// breaks and non-UTF-8 bytes are part of the datastream
+    #[clap(long)]
+    binary: bool,
+    /// Treat each line as a separate datastream, and print the marker for
+    /// each one. `--example` uses every example from the puzzle. Lines
+    /// without a marker are reported on stderr, and make the exit status
+    /// non-zero.
+    #[clap(long, conflicts_with_all = ["stream", "binary"])]
+    each_line: bool,
+}
 
 fn main() -> anyhow::Result<()> {
-    let datastream = std::io::stdin()
-        .lines()
-        .next()
-        .context("no input provided")?
-        .context("failed to read input")?;
+    let args = Args::parse();
 
-    let sync_index =
-        datastream
-            .as_bytes()
-            .windows(14)
-            .enumerate()
-            .find_map(|(start_index, bytes)| {
-                for (a, b) in bytes.iter().tuple_combinations() {
-                    if a == b {
-                        return None;
-                    }
+    let window = args.window.unwrap_or(match args.part.part {
+        1 => day6::PACKET_MARKER_LEN,
+        _ => day6::MESSAGE_MARKER_LEN,
+    });
+    if args.each_line {
+        let input = args.input.read(&day6::EXAMPLES.concat())?;
+        let mut failed = 0;
+        for (line_number, _, sync_index) in day6::line_markers(&input, window, args.detector) {
+            match sync_index {
+                Ok(sync_index) => println!("{sync_index}"),
+                Err(error) => {
+                    eprintln!("line {line_number}: {error:#}");
+                    failed += 1;
                 }
+            }
+        }
 
-                Some(start_index + bytes.len())
-            });
-
-    let sync_index = sync_index.context("could not sync datastream")?;
+        anyhow::ensure!(failed == 0, "no marker found on {failed} line(s)");
+        return Ok(());
+    }
 
+    let framing = if args.binary {
+        Framing::Binary
+    } else {
+        Framing::Line
+    };
+    let sync_index = if args.stream {
+        let reader = args.input.open(day6::EXAMPLE)?;
+        day6::stream_marker_end_with(reader, window, framing)?
+    } else if args.binary {
+        let mut datastream = vec![];
+        args.input
+            .open(day6::EXAMPLE)?
+            .read_to_end(&mut datastream)
+            .context("failed to read datastream")?;
+        day6::find_marker_in_bytes(&datastream, window, args.detector)?
+    } else {
+        let input = args.input.read(day6::EXAMPLE)?;
+        day6::find_marker_with(&input, window, args.detector)?
+    };
     println!("{sync_index}");
 
     Ok(())
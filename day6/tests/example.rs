@@ -0,0 +1,80 @@
+use day6::{
+    find_marker, line_markers, marker_end, Detector, EXAMPLES, MESSAGE_MARKER_LEN,
+    PACKET_MARKER_LEN,
+};
+
+#[test]
+fn packet_markers() {
+    let markers: Vec<_> = EXAMPLES
+        .iter()
+        .map(|example| find_marker(example, PACKET_MARKER_LEN).unwrap())
+        .collect();
+    assert_eq!(markers, [7, 5, 6, 10, 11]);
+}
+
+#[test]
+fn message_markers() {
+    let markers: Vec<_> = EXAMPLES
+        .iter()
+        .map(|example| find_marker(example, MESSAGE_MARKER_LEN).unwrap())
+        .collect();
+    assert_eq!(markers, [19, 23, 23, 29, 26]);
+}
+
+#[test]
+fn other_windows() {
+    assert_eq!(find_marker("aab", 1).unwrap(), 1);
+    assert_eq!(find_marker("aabc", 2).unwrap(), 3);
+    assert_eq!(find_marker("abcdef", 6).unwrap(), 6);
+    assert!(find_marker("abcdef", 7).is_err());
+    assert!(find_marker("abc", 0).is_err());
+}
+
+#[test]
+fn detectors_agree() {
+    let datastreams = [
+        "".as_bytes(),
+        b"a",
+        b"aaaa",
+        b"abcabcabcd",
+        b"abbacddcabcdeedcba",
+        b"zzzzzzzzzzzzzzzzzzzzzzzzzzabcdefghijklmn",
+        &[0, 255, 0, 1, 2, 255, 3],
+    ];
+    for datastream in datastreams {
+        for window in 0..=15 {
+            assert_eq!(
+                marker_end(datastream, window, Detector::Rolling),
+                marker_end(datastream, window, Detector::Combinations),
+                "{datastream:?} with window {window}"
+            );
+        }
+    }
+}
+
+#[test]
+fn empty_window() {
+    for detector in [Detector::Rolling, Detector::Combinations] {
+        assert_eq!(marker_end(b"", 0, detector), None, "{detector:?}");
+        assert_eq!(marker_end(b"abc", 0, detector), None, "{detector:?}");
+    }
+}
+
+#[test]
+fn one_marker_per_line() {
+    // Blank lines are skipped, but still counted in the line numbers
+    let input = format!("{}\n\nabab\n", EXAMPLES.concat());
+    let markers: Vec<_> = line_markers(&input, PACKET_MARKER_LEN, Detector::Rolling)
+        .map(|(line_number, line, marker)| (line_number, line, marker.ok()))
+        .collect();
+
+    let expected = [
+        (1, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", Some(7)),
+        (2, "bvwbjplbgvbhsrlpgdmjqwftvncz", Some(5)),
+        (3, "nppdvjthqldpwncqszvftbrmjlhg", Some(6)),
+        (4, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", Some(10)),
+        (5, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", Some(11)),
+        (8, "abab", None),
+    ];
+    assert_eq!(markers, expected);
+}
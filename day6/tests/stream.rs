@@ -0,0 +1,80 @@
+use std::io::Read;
+
+use day6::{
+    find_marker_in_bytes, stream_marker_end, stream_marker_end_with, Detector, Framing, EXAMPLES,
+    MESSAGE_MARKER_LEN, PACKET_MARKER_LEN,
+};
+
+/// Hands out at most `chunk` bytes per read, so markers get split across
+/// reads
+struct Trickle<'a> {
+    bytes: &'a [u8],
+    chunk: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.chunk.min(buf.len()).min(self.bytes.len());
+        buf[..len].copy_from_slice(&self.bytes[..len]);
+        self.bytes = &self.bytes[len..];
+        Ok(len)
+    }
+}
+
+#[test]
+fn streamed_examples() {
+    for chunk in [1, 3, 4096] {
+        let markers: Vec<_> = EXAMPLES
+            .iter()
+            .map(|example| {
+                let reader = Trickle {
+                    bytes: example.as_bytes(),
+                    chunk,
+                };
+                stream_marker_end(reader, MESSAGE_MARKER_LEN).unwrap()
+            })
+            .collect();
+        assert_eq!(markers, [19, 23, 23, 29, 26]);
+    }
+}
+
+#[test]
+fn marker_past_the_first_chunk() {
+    let len = 3 * day6::CHUNK_SIZE + 5;
+    let reader = std::io::repeat(b'a').take(len as u64).chain(&b"bcd"[..]);
+    assert_eq!(
+        stream_marker_end(reader, PACKET_MARKER_LEN).unwrap(),
+        len + 3
+    );
+}
+
+#[test]
+fn endless_stream() {
+    let reader = (&b"zzzabc"[..]).chain(std::io::repeat(b'a'));
+    assert_eq!(stream_marker_end(reader, PACKET_MARKER_LEN).unwrap(), 6);
+}
+
+#[test]
+fn stream_stops_at_the_first_line() {
+    let reader = &b"aaab\nabcd\n"[..];
+    assert!(stream_marker_end(reader, PACKET_MARKER_LEN).is_err());
+}
+
+#[test]
+fn binary_datastreams() {
+    let datastream = [0xff, 0xfe, 0xff, b'\n', 0x00, b'\r', 0x80];
+
+    let reader = &datastream[..];
+    assert_eq!(
+        stream_marker_end_with(reader, PACKET_MARKER_LEN, Framing::Binary).unwrap(),
+        5
+    );
+    assert_eq!(
+        find_marker_in_bytes(&datastream, PACKET_MARKER_LEN, Detector::Combinations).unwrap(),
+        5
+    );
+
+    // A line break ends the datastream unless it's binary
+    let reader = &datastream[..];
+    assert!(stream_marker_end_with(reader, PACKET_MARKER_LEN, Framing::Line).is_err());
+}
@@ -0,0 +1,556 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::Context;
+use common::answer::Answer;
+use serde::Serialize;
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub const TOTAL_DISK_SPACE: u64 = 70_000_000;
+pub const TARGET_UNUSED_SPACE: u64 = 30_000_000;
+pub const SMALL_DIRECTORY_MAX_SIZE: u64 = 100_000;
+
+pub fn solve_part1(input: &str) -> anyhow::Result<Answer> {
+    let filesystem = parse_filesystem(input)?;
+    let total_size = sum_small_directories(&filesystem, SMALL_DIRECTORY_MAX_SIZE);
+    Ok(total_size.into())
+}
+
+pub fn solve_part2(input: &str) -> anyhow::Result<Answer> {
+    let filesystem = parse_filesystem(input)?;
+    let directory_size =
+        find_directory_to_delete(&filesystem, TOTAL_DISK_SPACE, TARGET_UNUSED_SPACE)?;
+    Ok(directory_size.into())
+}
+
+pub fn parse_filesystem(input: &str) -> anyhow::Result<Filesystem> {
+    let mut lines = input.lines().peekable();
+
+    let mut filesystem = Filesystem::new();
+    let mut current_directory = Filesystem::ROOT;
+
+    while let Some(line) = lines.next() {
+        let prompt = line.strip_prefix("$ ").context("could not parse command")?;
+        let mut prompt = prompt.split_whitespace();
+        let command = prompt.next().context("no command entered")?;
+
+        let command = match command {
+            "cd" => {
+                let arg = prompt.next().context("cd: expected arg")?;
+                Command::Cd(arg)
+            }
+            "ls" => Command::Ls,
+            "mkdir" => {
+                let name = prompt.next().context("mkdir: expected name")?;
+                Command::Mkdir(name)
+            }
+            "touch" => {
+                let size = prompt.next().context("touch: expected size")?;
+                let size = size.parse().context("touch: invalid filesize")?;
+                let name = prompt.next().context("touch: expected name")?;
+                Command::Touch { size, name }
+            }
+            "rm" => {
+                let name = prompt.next().context("rm: expected name")?;
+                Command::Rm(name)
+            }
+            command => anyhow::bail!("unknown command: {command}"),
+        };
+
+        match command {
+            Command::Cd("/") => current_directory = Filesystem::ROOT,
+            Command::Cd("..") => {
+                current_directory = filesystem[current_directory]
+                    .parent
+                    .unwrap_or(Filesystem::ROOT);
+            }
+            Command::Cd(subpath) => {
+                current_directory = filesystem
+                    .child(current_directory, subpath)
+                    .filter(|&id| filesystem[id].is_dir())
+                    .with_context(|| format!("cd: not a directory: {subpath}"))?;
+            }
+            Command::Ls => {
+                while let Some(line) = lines.next_if(|line| !line.starts_with("$ ")) {
+                    let mut file_info = line.split_whitespace();
+                    let file_details = file_info
+                        .next()
+                        .context("failed to parse info field of ls command")?;
+                    let filename = file_info
+                        .next()
+                        .context("failed to parse filename field of ls command")?;
+
+                    if file_info.next().is_some() {
+                        anyhow::bail!("unexpected field in ls line: {line}");
+                    }
+
+                    let kind = match file_details {
+                        "dir" => EntryKind::dir(),
+                        size => {
+                            let size = size.parse().context("invalid filesize")?;
+                            EntryKind::File(File { size })
+                        }
+                    };
+
+                    filesystem.insert(current_directory, filename, kind)?;
+                }
+            }
+            Command::Mkdir(name) => {
+                filesystem.insert(current_directory, name, EntryKind::dir())?;
+            }
+            Command::Touch { size, name } => {
+                filesystem.insert(current_directory, name, EntryKind::File(File { size }))?;
+            }
+            Command::Rm(name) => {
+                filesystem.remove(current_directory, name)?;
+            }
+        }
+
+        if let Some(arg) = prompt.next() {
+            anyhow::bail!("unexpected argument for command {command:?}: {arg}");
+        }
+    }
+
+    Ok(filesystem)
+}
+
+/// Add up the total size of every directory with a total size of at most
+/// `max_size`. Nested directories get counted once for each directory
+/// they're in.
+pub fn sum_small_directories(filesystem: &Filesystem, max_size: u64) -> u64 {
+    filesystem
+        .directories()
+        .map(|(_, dir)| dir.total_size)
+        .filter(|&size| size <= max_size)
+        .sum()
+}
+
+pub fn find_directory_to_delete(
+    filesystem: &Filesystem,
+    total_disk_space: u64,
+    target_unused_space: u64,
+) -> anyhow::Result<u64> {
+    let current_unused_space = total_disk_space
+        .checked_sub(filesystem.size())
+        .context("filesystem is using more than total disk space")?;
+    let required_to_delete = target_unused_space
+        .checked_sub(current_unused_space)
+        .context("already have enough disk space")?;
+    let mut directory_sizes: Vec<_> = filesystem
+        .directories()
+        .map(|(_, dir)| dir.total_size)
+        .collect();
+
+    directory_sizes.sort();
+
+    for candidate_directory_size in directory_sizes {
+        if candidate_directory_size >= required_to_delete {
+            // Delete this directory
+            return Ok(candidate_directory_size);
+        }
+    }
+
+    anyhow::bail!("could not find a big enough directory to delete");
+}
+
+/// Every directory's path and total size, biggest first, like
+/// `du -d <max_depth> | sort -rn`. The root is at depth 0.
+pub fn disk_usage(filesystem: &Filesystem, max_depth: Option<usize>) -> Vec<(PathBuf, u64)> {
+    let mut usage: Vec<_> = filesystem
+        .directories()
+        .map(|(path, dir)| (path, dir.total_size))
+        .filter(|(path, _)| {
+            // Every path has a root component, then one per directory
+            let depth = path.components().count() - 1;
+            depth <= max_depth.unwrap_or(usize::MAX)
+        })
+        .collect();
+
+    usage.sort_by(|(a_path, a_size), (b_path, b_size)| {
+        b_size.cmp(a_size).then_with(|| a_path.cmp(b_path))
+    });
+    usage
+}
+
+#[derive(Debug)]
+enum Command<'a> {
+    Cd(&'a str),
+    Ls,
+    Mkdir(&'a str),
+    Touch { size: u64, name: &'a str },
+    Rm(&'a str),
+}
+
+/// Where an entry is stored in a [`Filesystem`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EntryId(usize);
+
+/// Every file and directory, stored together in one list. Entries refer to
+/// their parent and children by [`EntryId`], so walking up from an entry or
+/// down into a directory never has to search by path.
+#[derive(Debug)]
+pub struct Filesystem {
+    entries: Vec<FilesystemEntry>,
+}
+
+impl Filesystem {
+    pub const ROOT: EntryId = EntryId(0);
+
+    /// A filesystem with nothing but an empty root directory
+    pub fn new() -> Self {
+        let root = FilesystemEntry {
+            name: String::new(),
+            parent: None,
+            kind: EntryKind::dir(),
+        };
+        Self {
+            entries: vec![root],
+        }
+    }
+
+    /// The total size of everything in the filesystem
+    pub fn size(&self) -> u64 {
+        self[Self::ROOT].size()
+    }
+
+    /// The entry named `name` directly in the directory `parent`
+    pub fn child(&self, parent: EntryId, name: &str) -> Option<EntryId> {
+        match &self[parent].kind {
+            EntryKind::Directory(dir) => dir.children.get(name).copied(),
+            EntryKind::File(_) => None,
+        }
+    }
+
+    /// Find the entry at `path`. Relative paths start from the root too.
+    pub fn lookup(&self, path: &std::path::Path) -> Option<EntryId> {
+        let mut id = Self::ROOT;
+        for component in path.components() {
+            match component {
+                std::path::Component::RootDir | std::path::Component::CurDir => {}
+                std::path::Component::Normal(name) => {
+                    id = self.child(id, name.to_str()?)?;
+                }
+                std::path::Component::ParentDir | std::path::Component::Prefix(_) => {
+                    return None;
+                }
+            }
+        }
+
+        Some(id)
+    }
+
+    /// The path from the root to the entry, starting with `/`
+    pub fn path(&self, id: EntryId) -> PathBuf {
+        let mut names = vec![];
+        let mut current = id;
+        while let Some(parent) = self[current].parent {
+            names.push(self[current].name.as_str());
+            current = parent;
+        }
+
+        let mut path = PathBuf::from("/");
+        path.extend(names.into_iter().rev());
+        path
+    }
+
+    /// Every reachable entry with its path, starting with the root at `/`
+    pub fn entries(&self) -> impl Iterator<Item = (PathBuf, &FilesystemEntry)> {
+        let mut queue = vec![(PathBuf::from("/"), Self::ROOT)];
+        std::iter::from_fn(move || {
+            let (path, id) = queue.pop()?;
+            let entry = &self[id];
+            if let EntryKind::Directory(dir) = &entry.kind {
+                queue.extend(
+                    dir.children
+                        .iter()
+                        .rev()
+                        .map(|(name, &child)| (path.join(name), child)),
+                );
+            }
+
+            Some((path, entry))
+        })
+    }
+
+    /// Like `entries`, but only the directories
+    pub fn directories(&self) -> impl Iterator<Item = (PathBuf, &Directory)> {
+        self.entries()
+            .filter_map(|(path, entry)| match &entry.kind {
+                EntryKind::Directory(dir) => Some((path, dir)),
+                EntryKind::File(_) => None,
+            })
+    }
+
+    /// Everything reachable from the root, nested by name, for `--dump`.
+    /// Removed entries are left out even though they're still in the list.
+    pub fn dump(&self) -> Dump<'_> {
+        self.dump_entry(Self::ROOT)
+    }
+
+    fn dump_entry(&self, id: EntryId) -> Dump<'_> {
+        match &self[id].kind {
+            EntryKind::Directory(dir) => Dump::Directory {
+                total_size: dir.total_size,
+                entries: dir
+                    .children()
+                    .map(|(name, child)| (name, self.dump_entry(child)))
+                    .collect(),
+            },
+            EntryKind::File(file) => Dump::File { size: file.size },
+        }
+    }
+
+    /// Draw the filesystem as an indented tree, like `tree --du`
+    pub fn tree(&self, order: TreeOrder) -> Tree<'_> {
+        Tree {
+            filesystem: self,
+            order,
+        }
+    }
+
+    /// Add a new entry to the directory `parent`, replacing any file with
+    /// the same name. Adding a directory that's already there leaves its
+    /// contents alone, so listing a directory twice doesn't empty its
+    /// subdirectories.
+    fn insert(&mut self, parent: EntryId, name: &str, kind: EntryKind) -> anyhow::Result<()> {
+        if let Some(existing) = self.child(parent, name) {
+            match (&self[existing].kind, &kind) {
+                (EntryKind::Directory(_), EntryKind::Directory(_)) => return Ok(()),
+                (EntryKind::Directory(_), EntryKind::File(_)) => {
+                    anyhow::bail!("can't replace directory {name} with a file")
+                }
+                (EntryKind::File(_), _) => self.remove(parent, name)?,
+            }
+        }
+
+        anyhow::ensure!(
+            self[parent].is_dir(),
+            "not a directory: {}",
+            self.path(parent).display()
+        );
+
+        let size = kind.size();
+        let id = EntryId(self.entries.len());
+        self.entries.push(FilesystemEntry {
+            name: name.to_owned(),
+            parent: Some(parent),
+            kind,
+        });
+
+        if let EntryKind::Directory(dir) = &mut self.entries[parent.0].kind {
+            dir.children.insert(name.to_owned(), id);
+        }
+        self.add_to_ancestors(parent, |total_size| total_size.checked_add(size))
+    }
+
+    /// Take the entry named `name` out of the directory `parent`, along with
+    /// everything in it. The removed entries stay in the list, but can't be
+    /// reached from the root anymore.
+    fn remove(&mut self, parent: EntryId, name: &str) -> anyhow::Result<()> {
+        let EntryKind::Directory(dir) = &mut self.entries[parent.0].kind else {
+            anyhow::bail!("not a directory: {}", self.path(parent).display());
+        };
+        let id = dir
+            .children
+            .remove(name)
+            .with_context(|| format!("file not found: {name}"))?;
+
+        let size = self[id].size();
+        self.entries[id.0].parent = None;
+        self.add_to_ancestors(parent, |total_size| total_size.checked_sub(size))
+    }
+
+    /// Update the total size of `dir` and every directory above it
+    fn add_to_ancestors(
+        &mut self,
+        dir: EntryId,
+        update: impl Fn(u64) -> Option<u64>,
+    ) -> anyhow::Result<()> {
+        let mut current = Some(dir);
+        while let Some(id) = current {
+            let entry = &mut self.entries[id.0];
+            if let EntryKind::Directory(dir) = &mut entry.kind {
+                dir.total_size = update(dir.total_size).context("directory size out of range")?;
+            }
+            current = entry.parent;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Filesystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::ops::Index<EntryId> for Filesystem {
+    type Output = FilesystemEntry;
+
+    fn index(&self, id: EntryId) -> &FilesystemEntry {
+        &self.entries[id.0]
+    }
+}
+
+/// A file or directory, with its name and the directory it's in
+#[derive(Debug)]
+pub struct FilesystemEntry {
+    name: String,
+    parent: Option<EntryId>,
+    kind: EntryKind,
+}
+
+impl FilesystemEntry {
+    /// The entry's name, which is empty for the root
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The directory the entry is in, or `None` for the root
+    pub fn parent(&self) -> Option<EntryId> {
+        self.parent
+    }
+
+    pub fn kind(&self) -> &EntryKind {
+        &self.kind
+    }
+
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, EntryKind::Directory(_))
+    }
+
+    pub fn size(&self) -> u64 {
+        self.kind.size()
+    }
+}
+
+#[derive(Debug)]
+pub enum EntryKind {
+    Directory(Directory),
+    File(File),
+}
+
+impl EntryKind {
+    fn dir() -> Self {
+        Self::Directory(Directory::empty())
+    }
+
+    fn size(&self) -> u64 {
+        match self {
+            EntryKind::Directory(dir) => dir.total_size,
+            EntryKind::File(file) => file.size,
+        }
+    }
+}
+
+/// A directory and everything in it, or a file, as written by `--dump`
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub enum Dump<'a> {
+    Directory {
+        total_size: u64,
+        entries: BTreeMap<&'a str, Dump<'a>>,
+    },
+    File {
+        size: u64,
+    },
+}
+
+/// How to sort the entries in each directory of a [`Tree`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TreeOrder {
+    #[default]
+    Name,
+    /// Biggest first, then by name
+    Size,
+}
+
+pub struct Tree<'a> {
+    filesystem: &'a Filesystem,
+    order: TreeOrder,
+}
+
+impl Tree<'_> {
+    fn write_children(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        dir: &Directory,
+        prefix: &str,
+    ) -> std::fmt::Result {
+        let mut children: Vec<_> = dir.children().map(|(_, id)| &self.filesystem[id]).collect();
+        if self.order == TreeOrder::Size {
+            // Already sorted by name, and the sort is stable
+            children.sort_by_key(|entry| std::cmp::Reverse(entry.size()));
+        }
+
+        let last_index = children.len().saturating_sub(1);
+        for (index, entry) in children.into_iter().enumerate() {
+            let (branch, indent) = if index == last_index {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+
+            let name = &entry.name;
+            match &entry.kind {
+                EntryKind::Directory(child) => {
+                    writeln!(f, "{prefix}{branch}[{}]  {name}/", child.total_size)?;
+                    self.write_children(f, child, &format!("{prefix}{indent}"))?;
+                }
+                EntryKind::File(file) => {
+                    writeln!(f, "{prefix}{branch}[{}]  {name}", file.size)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Tree<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let root = &self.filesystem[Filesystem::ROOT];
+        writeln!(f, "[{}]  /", root.size())?;
+        if let EntryKind::Directory(dir) = &root.kind {
+            self.write_children(f, dir, "")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Directory {
+    total_size: u64,
+    children: BTreeMap<String, EntryId>,
+}
+
+impl Directory {
+    fn empty() -> Self {
+        Directory {
+            total_size: 0,
+            children: BTreeMap::new(),
+        }
+    }
+
+    /// The size of everything in the directory, including subdirectories
+    pub fn total_size(&self) -> u64 {
+        self.total_size
+    }
+
+    /// The entries directly in this directory, sorted by name
+    pub fn children(&self) -> impl Iterator<Item = (&str, EntryId)> {
+        self.children.iter().map(|(name, &id)| (name.as_str(), id))
+    }
+}
+
+#[derive(Debug)]
+pub struct File {
+    size: u64,
+}
+
+impl File {
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
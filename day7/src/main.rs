@@ -1,219 +1,94 @@
-use std::{collections::HashMap, io::BufRead};
+use std::path::PathBuf;
 
 use anyhow::Context;
 use clap::Parser;
+use cli::InputArgs;
+use day7::EntryKind;
 
 #[derive(Parser)]
 struct Args {
-    #[clap(long)]
+    #[clap(long, default_value_t = day7::TOTAL_DISK_SPACE)]
     total_disk_space: u64,
-    #[clap(long)]
+    #[clap(long, default_value_t = day7::TARGET_UNUSED_SPACE)]
     target_unused_space: u64,
+    /// Sum up the sizes of directories at most this big, instead of finding
+    /// a directory to delete
+    #[clap(long)]
+    max_size: Option<u64>,
+    /// Print the filesystem as a tree with the size of each entry, with
+    /// each directory's entries sorted by name or size
+    #[clap(long, value_enum)]
+    tree: Option<day7::TreeOrder>,
+    /// List every directory with its total size, biggest first
+    #[clap(long)]
+    du: bool,
+    /// With `--du`, only list directories at most this many levels below
+    /// the root
+    #[clap(long, requires = "du")]
+    depth: Option<usize>,
+    /// Print the size of the entry at this path, and the size of each entry
+    /// in it if it's a directory
+    #[clap(long)]
+    query: Option<PathBuf>,
+    #[clap(flatten)]
+    input: InputArgs,
+    #[clap(long)]
+    dump: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    let stdin = std::io::stdin().lock();
-    let mut lines = stdin.lines().peekable();
-
-    let mut filesystem = FilesystemEntry::dir();
-    let mut current_directory = Path::root();
-
-    while let Some(line) = lines.next() {
-        let line = line?;
-        let prompt = line.strip_prefix("$ ").context("could not parse command")?;
-        let mut prompt = prompt.split_whitespace();
-        let command = prompt.next().context("no command entered")?;
-
-        let command = match command {
-            "cd" => {
-                let arg = prompt.next().context("cd: expected arg")?;
-                Command::Cd(arg)
-            }
-            "ls" => Command::Ls,
-            command => anyhow::bail!("unknown command: {command}"),
-        };
-
-        match command {
-            Command::Cd("/") => current_directory = Path::root(),
-            Command::Cd("..") => current_directory.up(),
-            Command::Cd(subpath) => current_directory.enter(subpath.to_string()),
-            Command::Ls => loop {
-                let line = lines.next_if(|line| match line {
-                    Ok(line) if line.starts_with("$ ") => false,
-                    Ok(_) => true,
-                    Err(_) => false,
-                });
-
-                let line = match line {
-                    Some(Ok(line)) => line,
-                    None => break,
-                    Some(Err(_)) => unreachable!(), // Filtered out in next_if
-                };
-
-                let mut file_info = line.split_whitespace();
-                let file_details = file_info
-                    .next()
-                    .context("failed to parse info field of ls command")?;
-                let filename = file_info
-                    .next()
-                    .context("failed to parse filename field of ls command")?;
+    let input = args.input.read(day7::EXAMPLE)?;
 
-                if let Some(_) = prompt.next() {
-                    anyhow::bail!("unexpected field in ls line: {line}");
-                }
+    let filesystem = day7::parse_filesystem(&input)?;
 
-                let filesystem_entry = match file_details {
-                    "dir" => FilesystemEntry::dir(),
-                    size => {
-                        let size = size.parse().context("invalid filesize")?;
-                        FilesystemEntry::File(File { size })
-                    }
-                };
-
-                filesystem.insert(&current_directory, filename.to_owned(), filesystem_entry)?;
-            },
-        }
-
-        if let Some(arg) = prompt.next() {
-            anyhow::bail!("unexpected argument for command {command:?}: {arg}");
-        }
+    if let Some(dump) = &args.dump {
+        let file = std::io::BufWriter::new(std::fs::File::create(dump)?);
+        serde_json::to_writer_pretty(file, &filesystem.dump())?;
     }
 
-    let current_unused_space = args
-        .total_disk_space
-        .checked_sub(filesystem.size())
-        .context("filesystem is using more than total disk space")?;
-    let required_to_delete = args
-        .target_unused_space
-        .checked_sub(current_unused_space)
-        .context("already have enough disk space")?;
-    let mut directory_sizes: Vec<_> = filesystem
-        .entries()
-        .filter_map(|entry| match entry {
-            FilesystemEntry::Directory(dir) => Some(dir.total_size),
-            FilesystemEntry::File(_) => None,
-        })
-        .collect();
-
-    directory_sizes.sort();
-
-    for candidate_directory_size in directory_sizes {
-        if candidate_directory_size >= required_to_delete {
-            // Delete this directory and exit.
-            println!("{candidate_directory_size}");
-
-            return Ok(());
-        }
+    if let Some(order) = args.tree {
+        print!("{}", filesystem.tree(order));
+        return Ok(());
     }
 
-    anyhow::bail!("could not find a big enough directory to delete");
-}
-
-#[derive(Debug)]
-enum Command<'a> {
-    Cd(&'a str),
-    Ls,
-}
-
-#[derive(Debug, Clone)]
-struct Path {
-    components: Vec<String>,
-}
-
-impl Path {
-    fn root() -> Self {
-        Self { components: vec![] }
-    }
-
-    fn up(&mut self) {
-        self.components.pop();
-    }
-
-    fn enter(&mut self, filename: String) {
-        self.components.push(filename);
-    }
-}
-
-#[derive(Debug)]
-enum FilesystemEntry {
-    Directory(Directory),
-    File(File),
-}
-
-impl FilesystemEntry {
-    fn dir() -> Self {
-        Self::Directory(Directory::empty())
-    }
-
-    fn insert(
-        &mut self,
-        current_directory: &Path,
-        filename: String,
-        entry: FilesystemEntry,
-    ) -> anyhow::Result<()> {
-        let entry_size = entry.size();
-
-        let mut dir = match self {
-            Self::Directory(dir) => dir,
-            Self::File(_) => anyhow::bail!("not a directory"),
-        };
-        dir.total_size += entry_size;
-
-        for path_component in &current_directory.components {
-            dir = match dir.entries.get_mut(path_component) {
-                Some(Self::Directory(dir)) => dir,
-                Some(Self::File(_)) => anyhow::bail!("not a directory: {path_component}"),
-                None => anyhow::bail!("file not found: {path_component}"),
-            };
-            dir.total_size += entry_size;
+    if let Some(path) = &args.query {
+        let id = filesystem
+            .lookup(path)
+            .with_context(|| format!("file not found: {}", path.display()))?;
+        let entry = &filesystem[id];
+        println!("{}\t{}", entry.size(), filesystem.path(id).display());
+        if let EntryKind::Directory(dir) = entry.kind() {
+            for (_, child) in dir.children() {
+                let child_entry = &filesystem[child];
+                let suffix = if child_entry.is_dir() { "/" } else { "" };
+                let child_path = filesystem.path(child);
+                println!("{}\t{}{suffix}", child_entry.size(), child_path.display());
+            }
         }
-
-        dir.entries.insert(filename, entry);
-
-        Ok(())
+        return Ok(());
     }
 
-    fn size(&self) -> u64 {
-        match self {
-            FilesystemEntry::Directory(dir) => dir.total_size,
-            FilesystemEntry::File(file) => file.size,
+    if args.du {
+        for (path, size) in day7::disk_usage(&filesystem, args.depth) {
+            println!("{size}\t{}", path.display());
         }
+        return Ok(());
     }
 
-    fn entries(&self) -> impl Iterator<Item = &FilesystemEntry> {
-        let mut queue: Vec<&FilesystemEntry> = vec![self];
-        std::iter::from_fn(move || {
-            let current = queue.pop();
-            match current {
-                Some(Self::Directory(dir)) => {
-                    queue.extend(dir.entries.values());
-                }
-                _ => {}
-            }
-
-            current
-        })
+    if let Some(max_size) = args.max_size {
+        let total_size = day7::sum_small_directories(&filesystem, max_size);
+        println!("{total_size}");
+        return Ok(());
     }
-}
-
-#[derive(Debug)]
-struct Directory {
-    total_size: u64,
-    entries: HashMap<String, FilesystemEntry>,
-}
 
-impl Directory {
-    fn empty() -> Self {
-        Directory {
-            total_size: 0,
-            entries: HashMap::new(),
-        }
-    }
-}
+    let directory_size = day7::find_directory_to_delete(
+        &filesystem,
+        args.total_disk_space,
+        args.target_unused_space,
+    )?;
+    println!("{directory_size}");
 
-#[derive(Debug)]
-struct File {
-    size: u64,
+    Ok(())
 }
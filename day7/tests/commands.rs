@@ -0,0 +1,77 @@
+use day7::{disk_usage, parse_filesystem, EXAMPLE};
+
+fn usage(input: &str) -> Vec<(String, u64)> {
+    let filesystem = parse_filesystem(input).unwrap();
+    disk_usage(&filesystem, None)
+        .into_iter()
+        .map(|(path, size)| (path.display().to_string(), size))
+        .collect()
+}
+
+#[test]
+fn mkdir_and_touch() {
+    let input = "\
+$ mkdir a
+$ cd a
+$ touch 100 x
+$ mkdir b
+$ cd b
+$ touch 20 y
+$ touch 3 z
+";
+    assert_eq!(
+        usage(input),
+        [
+            ("/".to_string(), 123),
+            ("/a".to_string(), 123),
+            ("/a/b".to_string(), 23),
+        ]
+    );
+}
+
+#[test]
+fn rm_updates_totals() {
+    let input = format!("{EXAMPLE}$ cd /\n$ cd a\n$ rm e\n$ rm f\n$ cd /\n$ rm d\n");
+    assert_eq!(
+        usage(&input),
+        [
+            ("/".to_string(), 14848514 + 8504156 + 2557 + 62596),
+            ("/a".to_string(), 2557 + 62596),
+        ]
+    );
+}
+
+#[test]
+fn replacing_entries() {
+    // Touching an existing file replaces its size, and listing or making a
+    // directory again keeps what's in it
+    let input = "\
+$ mkdir a
+$ cd a
+$ touch 100 x
+$ touch 50 x
+$ cd /
+$ ls
+dir a
+$ mkdir a
+";
+    assert_eq!(
+        usage(input),
+        [("/".to_string(), 50), ("/a".to_string(), 50)]
+    );
+}
+
+#[test]
+fn invalid_commands() {
+    let invalid = [
+        "$ rm missing\n",
+        "$ touch x\n",
+        "$ touch 1 x y\n",
+        "$ mkdir\n",
+        "$ cd missing\n$ touch 1 x\n",
+        "$ mkdir a\n$ touch 1 a\n",
+    ];
+    for input in invalid {
+        assert!(parse_filesystem(input).is_err(), "{input:?} should fail");
+    }
+}
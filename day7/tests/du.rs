@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use day7::{disk_usage, parse_filesystem, Filesystem, EXAMPLE};
+
+fn usage(max_depth: Option<usize>) -> Vec<(String, u64)> {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    disk_usage(&filesystem, max_depth)
+        .into_iter()
+        .map(|(path, size)| (path.display().to_string(), size))
+        .collect()
+}
+
+#[test]
+fn every_directory() {
+    assert_eq!(
+        usage(None),
+        [
+            ("/".to_string(), 48381165),
+            ("/d".to_string(), 24933642),
+            ("/a".to_string(), 94853),
+            ("/a/e".to_string(), 584),
+        ]
+    );
+}
+
+#[test]
+fn limited_depth() {
+    assert_eq!(usage(Some(0)), [("/".to_string(), 48381165)]);
+    assert_eq!(
+        usage(Some(1)),
+        [
+            ("/".to_string(), 48381165),
+            ("/d".to_string(), 24933642),
+            ("/a".to_string(), 94853),
+        ]
+    );
+    assert_eq!(usage(Some(2)), usage(None));
+}
+
+#[test]
+fn entries_know_their_paths() {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    let paths: Vec<_> = filesystem
+        .entries()
+        .map(|(path, entry)| (path.display().to_string(), entry.size()))
+        .collect();
+
+    // Depth first, in name order
+    let expected = [
+        ("/", 48381165),
+        ("/a", 94853),
+        ("/a/e", 584),
+        ("/a/e/i", 584),
+        ("/a/f", 29116),
+        ("/a/g", 2557),
+        ("/a/h.lst", 62596),
+        ("/b.txt", 14848514),
+        ("/c.dat", 8504156),
+        ("/d", 24933642),
+        ("/d/d.ext", 5626152),
+        ("/d/d.log", 8033020),
+        ("/d/j", 4060174),
+        ("/d/k", 7214296),
+    ];
+    let expected: Vec<_> = expected
+        .into_iter()
+        .map(|(path, size)| (path.to_string(), size))
+        .collect();
+    assert_eq!(paths, expected);
+}
+
+#[test]
+fn entries_point_at_each_other() {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    let id = filesystem.lookup(Path::new("/a/e/i")).unwrap();
+    assert_eq!(filesystem.path(id), PathBuf::from("/a/e/i"));
+    assert_eq!(filesystem[id].name(), "i");
+
+    let parent = filesystem[id].parent().unwrap();
+    assert_eq!(filesystem.path(parent), PathBuf::from("/a/e"));
+    assert_eq!(filesystem.child(parent, "i"), Some(id));
+    assert_eq!(filesystem.path(Filesystem::ROOT), PathBuf::from("/"));
+    assert_eq!(filesystem[Filesystem::ROOT].parent(), None);
+}
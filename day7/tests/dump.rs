@@ -0,0 +1,33 @@
+use day7::{parse_filesystem, EXAMPLE};
+
+#[test]
+fn dump_nests_entries() {
+    let input = "\
+$ mkdir a
+$ cd a
+$ touch 100 x
+";
+    let filesystem = parse_filesystem(input).unwrap();
+    let dump = serde_json::to_string(&filesystem.dump()).unwrap();
+    assert_eq!(
+        dump,
+        r#"{"Directory":{"total_size":100,"entries":{"a":{"Directory":{"total_size":100,"entries":{"x":{"File":{"size":100}}}}}}}}"#
+    );
+}
+
+#[test]
+fn dump_after_rm() {
+    let input = format!("{EXAMPLE}$ cd /\n$ cd a\n$ rm e\n$ cd /\n$ rm d\n$ rm b.txt\n");
+    let filesystem = parse_filesystem(&input).unwrap();
+    let dump = serde_json::to_string(&filesystem.dump()).unwrap();
+
+    // Only the root is a top-level entry, and nothing removed is left in it
+    assert!(dump.starts_with(r#"{"Directory":{"total_size":"#));
+    for removed in [r#""e""#, r#""i""#, r#""d""#, r#""d.log""#, r#""b.txt""#] {
+        assert!(!dump.contains(removed), "{removed} in {dump}");
+    }
+    assert_eq!(
+        dump,
+        r#"{"Directory":{"total_size":8598425,"entries":{"a":{"Directory":{"total_size":94269,"entries":{"f":{"File":{"size":29116}},"g":{"File":{"size":2557}},"h.lst":{"File":{"size":62596}}}}},"c.dat":{"File":{"size":8504156}}}}}"#
+    );
+}
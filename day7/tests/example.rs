@@ -0,0 +1,33 @@
+use day7::{
+    find_directory_to_delete, parse_filesystem, sum_small_directories, EXAMPLE,
+    SMALL_DIRECTORY_MAX_SIZE, TARGET_UNUSED_SPACE, TOTAL_DISK_SPACE,
+};
+
+#[test]
+fn small_directories() {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    assert_eq!(
+        sum_small_directories(&filesystem, SMALL_DIRECTORY_MAX_SIZE),
+        95437
+    );
+    assert_eq!(sum_small_directories(&filesystem, 584), 584);
+    assert_eq!(sum_small_directories(&filesystem, 583), 0);
+    assert_eq!(
+        sum_small_directories(&filesystem, u64::MAX),
+        48381165 + 94853 + 584 + 24933642
+    );
+}
+
+#[test]
+fn directory_to_delete() {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    assert_eq!(
+        find_directory_to_delete(&filesystem, TOTAL_DISK_SPACE, TARGET_UNUSED_SPACE).unwrap(),
+        24933642
+    );
+}
+
+#[test]
+fn extra_ls_fields_are_errors() {
+    assert!(parse_filesystem("$ ls\n123 a.txt extra\n").is_err());
+}
@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use day7::{parse_filesystem, EntryKind, EXAMPLE};
+
+#[test]
+fn lookup_entries() {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    let size = |path: &str| {
+        let id = filesystem.lookup(Path::new(path))?;
+        Some(filesystem[id].size())
+    };
+
+    assert_eq!(size("/"), Some(48381165));
+    assert_eq!(size("/a"), Some(94853));
+    assert_eq!(size("/a/e/i"), Some(584));
+    assert_eq!(size("a/./e"), Some(584));
+    assert_eq!(size("/d/d.log"), Some(8033020));
+    assert_eq!(size("/missing"), None);
+    assert_eq!(size("/b.txt/nested"), None);
+    assert_eq!(size("/a/.."), None);
+}
+
+#[test]
+fn directory_children() {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    let id = filesystem.lookup(Path::new("/a")).unwrap();
+    let EntryKind::Directory(dir) = filesystem[id].kind() else {
+        panic!("/a should be a directory");
+    };
+
+    assert_eq!(dir.total_size(), 94853);
+    let children: Vec<_> = dir
+        .children()
+        .map(|(name, child)| (name, filesystem[child].size()))
+        .collect();
+    assert_eq!(
+        children,
+        [("e", 584), ("f", 29116), ("g", 2557), ("h.lst", 62596)]
+    );
+}
@@ -0,0 +1,51 @@
+use day7::{parse_filesystem, TreeOrder, EXAMPLE};
+
+#[test]
+fn tree_by_name() {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    let expected = "\
+[48381165]  /
+├── [94853]  a/
+│   ├── [584]  e/
+│   │   └── [584]  i
+│   ├── [29116]  f
+│   ├── [2557]  g
+│   └── [62596]  h.lst
+├── [14848514]  b.txt
+├── [8504156]  c.dat
+└── [24933642]  d/
+    ├── [5626152]  d.ext
+    ├── [8033020]  d.log
+    ├── [4060174]  j
+    └── [7214296]  k
+";
+    assert_eq!(filesystem.tree(TreeOrder::Name).to_string(), expected);
+}
+
+#[test]
+fn tree_by_size() {
+    let filesystem = parse_filesystem(EXAMPLE).unwrap();
+    let expected = "\
+[48381165]  /
+├── [24933642]  d/
+│   ├── [8033020]  d.log
+│   ├── [7214296]  k
+│   ├── [5626152]  d.ext
+│   └── [4060174]  j
+├── [14848514]  b.txt
+├── [8504156]  c.dat
+└── [94853]  a/
+    ├── [62596]  h.lst
+    ├── [29116]  f
+    ├── [2557]  g
+    └── [584]  e/
+        └── [584]  i
+";
+    assert_eq!(filesystem.tree(TreeOrder::Size).to_string(), expected);
+}
+
+#[test]
+fn empty_tree() {
+    let filesystem = parse_filesystem("").unwrap();
+    assert_eq!(filesystem.tree(TreeOrder::Name).to_string(), "[0]  /\n");
+}
@@ -0,0 +1,95 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day8::{TreePatch, VisibilityStrategy};
+
+const SIZE: usize = 10_000;
+
+/// A `SIZE`×`SIZE` patch from the `gen` tool, with a fixed seed so every
+/// run does the same work, along with its heights row by row
+fn synthetic_patch() -> (Vec<u32>, TreePatch) {
+    let options = gen::Options {
+        seed: 0x2022_1208,
+        max_bounds: 0,
+    };
+    let input = gen::generate(8, SIZE, &options).unwrap();
+    let heights = input
+        .bytes()
+        .filter(|byte| byte.is_ascii_digit())
+        .map(|byte| (byte - b'0') as u32)
+        .collect();
+
+    (heights, TreePatch::parse(&input).unwrap())
+}
+
+/// The single-pass sweeps over plain heights: every row from both sides,
+/// then every column a whole row at a time, all on one thread. This is what
+/// the default build runs, minus parsing into `Tree`s.
+fn count_visible_sequential(heights: &[u32], width: usize) -> usize {
+    let mut visible = vec![false; heights.len()];
+
+    // `None` is less than any height
+    for (trees, visible) in heights.chunks(width).zip(visible.chunks_mut(width)) {
+        let mut tallest = None;
+        for (&height, visible) in trees.iter().zip(visible.iter_mut()) {
+            if Some(height) > tallest {
+                *visible = true;
+                tallest = Some(height);
+            }
+        }
+
+        let mut tallest = None;
+        for (&height, visible) in trees.iter().zip(visible.iter_mut()).rev() {
+            if Some(height) > tallest {
+                *visible = true;
+                tallest = Some(height);
+            }
+        }
+    }
+
+    let sweep = |trees: &[u32], visible: &mut [bool], tallest: &mut [Option<u32>]| {
+        for ((&height, visible), tallest) in trees.iter().zip(visible).zip(tallest) {
+            if Some(height) > *tallest {
+                *visible = true;
+                *tallest = Some(height);
+            }
+        }
+    };
+
+    let mut tallest = vec![None; width];
+    for (trees, visible) in heights.chunks(width).zip(visible.chunks_mut(width)) {
+        sweep(trees, visible, &mut tallest);
+    }
+
+    let mut tallest = vec![None; width];
+    for (trees, visible) in heights.chunks(width).zip(visible.chunks_mut(width)).rev() {
+        sweep(trees, visible, &mut tallest);
+    }
+
+    visible.into_iter().filter(|&visible| visible).count()
+}
+
+/// Run with `--features parallel` to compare the row and band split on
+/// rayon's thread pool against `sequential_sweeps`. Without the feature,
+/// `sweeps` is the same single pass as the baseline.
+fn visibility(c: &mut Criterion) {
+    let (heights, tree_patch) = synthetic_patch();
+    assert_eq!(
+        count_visible_sequential(&heights, SIZE),
+        tree_patch.count_visible(VisibilityStrategy::Sweeps)
+    );
+
+    let mut group = c.benchmark_group("count_visible");
+    group.sample_size(10);
+    group.bench_function("sequential_sweeps", |b| {
+        b.iter(|| count_visible_sequential(&heights, SIZE))
+    });
+    group.bench_function("sweeps", |b| {
+        b.iter(|| tree_patch.count_visible(VisibilityStrategy::Sweeps))
+    });
+    group.bench_function("rays", |b| {
+        b.iter(|| tree_patch.count_visible(VisibilityStrategy::Rays))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, visibility);
+criterion_main!(benches);
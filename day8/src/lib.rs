@@ -0,0 +1,607 @@
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead, ops::RangeInclusive};
+
+use anyhow::Context;
+use common::{answer::Answer, parallel::prelude::*, render::Renderable};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+pub fn solve_part1(input: &str) -> anyhow::Result<Answer> {
+    let tree_patch = TreePatch::parse(input)?;
+    let visible_trees = tree_patch.count_visible(VisibilityStrategy::default());
+    Ok(visible_trees.into())
+}
+
+pub fn solve_part2(input: &str) -> anyhow::Result<Answer> {
+    let tree_patch = TreePatch::parse(input)?;
+    Ok(tree_patch.best_scenic_score().into())
+}
+
+/// How many columns each column sweep covers. Each sweep reads a run this
+/// long from every row, so it stays in memory order.
+#[cfg(feature = "parallel")]
+const COLUMN_BAND_WIDTH: usize = 1024;
+
+/// Sweep across a row from the left and from the right
+fn sweep_row(trees: &[Tree], visible: &mut [bool]) {
+    // `None` is less than any height
+    let mut tallest = None;
+    for (tree, visible) in trees.iter().zip(visible.iter_mut()) {
+        if Some(tree.height) > tallest {
+            *visible = true;
+            tallest = Some(tree.height);
+        }
+    }
+
+    let mut tallest = None;
+    for (tree, visible) in trees.iter().zip(visible.iter_mut()).rev() {
+        if Some(tree.height) > tallest {
+            *visible = true;
+            tallest = Some(tree.height);
+        }
+    }
+}
+
+/// Continue a sweep down (or up) every column by one row
+fn sweep_row_into_columns(trees: &[Tree], visible: &mut [bool], tallest: &mut [Option<u32>]) {
+    for ((tree, visible), tallest) in trees.iter().zip(visible).zip(tallest) {
+        if Some(tree.height) > *tallest {
+            *visible = true;
+            *tallest = Some(tree.height);
+        }
+    }
+}
+
+/// How to find which trees are visible from outside the patch
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum VisibilityStrategy {
+    /// Sweep across each row and column from both ends, keeping track of
+    /// the tallest tree so far. O(rows·cols).
+    #[default]
+    Sweeps,
+    /// Look out from each tree in every direction until a tree blocks the
+    /// view
+    Rays,
+}
+
+pub struct TreePatch {
+    width: usize,
+    trees: Vec<Tree>,
+}
+
+impl TreePatch {
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        Self::parse_with(input, Format::default())
+    }
+
+    pub fn parse_with(input: &str, format: Format) -> anyhow::Result<Self> {
+        Self::from_reader(input.as_bytes(), format)
+    }
+
+    /// Read the patch a row at a time from `reader`
+    pub fn from_reader<R: BufRead>(reader: R, format: Format) -> anyhow::Result<Self> {
+        let mut tree_patch = Self::new();
+        for (line_index, line) in reader.lines().enumerate() {
+            let line = line.context("failed to read tree patch")?;
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+            tree_patch
+                .parse_row(line, format)
+                .with_context(|| format!("line {}", line_index + 1))?;
+        }
+
+        Ok(tree_patch)
+    }
+
+    /// How many trees can be seen from outside the patch
+    pub fn count_visible(&self, strategy: VisibilityStrategy) -> usize {
+        match strategy {
+            VisibilityStrategy::Sweeps => {
+                self.visible_by_sweeps().into_iter().filter(|&v| v).count()
+            }
+            VisibilityStrategy::Rays => self
+                .indices()
+                .filter(|&index| self.is_visible_by_rays(index))
+                .count(),
+        }
+    }
+
+    /// The highest scenic score of any tree
+    pub fn best_scenic_score(&self) -> u64 {
+        self.indices()
+            .into_maybe_par_iter()
+            .map(|index| self.scenic_score(index))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// The `count` trees with the highest scenic scores, best first. Ties go
+    /// to the tree nearest the top, then nearest the left.
+    pub fn best_viewpoints(&self, count: usize) -> Vec<Viewpoint> {
+        // Min-heap of the best trees so far, so the worst is on top. Among
+        // equal scores, the tree furthest along is the worst
+        let mut best = BinaryHeap::with_capacity(count + 1);
+        for index in self.indices() {
+            best.push(Reverse((self.scenic_score(index), Reverse(index))));
+            if best.len() > count {
+                best.pop();
+            }
+        }
+
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((_, Reverse(index)))| self.viewpoint(index))
+            .collect()
+    }
+
+    /// The patch with only the visible trees drawn
+    pub fn visibility_map(&self) -> VisibilityMap<'_> {
+        VisibilityMap {
+            tree_patch: self,
+            visible: self.visible_by_sweeps(),
+        }
+    }
+
+    /// Every tree's scenic score, laid out like the patch
+    pub fn scenic_map(&self) -> ScenicMap {
+        let scores: Vec<_> = self
+            .indices()
+            .map(|index| self.scenic_score(index))
+            .collect();
+        let cell_width = scores
+            .iter()
+            .map(|score| score.to_string().len())
+            .max()
+            .unwrap_or(1);
+        ScenicMap {
+            width: self.width(),
+            scores,
+            cell_width,
+        }
+    }
+
+    /// Whether each tree is visible, found with four sweeps: every row from
+    /// the left and right, and every column from the top and bottom. The
+    /// column sweeps go a row at a time, keeping the tallest tree so far in
+    /// each column, so all four sweeps read the trees in memory order.
+    #[cfg(not(feature = "parallel"))]
+    fn visible_by_sweeps(&self) -> Vec<bool> {
+        let width = self.width();
+        let mut visible = vec![false; self.trees.len()];
+        if width == 0 {
+            return visible;
+        }
+
+        for (trees, visible) in self.trees.chunks(width).zip(visible.chunks_mut(width)) {
+            sweep_row(trees, visible);
+        }
+
+        // `None` is less than any height
+        let mut tallest = vec![None; width];
+        for (trees, visible) in self.trees.chunks(width).zip(visible.chunks_mut(width)) {
+            sweep_row_into_columns(trees, visible, &mut tallest);
+        }
+
+        let mut tallest = vec![None; width];
+        for (trees, visible) in self
+            .trees
+            .chunks(width)
+            .zip(visible.chunks_mut(width))
+            .rev()
+        {
+            sweep_row_into_columns(trees, visible, &mut tallest);
+        }
+
+        visible
+    }
+
+    /// Like the sequential sweeps, but rows and bands of columns are swept
+    /// independently on rayon's thread pool, each writing straight into its
+    /// own part of the result. The column sweeps go a row at a time over
+    /// each band, so they still read the trees in memory order.
+    #[cfg(feature = "parallel")]
+    fn visible_by_sweeps(&self) -> Vec<bool> {
+        let width = self.width();
+        let mut visible = vec![false; self.trees.len()];
+        if width == 0 {
+            return visible;
+        }
+
+        let rows: Vec<_> = self
+            .trees
+            .chunks(width)
+            .zip(visible.chunks_mut(width))
+            .collect();
+        rows.into_maybe_par_iter()
+            .for_each(|(trees, visible)| sweep_row(trees, visible));
+
+        // Each band gets its own slice of every row
+        let mut bands: Vec<Vec<&mut [bool]>> = (0..width)
+            .step_by(COLUMN_BAND_WIDTH)
+            .map(|_| Vec::with_capacity(self.height()))
+            .collect();
+        for row in visible.chunks_mut(width) {
+            for (band, segment) in bands.iter_mut().zip(row.chunks_mut(COLUMN_BAND_WIDTH)) {
+                band.push(segment);
+            }
+        }
+        let bands: Vec<_> = bands.into_iter().enumerate().collect();
+        bands.into_maybe_par_iter().for_each(|(band, segments)| {
+            self.sweep_column_band(band * COLUMN_BAND_WIDTH, segments);
+        });
+
+        visible
+    }
+
+    /// Sweep down and up the columns starting at `first_column`, where
+    /// `visible` has a segment as wide as the band from each row
+    #[cfg(feature = "parallel")]
+    fn sweep_column_band(&self, first_column: usize, mut visible: Vec<&mut [bool]>) {
+        let width = self.width();
+        let band_width = visible.first().map_or(0, |segment| segment.len());
+        let rows: Vec<&[Tree]> = self
+            .trees
+            .chunks(width)
+            .map(|row| &row[first_column..][..band_width])
+            .collect();
+
+        // `None` is less than any height
+        let mut tallest = vec![None; band_width];
+        for (trees, visible) in rows.iter().zip(visible.iter_mut()) {
+            sweep_row_into_columns(trees, visible, &mut tallest);
+        }
+
+        let mut tallest = vec![None; band_width];
+        for (trees, visible) in rows.iter().zip(visible.iter_mut()).rev() {
+            sweep_row_into_columns(trees, visible, &mut tallest);
+        }
+    }
+
+    fn is_visible_by_rays(&self, index: usize) -> bool {
+        DIRECTIONS.into_iter().any(|direction| {
+            let (mut row, mut col) = self.location(index);
+            let (row_stride, col_stride) = direction.stride();
+            loop {
+                row += row_stride;
+                col += col_stride;
+                match self.index((row, col)) {
+                    Some(other) if self.trees[other].height >= self.trees[index].height => {
+                        return false;
+                    }
+                    Some(_) => {}
+                    None => return true,
+                }
+            }
+        })
+    }
+
+    fn new() -> Self {
+        Self {
+            width: 0,
+            trees: vec![],
+        }
+    }
+
+    /// How many trees are in each row
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// How many rows of trees there are
+    pub fn height(&self) -> usize {
+        if self.width == 0 {
+            return 0;
+        }
+        self.trees.len() / self.width()
+    }
+
+    /// A patch with each row's tree heights
+    pub fn from_rows<const N: usize, const M: usize>(rows: [[u32; M]; N]) -> Self {
+        let width = rows.first().map(|row| row.len()).unwrap_or_default();
+        let mut tree_patch = Self {
+            width,
+            trees: vec![],
+        };
+        for row in rows.iter() {
+            tree_patch
+                .trees
+                .extend(row.iter().map(|&height| Tree { height }));
+        }
+
+        tree_patch
+    }
+
+    fn parse_row(&mut self, row: &str, format: Format) -> anyhow::Result<()> {
+        let mut row = match format {
+            Format::Digits => row
+                .chars()
+                .map(Tree::parse_cell)
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            Format::Csv => row
+                .split([',', ' ', '\t'])
+                .filter(|cell| !cell.is_empty())
+                .map(Tree::parse_number)
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        };
+
+        match self.width {
+            0 => {
+                self.width = row.len();
+            }
+            _ => {
+                anyhow::ensure!(
+                    self.width == row.len(),
+                    "expected {} trees in row, but found {}",
+                    self.width,
+                    row.len()
+                );
+            }
+        }
+
+        self.trees.append(&mut row);
+
+        Ok(())
+    }
+
+    fn indices(&self) -> std::ops::Range<usize> {
+        0..self.trees.len()
+    }
+
+    fn location(&self, index: usize) -> (isize, isize) {
+        let row = index / self.width;
+        let col = index % self.width;
+
+        let row = row.try_into().expect("row overflow");
+        let col = col.try_into().expect("col overflow");
+
+        (row, col)
+    }
+
+    fn index(&self, location: (isize, isize)) -> Option<usize> {
+        let width = self.width();
+        let height = self.height();
+
+        let (row, col) = location;
+        let row: usize = row.try_into().ok()?;
+        let col: usize = col.try_into().ok()?;
+
+        if row < height && col < width {
+            let index = (row * width) + col;
+
+            assert!(index < self.trees.len());
+
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// The tree in `row` and `col`, counting from 0 at the top left
+    pub fn tree(&self, row: usize, col: usize) -> Option<&Tree> {
+        let index = self.index((row.try_into().ok()?, col.try_into().ok()?))?;
+        Some(&self.trees[index])
+    }
+
+    /// How many trees can be seen from the tree at `row` and `col` looking
+    /// in `direction`, up to and including the first one that blocks the
+    /// view
+    pub fn viewing_distance(&self, row: usize, col: usize, direction: Direction) -> Option<u64> {
+        let index = self.index((row.try_into().ok()?, col.try_into().ok()?))?;
+        Some(self.scenic_score_for_direction(index, direction))
+    }
+
+    /// The viewing distances from the tree at `row` and `col` multiplied
+    /// together
+    pub fn scenic_score_at(&self, row: usize, col: usize) -> Option<u64> {
+        let index = self.index((row.try_into().ok()?, col.try_into().ok()?))?;
+        Some(self.scenic_score(index))
+    }
+
+    fn scenic_score_for_direction(&self, index: usize, direction: Direction) -> u64 {
+        let (mut row, mut col) = self.location(index);
+        let (row_stride, col_stride) = direction.stride();
+
+        let mut score = 0;
+        loop {
+            row += row_stride;
+            col += col_stride;
+            let candidate_index = match self.index((row, col)) {
+                Some(index) => index,
+                None => {
+                    break;
+                }
+            };
+
+            let candidate_height = self.trees[candidate_index].height;
+
+            score += 1;
+
+            if candidate_height >= self.trees[index].height {
+                break;
+            }
+        }
+
+        score
+    }
+
+    fn scenic_score(&self, index: usize) -> u64 {
+        DIRECTIONS
+            .into_iter()
+            .map(|direction| self.scenic_score_for_direction(index, direction))
+            .product()
+    }
+
+    fn viewpoint(&self, index: usize) -> Viewpoint {
+        let viewing_distances =
+            DIRECTIONS.map(|direction| self.scenic_score_for_direction(index, direction));
+        Viewpoint {
+            row: index / self.width,
+            col: index % self.width,
+            height: self.trees[index].height,
+            viewing_distances,
+            scenic_score: viewing_distances.iter().product(),
+        }
+    }
+}
+
+/// A tree and how far can be seen from it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewpoint {
+    pub row: usize,
+    pub col: usize,
+    pub height: u32,
+    /// How many trees can be seen in each of [`DIRECTIONS`]
+    pub viewing_distances: [u64; 4],
+    pub scenic_score: u64,
+}
+
+impl std::fmt::Display for Viewpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {}, col {} (height {}): scenic score {}",
+            self.row, self.col, self.height, self.scenic_score
+        )?;
+        for (i, (direction, distance)) in DIRECTIONS.iter().zip(self.viewing_distances).enumerate()
+        {
+            let separator = if i == 0 { " (" } else { ", " };
+            write!(f, "{separator}{direction} {distance}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Draws each visible tree's height (or `#` if it's above 9), and `.` for
+/// trees hidden from every direction
+pub struct VisibilityMap<'a> {
+    tree_patch: &'a TreePatch,
+    visible: Vec<bool>,
+}
+
+impl Renderable for VisibilityMap<'_> {
+    fn x_range(&self) -> RangeInclusive<i64> {
+        0..=(self.tree_patch.width() as i64 - 1)
+    }
+
+    fn y_range(&self) -> RangeInclusive<i64> {
+        0..=(self.tree_patch.height() as i64 - 1)
+    }
+
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        let Some(index) = self.tree_patch.index((y as isize, x as isize)) else {
+            return ' ';
+        };
+
+        if self.visible[index] {
+            let height = self.tree_patch.trees[index].height;
+            char::from_digit(height, 10).unwrap_or('#')
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Draws each tree's scenic score, right-aligned in columns
+pub struct ScenicMap {
+    width: usize,
+    scores: Vec<u64>,
+    cell_width: usize,
+}
+
+impl Renderable for ScenicMap {
+    fn x_range(&self) -> RangeInclusive<i64> {
+        // Each cell is the score, then a space between it and the next cell
+        0..=((self.width * (self.cell_width + 1)) as i64 - 2)
+    }
+
+    fn y_range(&self) -> RangeInclusive<i64> {
+        let height = self.scores.len().checked_div(self.width).unwrap_or(0);
+        0..=(height as i64 - 1)
+    }
+
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        let col = x as usize / (self.cell_width + 1);
+        let offset = x as usize % (self.cell_width + 1);
+        let score = self.scores[y as usize * self.width + col].to_string();
+
+        let padding = self.cell_width - score.len();
+        match offset.checked_sub(padding) {
+            Some(digit) if digit < score.len() => score.as_bytes()[digit].into(),
+            _ => ' ',
+        }
+    }
+}
+
+/// How each row of tree heights is written
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// One digit per tree, like the puzzle input
+    #[default]
+    Digits,
+    /// Numbers separated by commas, spaces, or tabs, for heights above 9
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tree {
+    height: u32,
+}
+
+impl Tree {
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn parse_cell(height: char) -> anyhow::Result<Self> {
+        let height = height
+            .to_digit(10)
+            .with_context(|| format!("invalid tree height: {height:?}"))?;
+        Ok(Self { height })
+    }
+
+    fn parse_number(height: &str) -> anyhow::Result<Self> {
+        let height = height
+            .parse()
+            .with_context(|| format!("invalid tree height: {height:?}"))?;
+        Ok(Self { height })
+    }
+}
+
+/// Which way to look from a tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    TopToBottom,
+    BottomToTop,
+    LeftToRight,
+    RightToLeft,
+}
+
+impl Direction {
+    /// How far one step in this direction moves, as (rows, columns)
+    pub fn stride(&self) -> (isize, isize) {
+        match self {
+            Direction::TopToBottom => (-1, 0),
+            Direction::BottomToTop => (1, 0),
+            Direction::LeftToRight => (0, -1),
+            Direction::RightToLeft => (0, 1),
+        }
+    }
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Which way the direction's stride looks from a tree
+        let name = match self {
+            Direction::TopToBottom => "up",
+            Direction::BottomToTop => "down",
+            Direction::LeftToRight => "left",
+            Direction::RightToLeft => "right",
+        };
+        f.write_str(name)
+    }
+}
+
+pub const DIRECTIONS: [Direction; 4] = [
+    Direction::TopToBottom,
+    Direction::BottomToTop,
+    Direction::LeftToRight,
+    Direction::RightToLeft,
+];
@@ -0,0 +1,52 @@
+use common::render::Renderable;
+use day8::{TreePatch, EXAMPLE};
+
+#[test]
+fn example_visibility_map() {
+    let tree_patch = TreePatch::parse(EXAMPLE).unwrap();
+    let expected = "\
+30373
+255.2
+65.32
+3.5.9
+35390";
+    assert_eq!(tree_patch.visibility_map().render(), expected);
+}
+
+#[test]
+fn example_scenic_map() {
+    let tree_patch = TreePatch::parse(EXAMPLE).unwrap();
+    let expected = "\
+0 0 0 0 0
+0 1 4 1 0
+0 6 1 2 0
+0 1 8 3 0
+0 0 0 0 0";
+    assert_eq!(tree_patch.scenic_map().render(), expected);
+}
+
+#[test]
+fn wide_scenic_scores() {
+    let tree_patch = TreePatch::from_rows([
+        [0, 0, 0, 0, 0],
+        [0, 9, 0, 0, 0],
+        [0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0],
+    ]);
+    let expected = "\
+0 0 0 0 0
+0 6 1 1 0
+0 1 1 1 0
+0 0 0 0 0";
+    assert_eq!(tree_patch.scenic_map().render(), expected);
+
+    // A peak in the middle of a 13×13 patch can see 6 trees every way, so
+    // every score gets padded to 4 digits
+    let mut rows = [[0; 13]; 13];
+    rows[6][6] = 9;
+    let scenic_map = TreePatch::from_rows(rows).scenic_map().render();
+    let peak_row = scenic_map.lines().nth(6).unwrap();
+    assert!(peak_row.starts_with("   0    1    1"));
+    assert_eq!(peak_row.split_whitespace().nth(6), Some("1296"));
+    assert_eq!(peak_row.len(), 13 * 5 - 1);
+}
@@ -0,0 +1,55 @@
+use day8::{Format, TreePatch, VisibilityStrategy, EXAMPLE};
+
+#[test]
+fn csv_matches_digits() {
+    let csv: String = EXAMPLE
+        .lines()
+        .map(|line| {
+            let cells: Vec<_> = line.chars().map(String::from).collect();
+            cells.join(",") + "\n"
+        })
+        .collect();
+
+    let digits = TreePatch::parse_with(EXAMPLE, Format::Digits).unwrap();
+    let csv = TreePatch::parse_with(&csv, Format::Csv).unwrap();
+    assert_eq!(
+        csv.count_visible(VisibilityStrategy::Sweeps),
+        digits.count_visible(VisibilityStrategy::Sweeps)
+    );
+    assert_eq!(csv.best_scenic_score(), digits.best_scenic_score());
+}
+
+#[test]
+fn tall_trees() {
+    let input = "\
+10, 20, 15
+12  100 14
+1000,5,\t3
+";
+    let tree_patch = TreePatch::parse_with(input, Format::Csv).unwrap();
+    for strategy in [VisibilityStrategy::Sweeps, VisibilityStrategy::Rays] {
+        assert_eq!(tree_patch.count_visible(strategy), 9);
+    }
+    assert_eq!(tree_patch.best_scenic_score(), 1);
+
+    // A shorter tree in the middle would be hidden
+    let tree_patch = TreePatch::from_rows([[10, 20, 15], [12, 4, 14], [1000, 5, 3]]);
+    assert_eq!(tree_patch.count_visible(VisibilityStrategy::Sweeps), 8);
+}
+
+#[test]
+fn invalid_rows() {
+    let invalid = [
+        ("12a\n", Format::Digits),
+        ("123\n12\n", Format::Digits),
+        ("1,2,x\n", Format::Csv),
+        ("1,2,3\n1,2\n", Format::Csv),
+        ("1,-2\n", Format::Csv),
+    ];
+    for (input, format) in invalid {
+        assert!(
+            TreePatch::parse_with(input, format).is_err(),
+            "{input:?} should be invalid as {format:?}"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use std::io::BufReader;
+
+use day8::{Direction, Format, TreePatch, EXAMPLE};
+
+#[test]
+fn reader_matches_parse() {
+    let from_reader =
+        TreePatch::from_reader(BufReader::new(EXAMPLE.as_bytes()), Format::Digits).unwrap();
+    let parsed = TreePatch::parse(EXAMPLE).unwrap();
+
+    assert_eq!(from_reader.width(), 5);
+    assert_eq!(from_reader.height(), 5);
+    for row in 0..5 {
+        for col in 0..5 {
+            assert_eq!(from_reader.tree(row, col), parsed.tree(row, col));
+        }
+    }
+}
+
+#[test]
+fn reader_accepts_crlf() {
+    let input = EXAMPLE.replace('\n', "\r\n");
+    let tree_patch = TreePatch::from_reader(input.as_bytes(), Format::Digits).unwrap();
+    assert_eq!(tree_patch.width(), 5);
+    assert_eq!(tree_patch.best_scenic_score(), 8);
+}
+
+#[test]
+fn example_viewing_distances() {
+    let tree_patch = TreePatch::parse(EXAMPLE).unwrap();
+
+    assert_eq!(tree_patch.tree(3, 2).map(|tree| tree.height()), Some(5));
+    assert_eq!(
+        tree_patch.viewing_distance(3, 2, Direction::TopToBottom),
+        Some(2)
+    );
+    assert_eq!(
+        tree_patch.viewing_distance(3, 2, Direction::LeftToRight),
+        Some(2)
+    );
+    assert_eq!(
+        tree_patch.viewing_distance(3, 2, Direction::BottomToTop),
+        Some(1)
+    );
+    assert_eq!(
+        tree_patch.viewing_distance(3, 2, Direction::RightToLeft),
+        Some(2)
+    );
+    assert_eq!(tree_patch.scenic_score_at(3, 2), Some(8));
+
+    assert_eq!(tree_patch.tree(5, 0), None);
+    assert_eq!(tree_patch.scenic_score_at(0, 5), None);
+}
@@ -0,0 +1,57 @@
+use day8::{TreePatch, Viewpoint, EXAMPLE};
+
+#[test]
+fn example_best_viewpoints() {
+    let tree_patch = TreePatch::parse(EXAMPLE).unwrap();
+    let viewpoints = tree_patch.best_viewpoints(3);
+
+    assert_eq!(
+        viewpoints,
+        [
+            Viewpoint {
+                row: 3,
+                col: 2,
+                height: 5,
+                viewing_distances: [2, 1, 2, 2],
+                scenic_score: 8,
+            },
+            Viewpoint {
+                row: 2,
+                col: 1,
+                height: 5,
+                viewing_distances: [1, 2, 1, 3],
+                scenic_score: 6,
+            },
+            Viewpoint {
+                row: 1,
+                col: 2,
+                height: 5,
+                viewing_distances: [1, 2, 1, 2],
+                scenic_score: 4,
+            },
+        ]
+    );
+    assert_eq!(
+        viewpoints[0].to_string(),
+        "row 3, col 2 (height 5): scenic score 8 (up 2, down 1, left 2, right 2)"
+    );
+    assert_eq!(viewpoints[0].scenic_score, tree_patch.best_scenic_score());
+}
+
+#[test]
+fn ties_go_to_the_first_tree() {
+    let tree_patch = TreePatch::from_rows([[1, 1, 1], [1, 1, 1], [1, 1, 1]]);
+    let viewpoints = tree_patch.best_viewpoints(2);
+    let locations: Vec<_> = viewpoints
+        .iter()
+        .map(|viewpoint| (viewpoint.row, viewpoint.col))
+        .collect();
+    assert_eq!(locations, [(1, 1), (0, 0)]);
+}
+
+#[test]
+fn more_viewpoints_than_trees() {
+    let tree_patch = TreePatch::from_rows([[3, 1]]);
+    assert_eq!(tree_patch.best_viewpoints(5).len(), 2);
+    assert!(TreePatch::parse("").unwrap().best_viewpoints(1).is_empty());
+}
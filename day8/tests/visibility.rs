@@ -0,0 +1,72 @@
+use day8::{TreePatch, VisibilityStrategy, EXAMPLE};
+
+const STRATEGIES: [VisibilityStrategy; 2] = [VisibilityStrategy::Sweeps, VisibilityStrategy::Rays];
+
+#[test]
+fn example_visibility() {
+    let tree_patch = TreePatch::parse(EXAMPLE).unwrap();
+    for strategy in STRATEGIES {
+        assert_eq!(tree_patch.count_visible(strategy), 21, "{strategy:?}");
+    }
+}
+
+#[test]
+fn strategies_agree() {
+    // Pseudorandom patches of different shapes, with only a few heights so
+    // there are plenty of ties. The widest is split across several column
+    // bands.
+    let mut seed = 0x8u64;
+    for (rows, cols) in [
+        (1, 1),
+        (1, 7),
+        (7, 1),
+        (2, 2),
+        (5, 9),
+        (16, 16),
+        (31, 17),
+        (6, 2100),
+    ] {
+        for max_height in [1, 3, 9] {
+            let input: String = (0..rows)
+                .map(|_| {
+                    let row: String = (0..cols)
+                        .map(|_| {
+                            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                            let height = (seed >> 33) % (max_height + 1);
+                            char::from_digit(height as u32, 10).unwrap()
+                        })
+                        .collect();
+                    row + "\n"
+                })
+                .collect();
+
+            let tree_patch = TreePatch::parse(&input).unwrap();
+            assert_eq!(
+                tree_patch.count_visible(VisibilityStrategy::Sweeps),
+                tree_patch.count_visible(VisibilityStrategy::Rays),
+                "{input}"
+            );
+        }
+    }
+}
+
+#[test]
+fn empty_patch() {
+    let tree_patch = TreePatch::parse("").unwrap();
+    for strategy in STRATEGIES {
+        assert_eq!(tree_patch.count_visible(strategy), 0);
+    }
+}
+
+#[test]
+fn hidden_center() {
+    let tree_patch = TreePatch::from_rows([[5, 5, 5], [5, 4, 5], [5, 5, 5]]);
+    for strategy in STRATEGIES {
+        assert_eq!(tree_patch.count_visible(strategy), 8);
+    }
+
+    let tree_patch = TreePatch::from_rows([[5, 5, 5], [5, 6, 5], [5, 5, 5]]);
+    for strategy in STRATEGIES {
+        assert_eq!(tree_patch.count_visible(strategy), 9);
+    }
+}
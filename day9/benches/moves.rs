@@ -0,0 +1,40 @@
+use common::simulation;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use day9::{Move, Rope, LONG_ROPE_KNOTS};
+
+/// A few very long moves, where stepping one position at a time spends
+/// almost all its time moving knots that have already lined up
+fn long_moves() -> Vec<Move> {
+    day9::parse_moves("R 1000000\nU 1000000\nL 1000000\nD 1000000\n").unwrap()
+}
+
+fn moves(c: &mut Criterion) {
+    let moves = long_moves();
+
+    let mut group = c.benchmark_group("long_moves");
+    group.sample_size(10);
+    group.bench_function("per_step", |b| {
+        b.iter_batched(
+            || Rope::new(LONG_ROPE_KNOTS, moves.clone()),
+            |mut rope| {
+                simulation::run(&mut rope);
+                rope.visited_positions()
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("run_length", |b| {
+        b.iter_batched(
+            || Rope::new(LONG_ROPE_KNOTS, moves.clone()),
+            |mut rope| {
+                rope.finish();
+                rope.visited_positions()
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, moves);
+criterion_main!(benches);
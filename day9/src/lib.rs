@@ -0,0 +1,471 @@
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    ops::{Add, AddAssign, ControlFlow, Mul, RangeInclusive, Sub},
+    str::FromStr,
+};
+
+use common::{answer::Answer, diagnostics::ParseError, render::Renderable, simulation::Simulation};
+
+pub const EXAMPLE: &str = include_str!("../fixtures/example1.txt");
+pub const LARGER_EXAMPLE: &str = include_str!("../fixtures/example2.txt");
+
+/// How many knots are in the rope for part 1
+pub const SHORT_ROPE_KNOTS: usize = 2;
+
+/// How many knots are in the rope for part 2
+pub const LONG_ROPE_KNOTS: usize = 10;
+
+pub fn solve_part1(input: &str) -> eyre::Result<Answer> {
+    let mut rope = Rope::new(SHORT_ROPE_KNOTS, parse_moves(input)?);
+    rope.finish();
+    Ok(rope.visited_positions().into())
+}
+
+pub fn solve_part2(input: &str) -> eyre::Result<Answer> {
+    let mut rope = Rope::new(LONG_ROPE_KNOTS, parse_moves(input)?);
+    rope.finish();
+    Ok(rope.visited_positions().into())
+}
+
+// Each move of the head, in order
+pub fn parse_moves(input: &str) -> eyre::Result<Vec<Move>> {
+    let mut moves = vec![];
+
+    for line in input.lines() {
+        let (direction, repeat) = parse_move(line)
+            .map_err(|error| ParseError::at(input, line, format_args!("{error:#}")))?;
+
+        moves.push(Move { direction, repeat });
+    }
+
+    Ok(moves)
+}
+
+/// One line of the input: the head moves `repeat` positions in `direction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub direction: Direction,
+    /// Never negative, and small enough for a move to fit in a [`Vector`]
+    pub repeat: i32,
+}
+
+fn parse_move(line: &str) -> eyre::Result<(Direction, i32)> {
+    let mut fields = line.split_whitespace();
+    let direction: Direction = fields
+        .next()
+        .ok_or_else(|| eyre::eyre!("no direction field"))?
+        .parse()?;
+    let repeat: u64 = fields
+        .next()
+        .ok_or_else(|| eyre::eyre!("no repeat field"))?
+        .parse()?;
+    let repeat = i32::try_from(repeat)
+        .map_err(|_| eyre::eyre!("moving {repeat} positions is out of range"))?;
+
+    Ok((direction, repeat))
+}
+
+pub struct Rope {
+    knot_positions: Vec<Position>,
+    last_positions: HashSet<Position>,
+    moves: std::vec::IntoIter<Move>,
+    // What's left of the move the simulation is partway through
+    current_move: Option<Move>,
+}
+
+impl Rope {
+    pub fn new(knots: usize, moves: Vec<Move>) -> Self {
+        let initial_position = Position { x: 0, y: 0 };
+        Self {
+            knot_positions: vec![initial_position; knots],
+            last_positions: HashSet::from([initial_position]),
+            moves: moves.into_iter(),
+            current_move: None,
+        }
+    }
+
+    /// Make every remaining move, a whole move at a time instead of a step
+    /// at a time like [`Simulation::step`]
+    pub fn finish(&mut self) {
+        let current_move = self.current_move.take();
+        let moves: Vec<_> = current_move.into_iter().chain(&mut self.moves).collect();
+        for Move { direction, repeat } in moves {
+            self.move_head_by(direction, repeat);
+        }
+    }
+
+    /// Move the head `repeat` positions in `direction` at once. A knot only
+    /// depends on the positions the knot ahead of it goes through, so each
+    /// knot follows the whole path of the one ahead before the next knot
+    /// moves.
+    fn move_head_by(&mut self, direction: Direction, repeat: i32) {
+        let mut path = vec![Run {
+            step: direction.vector(),
+            count: repeat,
+        }];
+        let mut leader_start = None;
+        for knot in &mut self.knot_positions {
+            let start = *knot;
+            if let Some(leader_start) = leader_start {
+                path = follow_path(leader_start, &path, start);
+            }
+            for run in &path {
+                *knot += run.step * run.count;
+            }
+
+            leader_start = Some(start);
+        }
+
+        // The last knot to move was the tail, so `path` is the tail's path
+        if let Some(mut last) = leader_start {
+            for run in &path {
+                for _ in 0..run.count {
+                    last += run.step;
+                    self.last_positions.insert(last);
+                }
+            }
+        }
+    }
+
+    fn move_head(&mut self, direction: Direction) {
+        if let Some(first) = self.knot_positions.first_mut() {
+            *first += direction.vector();
+        }
+
+        // Each knot follows the one before it, after that one has moved
+        for tail in 1..self.knot_positions.len() {
+            let head = self.knot_positions[tail - 1];
+            self.knot_positions[tail] = adjust_tail_position(head, self.knot_positions[tail]);
+        }
+
+        if let Some(&last) = self.knot_positions.last() {
+            self.last_positions.insert(last);
+        }
+    }
+
+    pub fn visited_positions(&self) -> usize {
+        self.last_positions.len()
+    }
+
+    /// Every position the tail has visited so far
+    pub fn visited_map(&self) -> VisitedMap<'_> {
+        VisitedMap {
+            positions: &self.last_positions,
+        }
+    }
+}
+
+impl Simulation for Rope {
+    type Event = ();
+
+    fn step(&mut self) -> ControlFlow<()> {
+        let direction = loop {
+            match &mut self.current_move {
+                Some(current_move) if current_move.repeat > 0 => {
+                    current_move.repeat -= 1;
+                    break current_move.direction;
+                }
+                _ => {
+                    let Some(next_move) = self.moves.next() else {
+                        return ControlFlow::Break(());
+                    };
+                    self.current_move = Some(next_move);
+                }
+            }
+        };
+
+        self.move_head(direction);
+        ControlFlow::Continue(())
+    }
+}
+
+impl Renderable for Rope {
+    const Y_UP: bool = true;
+
+    fn x_range(&self) -> RangeInclusive<i64> {
+        let xs = self.knot_positions.iter().map(|pos| pos.x);
+        let x_min = xs.clone().min().unwrap();
+        let x_max = xs.max().unwrap();
+        (i64::from(x_min) - 1)..=(i64::from(x_max) + 1)
+    }
+
+    fn y_range(&self) -> RangeInclusive<i64> {
+        let ys = self.knot_positions.iter().map(|pos| pos.y);
+        let y_min = ys.clone().min().unwrap();
+        let y_max = ys.max().unwrap();
+        (i64::from(y_min) - 1)..=(i64::from(y_max) + 1)
+    }
+
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        let pos = Position {
+            x: x.try_into().unwrap(),
+            y: y.try_into().unwrap(),
+        };
+        self.knot_positions
+            .iter()
+            .position(|&knot| knot == pos)
+            .map(|n| match n.try_into().unwrap() {
+                0 => 'H',
+                n => char::from_digit(n, 16).unwrap_or('-'),
+            })
+            .unwrap_or('.')
+    }
+}
+
+/// Draws the positions the tail visited as `#`, with the starting position
+/// as `s`
+pub struct VisitedMap<'a> {
+    positions: &'a HashSet<Position>,
+}
+
+impl Renderable for VisitedMap<'_> {
+    const Y_UP: bool = true;
+
+    fn x_range(&self) -> RangeInclusive<i64> {
+        // The tail starts at the origin, so there's always at least one
+        // position
+        let xs = self.positions.iter().map(|pos| pos.x);
+        let x_min = xs.clone().min().unwrap();
+        let x_max = xs.max().unwrap();
+        i64::from(x_min)..=i64::from(x_max)
+    }
+
+    fn y_range(&self) -> RangeInclusive<i64> {
+        let ys = self.positions.iter().map(|pos| pos.y);
+        let y_min = ys.clone().min().unwrap();
+        let y_max = ys.max().unwrap();
+        i64::from(y_min)..=i64::from(y_max)
+    }
+
+    fn render_cell(&self, x: i64, y: i64) -> char {
+        let pos = Position {
+            x: x.try_into().unwrap(),
+            y: y.try_into().unwrap(),
+        };
+        if pos == (Position { x: 0, y: 0 }) {
+            's'
+        } else if self.positions.contains(&pos) {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Part of a knot's path: `count` moves of `step` in a row
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    step: Vector,
+    count: i32,
+}
+
+/// The path a knot starting at `tail` takes to follow a knot starting at
+/// `leader` along `path`. Steps where the tail doesn't move are left out,
+/// since the knot behind it wouldn't move either.
+fn follow_path(mut leader: Position, path: &[Run], mut tail: Position) -> Vec<Run> {
+    let mut tail_path: Vec<Run> = vec![];
+    for run in path {
+        let mut remaining = run.count;
+        while remaining > 0 {
+            leader += run.step;
+            remaining -= 1;
+
+            let next = adjust_tail_position(leader, tail);
+            let step = next - tail;
+            let count = if step == run.step {
+                // The tail is the same distance behind as before this step,
+                // so it'll keep matching the leader until the run ends
+                leader += run.step * remaining;
+                std::mem::take(&mut remaining) + 1
+            } else if step == Vector::ZERO {
+                continue;
+            } else {
+                1
+            };
+            tail += step * count;
+
+            match tail_path.last_mut() {
+                Some(last) if last.step == step => last.count += count,
+                _ => tail_path.push(Run { step, count }),
+            }
+        }
+    }
+
+    tail_path
+}
+
+/// Where a knot moves to after the knot ahead of it (`head`) moves. It
+/// stays put while the two are touching, and otherwise steps one position
+/// towards the head, diagonally if they aren't in the same row or column.
+pub fn adjust_tail_position(head: Position, tail: Position) -> Position {
+    if head.is_touching(tail) {
+        return tail;
+    }
+
+    let adjustment = (head - tail).normalize();
+
+    tail + adjustment
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Position {
+    const NEIGHBOR_OFFSETS: [Vector; 9] = [
+        Vector { x: -1, y: -1 },
+        Vector { x: -1, y: 0 },
+        Vector { x: -1, y: 1 },
+        Vector { x: 0, y: -1 },
+        Vector { x: 0, y: 0 },
+        Vector { x: 0, y: 1 },
+        Vector { x: 1, y: -1 },
+        Vector { x: 1, y: 0 },
+        Vector { x: 1, y: 1 },
+    ];
+
+    fn is_touching(self, other: Position) -> bool {
+        for offset in Self::NEIGHBOR_OFFSETS {
+            if self + offset == other {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Pos({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Vector {
+    const ZERO: Self = Self { x: 0, y: 0 };
+
+    fn normalize(self) -> Self {
+        let x = match self.x {
+            i32::MIN..=-1 => -1,
+            0 => 0,
+            1..=i32::MAX => 1,
+        };
+        let y = match self.y {
+            i32::MIN..=-1 => -1,
+            0 => 0,
+            1..=i32::MAX => 1,
+        };
+
+        Self { x, y }
+    }
+}
+
+impl Display for Vector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Vec({}, {})", self.x, self.y)
+    }
+}
+
+impl Add<Vector> for Position {
+    type Output = Position;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Position {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl AddAssign<Vector> for Position {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Vector {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl Add<Position> for Vector {
+    type Output = Position;
+
+    fn add(self, rhs: Position) -> Self::Output {
+        Position {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub<Position> for Position {
+    type Output = Vector;
+
+    fn sub(self, rhs: Position) -> Self::Output {
+        Vector {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector { x: 0, y: 1 },
+            Direction::Down => Vector { x: 0, y: -1 },
+            Direction::Left => Vector { x: -1, y: 0 },
+            Direction::Right => Vector { x: 1, y: 0 },
+            Direction::UpLeft => Vector { x: -1, y: 1 },
+            Direction::UpRight => Vector { x: 1, y: 1 },
+            Direction::DownLeft => Vector { x: -1, y: -1 },
+            Direction::DownRight => Vector { x: 1, y: -1 },
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            "UL" => Ok(Self::UpLeft),
+            "UR" => Ok(Self::UpRight),
+            "DL" => Ok(Self::DownLeft),
+            "DR" => Ok(Self::DownRight),
+            other => Err(eyre::eyre!("invalid direction: {other:?}")),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use common::{render::Renderable, simulation};
+use day9::{Rope, SHORT_ROPE_KNOTS};
+
+fn simulate(knots: usize, input: &str) -> Rope {
+    let mut rope = Rope::new(knots, day9::parse_moves(input).unwrap());
+    simulation::run(&mut rope);
+    rope
+}
+
+#[test]
+fn diagonal_head_moves() {
+    let rope = simulate(1, "UR 2\nDR 1\nDL 3\nUL 1\n");
+    let expected = "\
+...#.
+..#.#
+.s.#.
+#.#..
+.#...";
+    assert_eq!(rope.visited_map().render(), expected);
+}
+
+#[test]
+fn tail_follows_diagonal_moves() {
+    // The tail stays one step behind the head along the diagonal
+    let rope = simulate(SHORT_ROPE_KNOTS, "UR 4\n");
+    assert_eq!(rope.visited_positions(), 4);
+
+    // Mixing diagonal and straight moves
+    let rope = simulate(SHORT_ROPE_KNOTS, "R 2\nUL 2\nDR 3\n");
+    let expected = "\
+#..
+s#.
+..#";
+    assert_eq!(rope.visited_map().render(), expected);
+}
+
+#[test]
+fn invalid_diagonal() {
+    assert!(day9::parse_moves("UD 1\n").is_err());
+    assert!(day9::parse_moves("RL 1\n").is_err());
+}
@@ -0,0 +1,22 @@
+use common::simulation;
+use day9::{Rope, EXAMPLE, LARGER_EXAMPLE, LONG_ROPE_KNOTS, SHORT_ROPE_KNOTS};
+
+fn visited_positions(knots: usize, input: &str) -> usize {
+    let mut rope = Rope::new(knots, day9::parse_moves(input).unwrap());
+    simulation::run(&mut rope);
+    rope.visited_positions()
+}
+
+#[test]
+fn example_ropes() {
+    assert_eq!(visited_positions(SHORT_ROPE_KNOTS, EXAMPLE), 13);
+    assert_eq!(visited_positions(LONG_ROPE_KNOTS, EXAMPLE), 1);
+    assert_eq!(visited_positions(LONG_ROPE_KNOTS, LARGER_EXAMPLE), 36);
+}
+
+#[test]
+fn head_only_rope() {
+    // The head visits every position it moves through
+    assert_eq!(visited_positions(1, "R 4\nU 2\n"), 7);
+}
+
@@ -0,0 +1,39 @@
+use common::{render::Renderable, simulation};
+use day9::{Rope, EXAMPLE, LARGER_EXAMPLE, LONG_ROPE_KNOTS, SHORT_ROPE_KNOTS};
+
+fn simulate(knots: usize, input: &str) -> Rope {
+    let mut rope = Rope::new(knots, day9::parse_moves(input).unwrap());
+    simulation::run(&mut rope);
+    rope
+}
+
+#[test]
+fn example_map() {
+    let rope = simulate(SHORT_ROPE_KNOTS, EXAMPLE);
+    let expected = "\
+..##.
+...##
+.####
+....#
+s###.";
+    assert_eq!(rope.visited_map().render(), expected);
+}
+
+#[test]
+fn larger_example_map() {
+    let rope = simulate(LONG_ROPE_KNOTS, LARGER_EXAMPLE);
+    let expected = "\
+#.....................
+#.............###.....
+#............#...#....
+.#..........#.....#...
+..#..........#.....#..
+...#........#.......#.
+....#......s.........#
+.....#..............#.
+......#............#..
+.......#..........#...
+........#........#....
+.........########.....";
+    assert_eq!(rope.visited_map().render(), expected);
+}
@@ -0,0 +1,95 @@
+use common::{
+    render::Renderable,
+    simulation::{self, Simulation},
+};
+use day9::{Direction, Move, Rope, LARGER_EXAMPLE};
+
+const DIRECTIONS: [Direction; 8] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+    Direction::UpLeft,
+    Direction::UpRight,
+    Direction::DownLeft,
+    Direction::DownRight,
+];
+
+/// Pseudorandom moves in every direction, some long enough for every knot
+/// to line up behind the head
+fn pseudorandom_moves(seed: u64, count: usize) -> Vec<Move> {
+    let mut seed = seed;
+    (0..count)
+        .map(|_| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let direction = DIRECTIONS[(seed >> 33) as usize % DIRECTIONS.len()];
+            let repeat = ((seed >> 40) % 25) as i32;
+            Move { direction, repeat }
+        })
+        .collect()
+}
+
+fn assert_same_ropes(knots: usize, moves: Vec<Move>) {
+    let mut stepped = Rope::new(knots, moves.clone());
+    simulation::run(&mut stepped);
+
+    let mut finished = Rope::new(knots, moves);
+    finished.finish();
+
+    assert_eq!(finished.visited_positions(), stepped.visited_positions());
+    assert_eq!(
+        finished.visited_map().render(),
+        stepped.visited_map().render()
+    );
+    assert_eq!(finished.render(), stepped.render());
+}
+
+#[test]
+fn run_length_matches_steps() {
+    for knots in [1, 2, 3, 10, 25] {
+        for seed in 0..10 {
+            assert_same_ropes(knots, pseudorandom_moves(seed, 50));
+        }
+
+        assert_same_ropes(knots, day9::parse_moves(LARGER_EXAMPLE).unwrap());
+    }
+}
+
+#[test]
+fn finish_partway_through_a_move() {
+    let moves = day9::parse_moves(LARGER_EXAMPLE).unwrap();
+
+    let mut stepped = Rope::new(10, moves.clone());
+    simulation::run(&mut stepped);
+
+    let mut finished = Rope::new(10, moves);
+    for _ in 0..7 {
+        assert!(finished.step().is_continue());
+    }
+    finished.finish();
+
+    assert_eq!(finished.visited_positions(), stepped.visited_positions());
+    assert_eq!(finished.render(), stepped.render());
+}
+
+#[test]
+fn long_moves() {
+    let moves = day9::parse_moves("R 20000\nU 5\nL 20000\nDL 3\n").unwrap();
+    assert_same_ropes(10, moves);
+}
+
+#[test]
+fn longest_move() {
+    let moves = day9::parse_moves("R 2147483647\n").unwrap();
+    assert_eq!(moves[0].repeat, i32::MAX);
+}
+
+#[test]
+fn out_of_range_move() {
+    for input in ["U 1\nR 3000000000\n", "U 1\nL 2147483648\n"] {
+        let error = day9::parse_moves(input).unwrap_err();
+        let message = format!("{error:#}");
+        assert!(message.contains("out of range"), "{message}");
+        assert!(message.contains("line 2"), "{message}");
+    }
+}
@@ -0,0 +1,54 @@
+use day9::{adjust_tail_position, Position};
+
+fn pos(x: i32, y: i32) -> Position {
+    Position { x, y }
+}
+
+#[test]
+fn touching_tail_stays_put() {
+    let tail = pos(0, 0);
+    for x in -1..=1 {
+        for y in -1..=1 {
+            assert_eq!(
+                adjust_tail_position(pos(x, y), tail),
+                tail,
+                "head at ({x}, {y})"
+            );
+        }
+    }
+}
+
+#[test]
+fn straight_moves() {
+    let tail = pos(0, 0);
+    assert_eq!(adjust_tail_position(pos(2, 0), tail), pos(1, 0));
+    assert_eq!(adjust_tail_position(pos(-2, 0), tail), pos(-1, 0));
+    assert_eq!(adjust_tail_position(pos(0, 2), tail), pos(0, 1));
+    assert_eq!(adjust_tail_position(pos(0, -2), tail), pos(0, -1));
+}
+
+#[test]
+fn knight_moves() {
+    // The head is two steps away in one axis and one in the other, so the
+    // tail steps diagonally to line back up behind it
+    let tail = pos(0, 0);
+    assert_eq!(adjust_tail_position(pos(2, 1), tail), pos(1, 1));
+    assert_eq!(adjust_tail_position(pos(1, 2), tail), pos(1, 1));
+    assert_eq!(adjust_tail_position(pos(-2, 1), tail), pos(-1, 1));
+    assert_eq!(adjust_tail_position(pos(-1, 2), tail), pos(-1, 1));
+    assert_eq!(adjust_tail_position(pos(2, -1), tail), pos(1, -1));
+    assert_eq!(adjust_tail_position(pos(1, -2), tail), pos(1, -1));
+    assert_eq!(adjust_tail_position(pos(-2, -1), tail), pos(-1, -1));
+    assert_eq!(adjust_tail_position(pos(-1, -2), tail), pos(-1, -1));
+}
+
+#[test]
+fn diagonal_moves() {
+    // With more than two knots, a knot can move diagonally and leave the
+    // one behind it two steps away in both axes
+    let tail = pos(0, 0);
+    assert_eq!(adjust_tail_position(pos(2, 2), tail), pos(1, 1));
+    assert_eq!(adjust_tail_position(pos(-2, 2), tail), pos(-1, 1));
+    assert_eq!(adjust_tail_position(pos(2, -2), tail), pos(1, -1));
+    assert_eq!(adjust_tail_position(pos(-2, -2), tail), pos(-1, -1));
+}
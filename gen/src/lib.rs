@@ -0,0 +1,124 @@
+// Synthesizes large, valid puzzle inputs, so performance work can be tested
+// beyond the size of the real inputs
+
+use common::rng::Rng;
+
+// An x, y pair
+type Point = (i32, i32);
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub seed: u64,
+    // The day 15 search area, which sensors are placed within
+    pub max_bounds: i32,
+}
+
+/// Generate an input for `day`. What `scale` counts depends on the day:
+/// the side of the tree grid for day 8, the number of rock paths for day 14,
+/// and the number of sensors for day 15.
+pub fn generate(day: u8, scale: usize, options: &Options) -> eyre::Result<String> {
+    let mut rng = Rng::new(options.seed);
+    match day {
+        8 => Ok(generate_trees(scale, &mut rng)),
+        14 => Ok(generate_rock_paths(scale, &mut rng)),
+        15 => generate_sensors(scale, options.max_bounds, &mut rng),
+        day => eyre::bail!("no generator for day {day}"),
+    }
+}
+
+/// A `size`×`size` grid of tree heights.
+fn generate_trees(size: usize, rng: &mut Rng) -> String {
+    let mut output = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        output.extend((0..size).map(|_| char::from(b'0' + rng.below(10) as u8)));
+        output.push('\n');
+    }
+
+    output
+}
+
+/// `count` rock paths, each a few segments long and a little deeper than the
+/// last, starting somewhere under the cone of sand poured from `500,0`.
+fn generate_rock_paths(count: usize, rng: &mut Rng) -> String {
+    let mut output = String::new();
+    for i in 0..count {
+        let depth = 2 + 3 * i as i32;
+        let mut point = (500 + rng.between(-depth..=depth), depth);
+        output.push_str(&format!("{},{}", point.0, point.1));
+
+        // Alternate between horizontal and vertical segments, only ever
+        // going down so the path stays below the source
+        let segments = 1 + rng.below(4);
+        let mut horizontal = rng.coin();
+        for _ in 0..segments {
+            let length = rng.between(1..=6);
+            if horizontal {
+                point.0 += if rng.coin() { length } else { -length };
+            } else {
+                point.1 += length;
+            }
+            horizontal = !horizontal;
+
+            output.push_str(&format!(" -> {},{}", point.0, point.1));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// `count` sensors in the search area. Every sensor's closest beacon is
+/// nearer than a hidden point somewhere in the search area, so that point is
+/// never covered. Other points may be left uncovered too. Like the real
+/// inputs, no beacon is in range of a sensor other than its own.
+fn generate_sensors(count: usize, max_bounds: i32, rng: &mut Rng) -> eyre::Result<String> {
+    eyre::ensure!(max_bounds >= 2, "max bounds must be at least 2");
+
+    let distance = |a: Point, b: Point| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+    let distress = (rng.between(0..=max_bounds), rng.between(0..=max_bounds));
+
+    // Sensors, their beacons, and their radii
+    let mut placed: Vec<(Point, Point, i32)> = Vec::with_capacity(count);
+    let mut attempts = 0;
+    while placed.len() < count {
+        attempts += 1;
+        eyre::ensure!(
+            attempts <= 1000 * (count + 1),
+            "search area is too crowded for {count} sensors"
+        );
+
+        // The beacon needs to be at least 1 away from the sensor, and nearer
+        // than the distress beacon or any other beacon
+        let sensor = (rng.between(0..=max_bounds), rng.between(0..=max_bounds));
+        let nearest = placed
+            .iter()
+            .map(|&(_, beacon, _)| distance(sensor, beacon))
+            .fold(distance(sensor, distress), std::cmp::min);
+        if nearest < 2 {
+            continue;
+        }
+
+        let radius = rng.between(1..=nearest - 1);
+        let dx = rng.between(-radius..=radius);
+        let dy = radius - dx.abs();
+        let dy = if rng.coin() { dy } else { -dy };
+        let beacon = (sensor.0 + dx, sensor.1 + dy);
+
+        let in_range = placed
+            .iter()
+            .any(|&(other, _, other_radius)| distance(other, beacon) <= other_radius);
+        if !in_range {
+            placed.push((sensor, beacon, radius));
+        }
+    }
+
+    let mut output = String::new();
+    for (sensor, beacon, _) in placed {
+        output.push_str(&format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            sensor.0, sensor.1, beacon.0, beacon.1,
+        ));
+    }
+
+    Ok(output)
+}
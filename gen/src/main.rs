@@ -0,0 +1,42 @@
+use std::time::SystemTime;
+
+use clap::Parser;
+use common::config::Config;
+use gen::Options;
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[clap(short, long)]
+    day: u8,
+    #[clap(short = 'k', long)]
+    scale: usize,
+    #[clap(short, long)]
+    seed: Option<u64>,
+    #[clap(long)]
+    max_bounds: Option<i32>,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+    let config = Config::load()?;
+    let seed = match args.seed {
+        Some(seed) => seed,
+        None => {
+            let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+            now.as_nanos() as u64
+        }
+    };
+    let max_bounds = match args.max_bounds {
+        Some(max_bounds) => max_bounds,
+        None => config.get("day15", "max_bounds")?.unwrap_or(4_000_000),
+    };
+
+    let generated = gen::generate(args.day, args.scale, &Options { seed, max_bounds })?;
+
+    eprintln!("Generated with seed {seed}");
+    print!("{generated}");
+
+    Ok(())
+}
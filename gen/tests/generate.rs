@@ -0,0 +1,82 @@
+use gen::Options;
+
+const OPTIONS: Options = Options {
+    seed: 0x2022,
+    max_bounds: 50,
+};
+
+#[test]
+fn trees() {
+    let input = gen::generate(8, 40, &OPTIONS).unwrap();
+    let tree_patch = day8::TreePatch::parse(&input).unwrap();
+    assert_eq!(tree_patch.width(), 40);
+    assert_eq!(tree_patch.height(), 40);
+    assert_eq!(
+        tree_patch.count_visible(day8::VisibilityStrategy::Sweeps),
+        tree_patch.count_visible(day8::VisibilityStrategy::Rays),
+    );
+}
+
+#[test]
+fn rock_paths() {
+    let input = gen::generate(14, 30, &OPTIONS).unwrap();
+    let paths = day14::parse_paths(&input).unwrap();
+    assert_eq!(paths.len(), 30);
+    for path in &paths {
+        for pair in path.points.windows(2) {
+            assert!(pair[0].x == pair[1].x || pair[0].y == pair[1].y, "{pair:?}");
+            assert!(pair[0].y <= pair[1].y, "{pair:?}");
+        }
+    }
+
+    day14::solve_part1(&input).unwrap();
+    day14::solve_part2(&input).unwrap();
+}
+
+#[test]
+fn sensors() {
+    let input = gen::generate(15, 40, &OPTIONS).unwrap();
+    let sensor_reports = day15::parse_sensor_reports(&input).unwrap();
+    assert_eq!(sensor_reports.len(), 40);
+
+    let row = OPTIONS.max_bounds / 2;
+    assert_eq!(
+        day15::count_beaconless_points(&sensor_reports, row),
+        day15::count_beaconless_points_naive(&sensor_reports, row),
+    );
+
+    // The hidden point is somewhere in the search area
+    let uncovered = (0..=OPTIONS.max_bounds)
+        .flat_map(|y| (0..=OPTIONS.max_bounds).map(move |x| day15::Point { x, y }))
+        .any(|point| {
+            sensor_reports
+                .iter()
+                .all(|report| !report.covers_point(point))
+        });
+    assert!(uncovered);
+}
+
+#[test]
+fn same_seed() {
+    for day in [8, 14, 15] {
+        assert_eq!(
+            gen::generate(day, 20, &OPTIONS).unwrap(),
+            gen::generate(day, 20, &OPTIONS).unwrap(),
+            "day {day}"
+        );
+    }
+
+    let other = Options {
+        seed: 0x2023,
+        ..OPTIONS
+    };
+    assert_ne!(
+        gen::generate(8, 20, &OPTIONS).unwrap(),
+        gen::generate(8, 20, &other).unwrap()
+    );
+}
+
+#[test]
+fn unknown_day() {
+    assert!(gen::generate(1, 10, &OPTIONS).is_err());
+}
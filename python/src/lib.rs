@@ -0,0 +1,129 @@
+use common::geometry;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+#[pyfunction]
+fn solve(day: u8, part: u8, input: &str) -> PyResult<String> {
+    solvers::solve(day, part, input)
+        .map(|answer| answer.to_string())
+        .map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+#[pyclass]
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    #[pyo3(get, set)]
+    x: i32,
+    #[pyo3(get, set)]
+    y: i32,
+}
+
+#[pymethods]
+impl Point {
+    #[new]
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    fn manhattan_distance(&self, other: &Point) -> i32 {
+        geometry::Point::from(*self).manhattan_distance(&geometry::Point::from(*other))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Point(x={}, y={})", self.x, self.y)
+    }
+}
+
+impl From<Point> for geometry::Point {
+    fn from(point: Point) -> Self {
+        geometry::Point {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<geometry::Point> for Point {
+    fn from(point: geometry::Point) -> Self {
+        Point {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Debug, Clone, Copy)]
+struct Bounds {
+    #[pyo3(get, set)]
+    min: Point,
+    #[pyo3(get, set)]
+    max: Point,
+}
+
+#[pymethods]
+impl Bounds {
+    #[new]
+    fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    fn width(&self) -> i32 {
+        geometry::Bounds::from(*self).width()
+    }
+
+    fn height(&self) -> i32 {
+        geometry::Bounds::from(*self).height()
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        geometry::Bounds::from(*self).contains(point.into())
+    }
+
+    fn union(&self, other: &Bounds) -> Bounds {
+        let mut bounds = geometry::Bounds::from(*self);
+        bounds.union(&geometry::Bounds::from(*other));
+        bounds.into()
+    }
+
+    fn points(&self) -> Vec<Point> {
+        geometry::Bounds::from(*self)
+            .points()
+            .map(Point::from)
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Bounds(min={}, max={})",
+            self.min.__repr__(),
+            self.max.__repr__()
+        )
+    }
+}
+
+impl From<Bounds> for geometry::Bounds {
+    fn from(bounds: Bounds) -> Self {
+        geometry::Bounds {
+            min: bounds.min.into(),
+            max: bounds.max.into(),
+        }
+    }
+}
+
+impl From<geometry::Bounds> for Bounds {
+    fn from(bounds: geometry::Bounds) -> Self {
+        Bounds {
+            min: bounds.min.into(),
+            max: bounds.max.into(),
+        }
+    }
+}
+
+#[pymodule]
+fn aoc2022(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_class::<Point>()?;
+    m.add_class::<Bounds>()?;
+
+    Ok(())
+}
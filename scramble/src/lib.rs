@@ -0,0 +1,138 @@
+// Turns a real puzzle input into one with the same structure but different
+// values, so fixtures can be committed without sharing the real inputs
+
+use std::collections::HashMap;
+
+use common::rng::Rng;
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub seed: u64,
+    // The day 15 search area, which sensors are mirrored within
+    pub max_bounds: i32,
+}
+
+pub fn scramble(day: u8, input: &str, options: &Options) -> eyre::Result<String> {
+    let mut rng = Rng::new(options.seed);
+    match day {
+        5 => Ok(scramble_crates(input, &mut rng)),
+        15 => scramble_sensors(input, options.max_bounds, &mut rng),
+        16 => scramble_valves(input, &mut rng),
+        day => eyre::bail!("no scrambler for day {day}"),
+    }
+}
+
+/// Relabel the crates in the starting stacks. The moves are left alone.
+fn scramble_crates(input: &str, rng: &mut Rng) -> String {
+    let mut labels: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut labels);
+
+    let mut in_stacks = true;
+    let mut output = String::new();
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            in_stacks = false;
+        }
+
+        if in_stacks {
+            output.extend(line.chars().map(|c| match c {
+                'A'..='Z' => labels[(c as u8 - b'A') as usize],
+                c => c,
+            }));
+        } else {
+            output.push_str(line);
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Mirror every sensor and beacon across the middle of the search area and
+/// shuffle the reports. Mirroring keeps the distances between points the
+/// same, and keeps the part 1 row (in the middle) in place.
+fn scramble_sensors(input: &str, max_bounds: i32, rng: &mut Rng) -> eyre::Result<String> {
+    let mut sensor_reports = day15::parse_sensor_reports(input)?;
+
+    let flip_x = rng.coin();
+    let flip_y = rng.coin();
+    let mirror = |point: day15::Point| day15::Point {
+        x: if flip_x {
+            max_bounds - point.x
+        } else {
+            point.x
+        },
+        y: if flip_y {
+            max_bounds - point.y
+        } else {
+            point.y
+        },
+    };
+
+    rng.shuffle(&mut sensor_reports);
+
+    let mut output = String::new();
+    for report in sensor_reports {
+        let sensor = mirror(report.sensor);
+        let beacon = mirror(report.closest_beacon);
+        output.push_str(&format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            sensor.x, sensor.y, beacon.x, beacon.y,
+        ));
+    }
+
+    Ok(output)
+}
+
+/// Rename every valve except the starting valve `AA`, and shuffle the scans.
+fn scramble_valves(input: &str, rng: &mut Rng) -> eyre::Result<String> {
+    let mut tunnel_scans = day16::parse_tunnel_scans(input)?;
+
+    let mut names: Vec<String> = ('A'..='Z')
+        .flat_map(|a| ('A'..='Z').map(move |b| format!("{a}{b}")))
+        .filter(|name| name != "AA")
+        .collect();
+    rng.shuffle(&mut names);
+
+    let mut renames = HashMap::new();
+    renames.insert("AA".to_string(), "AA".to_string());
+    for scan in &tunnel_scans {
+        if !renames.contains_key(&scan.valve) {
+            let name = names
+                .pop()
+                .ok_or_else(|| eyre::eyre!("too many valves to rename"))?;
+            renames.insert(scan.valve.clone(), name);
+        }
+    }
+
+    let rename = |valve: &str| {
+        renames
+            .get(valve)
+            .cloned()
+            .ok_or_else(|| eyre::eyre!("tunnel leads to unknown valve {valve:?}"))
+    };
+
+    rng.shuffle(&mut tunnel_scans);
+
+    let mut output = String::new();
+    for scan in &tunnel_scans {
+        let paths = scan
+            .paths
+            .iter()
+            .map(|path| rename(path))
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let tunnels = if paths.len() == 1 {
+            "tunnel leads to valve"
+        } else {
+            "tunnels lead to valves"
+        };
+        output.push_str(&format!(
+            "Valve {} has flow rate={}; {tunnels} {}\n",
+            rename(&scan.valve)?,
+            scan.flow_rate,
+            paths.join(", "),
+        ));
+    }
+
+    Ok(output)
+}
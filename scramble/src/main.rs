@@ -0,0 +1,44 @@
+use std::time::SystemTime;
+
+use clap::Parser;
+use cli::InputArgs;
+use common::config::Config;
+use scramble::Options;
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[clap(short, long)]
+    day: u8,
+    #[clap(short, long)]
+    seed: Option<u64>,
+    #[clap(long)]
+    max_bounds: Option<i32>,
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+    let config = Config::load()?;
+    let seed = match args.seed {
+        Some(seed) => seed,
+        None => {
+            let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+            now.as_nanos() as u64
+        }
+    };
+    let max_bounds = match args.max_bounds {
+        Some(max_bounds) => max_bounds,
+        None => config.get("day15", "max_bounds")?.unwrap_or(4_000_000),
+    };
+
+    let input = args.input.read("")?;
+    let scrambled = scramble::scramble(args.day, &input, &Options { seed, max_bounds })?;
+
+    eprintln!("Scrambled with seed {seed}");
+    print!("{scrambled}");
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use scramble::Options;
+
+// The example's search area, so mirroring keeps the part 1 row (10) in place
+const OPTIONS: Options = Options {
+    seed: 0x2022,
+    max_bounds: 20,
+};
+
+// A few seeds, so both mirrorings and plenty of shuffles get checked
+const SEEDS: [u64; 4] = [0, 1, 0x2022, u64::MAX];
+
+fn scramble_with_seed(day: u8, input: &str, seed: u64) -> String {
+    scramble::scramble(day, input, &Options { seed, ..OPTIONS }).unwrap()
+}
+
+#[test]
+fn day5_keeps_stack_shape() {
+    let (stacks, moves) = day5::parse(day5::EXAMPLE).unwrap();
+    for seed in SEEDS {
+        let scrambled = scramble_with_seed(5, day5::EXAMPLE, seed);
+        let (scrambled_stacks, scrambled_moves) = day5::parse(&scrambled).unwrap();
+
+        assert_eq!(scrambled_moves, moves, "seed {seed}");
+        assert_eq!(scrambled_stacks.len(), stacks.len(), "seed {seed}");
+
+        // Every crate is relabeled the same way everywhere
+        let mut labels = HashMap::new();
+        for index in 0..stacks.len() {
+            let stack = stacks.stack(index).unwrap();
+            let scrambled_stack = scrambled_stacks.stack(index).unwrap();
+            assert_eq!(scrambled_stack.len(), stack.len(), "seed {seed}");
+
+            for (&label, &scrambled_label) in stack.iter().zip(scrambled_stack) {
+                let previous = labels.insert(label, scrambled_label);
+                assert!(
+                    previous.map_or(true, |previous| previous == scrambled_label),
+                    "seed {seed}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn day15_keeps_part1() {
+    let sensor_reports = day15::parse_sensor_reports(day15::EXAMPLE).unwrap();
+    let expected = day15::count_beaconless_points(&sensor_reports, 10);
+    assert_eq!(expected, 26);
+
+    for seed in SEEDS {
+        let scrambled = scramble_with_seed(15, day15::EXAMPLE, seed);
+        let scrambled_reports = day15::parse_sensor_reports(&scrambled).unwrap();
+
+        assert_eq!(scrambled_reports.len(), sensor_reports.len(), "seed {seed}");
+        assert_eq!(
+            day15::count_beaconless_points(&scrambled_reports, 10),
+            expected,
+            "seed {seed}"
+        );
+    }
+}
+
+// The full 30 minute search takes far too long, but renaming valves can't
+// change the best score at any time limit
+const DAY16_TIME: u64 = 8;
+
+fn day16_best_score(input: &str) -> u64 {
+    let scans = day16::parse_tunnel_scans(input).unwrap();
+    let tunnels = day16::Tunnels::from_scans(&scans);
+    day16::find_best_path(&tunnels, "AA", DAY16_TIME).score(DAY16_TIME)
+}
+
+#[test]
+fn day16_keeps_part1() {
+    let expected = day16_best_score(day16::EXAMPLE);
+    assert!(expected > 0);
+
+    for seed in SEEDS {
+        let scrambled = scramble_with_seed(16, day16::EXAMPLE, seed);
+        let scans = day16::parse_tunnel_scans(&scrambled).unwrap();
+
+        assert!(scans.iter().any(|scan| scan.valve == "AA"), "seed {seed}");
+        assert_eq!(day16_best_score(&scrambled), expected, "seed {seed}");
+    }
+}
+
+#[test]
+fn same_seed_same_output() {
+    for (day, input) in [
+        (5, day5::EXAMPLE),
+        (15, day15::EXAMPLE),
+        (16, day16::EXAMPLE),
+    ] {
+        let scrambled = scramble_with_seed(day, input, 0x2022);
+        assert_eq!(
+            scramble_with_seed(day, input, 0x2022),
+            scrambled,
+            "day {day}"
+        );
+    }
+}
+
+#[test]
+fn unknown_day() {
+    assert!(scramble::scramble(1, "", &OPTIONS).is_err());
+}
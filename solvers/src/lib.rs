@@ -0,0 +1,65 @@
+pub use common::answer::Answer;
+
+macro_rules! solvers {
+    ($($day:literal => $krate:ident),* $(,)?) => {
+        pub const DAYS: &[u8] = &[$($day),*];
+
+        pub fn solve(day: u8, part: u8, input: &str) -> Result<Answer, SolveError> {
+            match (day, part) {
+                $(
+                    ($day, 1) => $krate::solve_part1(input).map_err(|error| SolveError::Failed(format!("{error:#}"))),
+                    ($day, 2) => $krate::solve_part2(input).map_err(|error| SolveError::Failed(format!("{error:#}"))),
+                )*
+                (day, _) if !DAYS.contains(&day) => Err(SolveError::UnknownDay(day)),
+                (_, part) => Err(SolveError::UnknownPart(part)),
+            }
+        }
+    };
+}
+
+solvers! {
+    1 => day1,
+    2 => day2,
+    3 => day3,
+    4 => day4,
+    5 => day5,
+    6 => day6,
+    7 => day7,
+    8 => day8,
+    9 => day9,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+    13 => day13,
+    14 => day14,
+    15 => day15,
+    16 => day16,
+    17 => day17,
+    18 => day18,
+    19 => day19,
+    20 => day20,
+    21 => day21,
+    22 => day22,
+    23 => day23,
+    24 => day24,
+    25 => day25,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    UnknownDay(u8),
+    UnknownPart(u8),
+    Failed(String),
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::UnknownDay(day) => write!(f, "no solver for day {day}"),
+            SolveError::UnknownPart(part) => write!(f, "no solver for part {part}"),
+            SolveError::Failed(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}